@@ -20,6 +20,17 @@ pub fn dispatch(args: TokenStream, input: TokenStream) -> TokenStream {
         } else {
             None
         };
+        let (clone_avx512, feature_avx512) = if nightly {
+            (
+                Some(quote! { #[clone(target = "[x86|x86_64]+avx512f")] }),
+                Some(quote! {
+                    #[target_cfg(target = "[x86|x86_64]+avx512f")]
+                    let #feature = unsafe { <generic_simd::arch::x86::Avx512 as generic_simd::arch::Token>::new_unchecked() };
+                }),
+            )
+        } else {
+            (None, None)
+        };
         let clone_arm = if nightly {
             Some(quote! { #[clone(target = "aarch64+neon")] })
         } else {
@@ -27,6 +38,8 @@ pub fn dispatch(args: TokenStream, input: TokenStream) -> TokenStream {
         };
         quote! {
             #[generic_simd::multiversion::multiversion]
+            #clone_avx512
+            #[clone(target = "[x86|x86_64]+avx2+fma")]
             #[clone(target = "[x86|x86_64]+avx")]
             #[clone(target = "[x86|x86_64]+sse4.1")]
             #clone_wasm
@@ -42,6 +55,11 @@ pub fn dispatch(args: TokenStream, input: TokenStream) -> TokenStream {
                 #[target_cfg(target = "[x86|x86_64]+avx")]
                 let #feature = unsafe { <generic_simd::arch::x86::Avx as generic_simd::arch::Token>::new_unchecked() };
 
+                #[target_cfg(target = "[x86|x86_64]+avx2+fma")]
+                let #feature = unsafe { <generic_simd::arch::x86::Avx2 as generic_simd::arch::Token>::new_unchecked() };
+
+                #feature_avx512
+
                 #[target_cfg(target = "wasm32+simd128")]
                 let #feature = unsafe { <generic_simd::arch::wasm::Simd128 as generic_simd::arch::Token>::new_unchecked() };
 
@@ -51,6 +69,8 @@ pub fn dispatch(args: TokenStream, input: TokenStream) -> TokenStream {
                 #[target_cfg(not(any(
                     target = "[x86|x86_64]+sse4.1",
                     target = "[x86|x86_64]+avx",
+                    target = "[x86|x86_64]+avx2+fma",
+                    target = "[x86|x86_64]+avx512f",
                     target = "[arm|aarch64]+neon",
                     target = "wasm32+simd128",
                 )))]
@@ -6,12 +6,27 @@
     all(feature = "nightly", target_arch = "aarch64"),
     feature(stdsimd, aarch64_target_feature)
 )]
+#![cfg_attr(
+    all(feature = "nightly", any(target_arch = "x86", target_arch = "x86_64")),
+    feature(stdsimd, avx512_target_feature)
+)]
 #![cfg_attr(
     all(feature = "nightly", target_arch = "arm"),
     feature(stdsimd, arm_target_feature)
 )]
 
-use generic_simd::{dispatch, scalar::ScalarExt, vector::Signed};
+use generic_simd::{
+    dispatch,
+    math::{self, Transcendental},
+    pointer::{Pointer, PointerMut},
+    scalar::{Scalar, ScalarExt},
+    shim::Half,
+    slice::{self, Overlapping, OverlappingMut, Vectors, VectorsMut},
+    vector::{
+        Bits, Bitwise, Compare, Convert, ConvertRound, Float, Gather, Mask, MulAdd, Native, Ops,
+        Reduce, ReduceOrd, Round, Select, ShuffleIndices, Sqrt, Vector,
+    },
+};
 use num_traits::Num;
 use rand::distributions::Standard;
 use rand::prelude::*;
@@ -22,12 +37,441 @@ wasm_bindgen_test::wasm_bindgen_test_configure!(run_in_browser);
 #[cfg(feature = "complex")]
 use num_complex::{Complex, ComplexDistribution};
 
+/// Samples any nonzero integer, so integer division is always defined.
+#[derive(Copy, Clone)]
+struct NonZero;
+
+macro_rules! nonzero_distribution {
+    { $($type:ty),* } => {
+        $(
+        impl rand::distributions::Distribution<$type> for NonZero {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $type {
+                loop {
+                    let value: $type = rng.gen();
+                    if value != 0 {
+                        return value;
+                    }
+                }
+            }
+        }
+        )*
+    }
+}
+
+nonzero_distribution! { i8, i16, i32, i64, u8, u16, u32, u64 }
+
+/// Samples small powers of two, so floating-point sums and products are exact in any order.
+#[derive(Copy, Clone)]
+struct Dyadic;
+
+macro_rules! dyadic_distribution {
+    { $($type:ty),* } => {
+        $(
+        impl rand::distributions::Distribution<$type> for Dyadic {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $type {
+                *[-2., -1., -0.5, 0.5, 1., 2.].choose(rng).unwrap()
+            }
+        }
+        )*
+    }
+}
+
+dyadic_distribution! { f32, f64 }
+
+// Complex samples lie on an axis, so complex products stay exact as well.
+#[cfg(feature = "complex")]
+macro_rules! dyadic_complex_distribution {
+    { $($type:ty),* } => {
+        $(
+        impl rand::distributions::Distribution<Complex<$type>> for Dyadic {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Complex<$type> {
+                let value: $type = self.sample(rng);
+                if rng.gen() {
+                    Complex::new(value, 0.)
+                } else {
+                    Complex::new(0., value)
+                }
+            }
+        }
+        )*
+    }
+}
+
+#[cfg(feature = "complex")]
+dyadic_complex_distribution! { f32, f64 }
+
+/// Samples multiples of a quarter, so float to integer conversions are in range and rounding ties
+/// occur.
+#[derive(Copy, Clone)]
+struct Quarters;
+
+macro_rules! quarters_distribution {
+    { $($type:ty),* } => {
+        $(
+        impl rand::distributions::Distribution<$type> for Quarters {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $type {
+                rng.gen_range(-4096, 4096) as $type / 4.
+            }
+        }
+        )*
+    }
+}
+
+quarters_distribution! { f32, f64 }
+
+/// Rounds to the nearest integer, with ties to even.
+fn round_ties_even(x: f32) -> i32 {
+    let rounded = x.round();
+    if (rounded - x).abs() == 0.5 {
+        (2. * (x / 2.).round()) as i32
+    } else {
+        rounded as i32
+    }
+}
+
+/// Samples dyadic values, or NaN a quarter of the time.
+#[derive(Copy, Clone)]
+struct WithNan;
+
+macro_rules! with_nan_distribution {
+    { $($type:ty),* } => {
+        $(
+        impl rand::distributions::Distribution<$type> for WithNan {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $type {
+                if rng.gen_range(0, 4) == 0 {
+                    <$type>::NAN
+                } else {
+                    rng.sample(Dyadic)
+                }
+            }
+        }
+        )*
+    }
+}
+
+with_nan_distribution! { f32, f64 }
+
+/// Samples positive values spanning several orders of magnitude.
+#[derive(Copy, Clone)]
+struct Positive;
+
+macro_rules! positive_distribution {
+    { $($type:ty),* } => {
+        $(
+        impl rand::distributions::Distribution<$type> for Positive {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $type {
+                rng.gen_range(1., 1000.) / rng.gen_range(1., 1000.)
+            }
+        }
+        )*
+    }
+}
+
+positive_distribution! { f32, f64 }
+
+/// Samples values of moderate magnitude with either sign.
+#[derive(Copy, Clone)]
+struct Moderate;
+
+macro_rules! moderate_distribution {
+    { $($type:ty),* } => {
+        $(
+        impl rand::distributions::Distribution<$type> for Moderate {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $type {
+                rng.gen_range(-10., 10.)
+            }
+        }
+        )*
+    }
+}
+
+moderate_distribution! { f32, f64 }
+
+/// Samples finite values of large magnitude with either sign.
+#[derive(Copy, Clone)]
+struct Large;
+
+macro_rules! large_distribution {
+    { $($type:ty),* } => {
+        $(
+        impl rand::distributions::Distribution<$type> for Large {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $type {
+                let exponent = rng.gen_range(2., <$type>::MAX_10_EXP as $type);
+                let sign = if rng.gen() { 1. } else { -1. };
+                sign * (10 as $type).powf(exponent)
+            }
+        }
+        )*
+    }
+}
+
+large_distribution! { f32, f64 }
+
+/// A dense sweep of arguments between `lo` and `hi`.
+#[derive(Copy, Clone)]
+struct Sweep {
+    lo: f64,
+    hi: f64,
+    geometric: bool,
+    signed: bool,
+}
+
+impl Sweep {
+    /// Evenly spaced arguments.
+    const fn linear(lo: f64, hi: f64) -> Self {
+        Self {
+            lo,
+            hi,
+            geometric: false,
+            signed: false,
+        }
+    }
+
+    /// Positive arguments evenly spaced in logarithm, for arguments spanning many orders of
+    /// magnitude.
+    const fn geometric(lo: f64, hi: f64) -> Self {
+        Self {
+            lo,
+            hi,
+            geometric: true,
+            signed: false,
+        }
+    }
+
+    /// Alternates the sign of the arguments.
+    const fn signed(self) -> Self {
+        Self {
+            signed: true,
+            ..self
+        }
+    }
+
+    fn points<T: num_traits::Float>(self, count: usize) -> impl Iterator<Item = T> {
+        (0..count).map(move |i| {
+            let t = i as f64 / (count - 1) as f64;
+            let x = if self.geometric {
+                (self.lo.ln() + (self.hi.ln() - self.lo.ln()) * t).exp()
+            } else {
+                self.lo + (self.hi - self.lo) * t
+            };
+            let x = if self.signed && i % 2 == 1 { -x } else { x };
+            num_traits::cast(x).unwrap()
+        })
+    }
+}
+
+/// A float type whose errors are measured against an `f64` reference.
+trait Reference: num_traits::Float + core::fmt::Debug {
+    /// The maximum error of the reference functions, in units in the last place of this type.
+    const REFERENCE_ERROR: f64;
+
+    /// Returns the error of `y`, in units in the last place of `exact`.
+    fn ulp_error(y: Self, exact: f64) -> f64;
+}
+
+macro_rules! reference {
+    { $($type:ty => $reference_error:expr),* } => {
+        $(
+        impl Reference for $type {
+            const REFERENCE_ERROR: f64 = $reference_error;
+
+            fn ulp_error(y: Self, exact: f64) -> f64 {
+                let y = y as f64;
+                if y.is_nan() || exact.is_nan() {
+                    return if y.is_nan() && exact.is_nan() { 0. } else { f64::INFINITY };
+                }
+                if y == exact {
+                    return 0.;
+                }
+
+                // Subnormal results have the unit of the smallest normal exponent.
+                let exponent = ((exact.to_bits() >> 52) & 0x7ff) as i32 - 1023;
+                let exponent = exponent.max(<$type>::MIN_EXP - 1);
+                let ulp = 2f64.powi(exponent) * 2f64.powi(1 - <$type>::MANTISSA_DIGITS as i32);
+                (y - exact).abs() / ulp
+            }
+        }
+        )*
+    }
+}
+
+// `f32` is measured against the `f64` functions, which are accurate to well under an `f32` unit.
+// `f64` is measured against the `f64` functions themselves, which may be off by one unit.
+reference! { f32 => 0.01, f64 => 1. }
+
+/// Zeros, infinities, NaN, subnormals and the extremes of a float type.
+macro_rules! special_values {
+    { $type:ident } => {
+        &[
+            0.,
+            -0.,
+            $type::INFINITY,
+            -$type::INFINITY,
+            $type::NAN,
+            $type::MIN_POSITIVE,
+            $type::MIN_POSITIVE / 2.,
+            -$type::MIN_POSITIVE / 2.,
+            $type::from_bits(1),
+            1.,
+            -1.,
+            $type::MAX,
+            -$type::MAX,
+        ]
+    }
+}
+
+/// Trigonometric arguments too large to be reduced with the vectorized reduction.
+macro_rules! large_trig_values {
+    { $type:ident } => {
+        &[6000.5, -1.6e6, 1e8, -1e16, 1e30, -1e38, $type::MAX, -$type::MAX]
+    }
+}
+
+/// Special `(y, x)` arguments of `atan2`, covering each quadrant and signed zeros.
+macro_rules! atan2_special_values {
+    { $type:ident } => {
+        &[
+            (0., 0.),
+            (-0., 0.),
+            (0., -0.),
+            (-0., -0.),
+            (0., 1.),
+            (-0., 1.),
+            (0., -1.),
+            (-0., -1.),
+            (1., 0.),
+            (1., -0.),
+            (-1., 0.),
+            (-1., -0.),
+            (1., 1.),
+            (1., -1.),
+            (-1., 1.),
+            (-1., -1.),
+            ($type::INFINITY, $type::INFINITY),
+            ($type::INFINITY, -$type::INFINITY),
+            (-$type::INFINITY, $type::INFINITY),
+            (-$type::INFINITY, -$type::INFINITY),
+            (1., $type::INFINITY),
+            (1., -$type::INFINITY),
+            (-1., -$type::INFINITY),
+            ($type::INFINITY, 1.),
+            (-$type::INFINITY, -1.),
+            ($type::NAN, 1.),
+            (1., $type::NAN),
+            ($type::MIN_POSITIVE / 2., 1.),
+            ($type::MIN_POSITIVE / 2., -1.),
+            (1., $type::MIN_POSITIVE / 2.),
+            ($type::MAX, $type::MIN_POSITIVE),
+        ]
+    }
+}
+
+/// Special `(x, y)` arguments of `pow`, including negative bases.
+macro_rules! pow_special_values {
+    { $type:ident } => {
+        &[
+            (-2., 3.),
+            (-2., 2.),
+            (-2., 0.5),
+            (-2., -3.),
+            (-2., -2.),
+            (-8., 1. / 3.),
+            (-0., 3.),
+            (-0., -3.),
+            (-0., 2.),
+            (-0., -2.),
+            (-0., 0.5),
+            (-0., -0.5),
+            (0., 1.),
+            (0., -1.),
+            (0., 0.),
+            (-$type::INFINITY, 3.),
+            (-$type::INFINITY, -3.),
+            (-$type::INFINITY, 2.),
+            (-$type::INFINITY, -2.),
+            (-$type::INFINITY, 0.5),
+            ($type::INFINITY, 0.5),
+            ($type::INFINITY, -1.),
+            (-1., $type::INFINITY),
+            (-1., -$type::INFINITY),
+            (-0.5, $type::INFINITY),
+            (-0.5, -$type::INFINITY),
+            (-2., $type::INFINITY),
+            (-2., -$type::INFINITY),
+            (0.5, $type::INFINITY),
+            (2., $type::INFINITY),
+            (-3., $type::MAX),
+            (-0.5, $type::MAX),
+            (2., 2000.),
+            (2., -2000.),
+            ($type::NAN, 0.),
+            ($type::NAN, 1.),
+            (1., $type::NAN),
+            (-1., $type::NAN),
+            (2., $type::NAN),
+            ($type::MIN_POSITIVE / 2., 0.5),
+            (-$type::MIN_POSITIVE / 2., 2.),
+        ]
+    }
+}
+
+/// Raises the magnitude of `x` to the power `y`, which is always defined.
+fn pow_abs<V: Transcendental>(x: V, y: V) -> V {
+    math::pow(x.abs(), y)
+}
+
+/// Writes a vector through a pointer and reads it back.
+#[inline]
+fn pointer_round_trip<V>(vector: V) -> V
+where
+    V: Vector,
+    *const V::Scalar: Pointer<V::Token, V::Width, Vector = V>,
+    *mut V::Scalar: PointerMut<V::Token, V::Width, Vector = V>,
+{
+    let token = vector.to_token();
+    let mut buffer = V::zeroed(token);
+    let ptr = buffer.as_slice_mut().as_mut_ptr();
+    unsafe {
+        ptr.vector_write(token, vector);
+        (ptr as *const V::Scalar).vector_read(token)
+    }
+}
+
+/// Writes a vector through a vector-aligned pointer and reads it back.
+#[inline]
+fn pointer_round_trip_aligned<V>(vector: V) -> V
+where
+    V: Vector,
+    *const V::Scalar: Pointer<V::Token, V::Width, Vector = V>,
+    *mut V::Scalar: PointerMut<V::Token, V::Width, Vector = V>,
+{
+    let token = vector.to_token();
+    let mut buffer = V::zeroed(token);
+    let ptr = buffer.as_slice_mut().as_mut_ptr();
+    unsafe {
+        ptr.vector_write_aligned(token, vector);
+        (ptr as *const V::Scalar).vector_read_aligned(token)
+    }
+}
+
+/// Converts a vector to its underlying type and back.
+#[inline]
+fn underlying_round_trip<V: Vector>(vector: V) -> V {
+    V::from_underlying(vector.to_token(), vector.to_underlying())
+}
+
+/// Converts a vector to bits and back.
+#[inline]
+fn bits_round_trip<V: Bits>(vector: V) -> V {
+    V::from_bits(vector.to_bits())
+}
+
 #[inline]
 fn unary_op_impl<D, V, VFunc, SFunc>(distribution: D, mut vector: V, vfunc: VFunc, sfunc: SFunc)
 where
-    V::Scalar: Num + core::ops::Neg<Output = V::Scalar> + core::fmt::Debug + Copy,
+    V::Scalar: PartialEq + core::fmt::Debug + Copy,
     D: rand::distributions::Distribution<V::Scalar> + Copy,
-    V: Signed,
+    V: Vector,
     VFunc: Fn(V) -> V,
     SFunc: Fn(V::Scalar) -> V::Scalar,
 {
@@ -38,7 +482,32 @@ where
 
     let output = vfunc(vector);
     for i in 0..V::width() {
-        assert_eq!(output[i], sfunc(vector[i]))
+        assert_eq!(output.as_slice()[i], sfunc(vector.as_slice()[i]))
+    }
+}
+
+#[inline]
+fn convert_op_impl<D, V, U, VFunc, SFunc>(
+    distribution: D,
+    mut vector: V,
+    vfunc: VFunc,
+    sfunc: SFunc,
+) where
+    U::Scalar: PartialEq + core::fmt::Debug + Copy,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Vector,
+    U: Vector,
+    VFunc: Fn(V) -> U,
+    SFunc: Fn(V::Scalar) -> U::Scalar,
+{
+    let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
+    for x in vector.as_slice_mut() {
+        *x = rng.sample(distribution);
+    }
+
+    let output = vfunc(vector);
+    for i in 0..V::width() {
+        assert_eq!(output.as_slice()[i], sfunc(vector.as_slice()[i]))
     }
 }
 
@@ -49,9 +518,9 @@ fn binary_op_impl<D, V, VFunc, SFunc>(
     vfunc: VFunc,
     sfunc: SFunc,
 ) where
-    V::Scalar: Num + core::ops::Neg<Output = V::Scalar> + core::fmt::Debug + Copy,
+    V::Scalar: Num + core::fmt::Debug + Copy,
     D: rand::distributions::Distribution<V::Scalar> + Copy,
-    V: Signed,
+    V: Ops,
     VFunc: Fn(V, V) -> V,
     SFunc: Fn(V::Scalar, V::Scalar) -> V::Scalar,
 {
@@ -70,38 +539,79 @@ fn binary_op_impl<D, V, VFunc, SFunc>(
 }
 
 #[inline]
-fn binary_scalar_op_impl<D, V, VFunc, SFunc>(distribution: D, mut a: V, vfunc: VFunc, sfunc: SFunc)
-where
-    V::Scalar: Num + core::ops::Neg<Output = V::Scalar> + core::fmt::Debug + Copy,
+fn binary_nan_op_impl<D, V, VFunc, SFunc>(
+    distribution: D,
+    (mut a, mut b): (V, V),
+    vfunc: VFunc,
+    sfunc: SFunc,
+) where
+    V::Scalar: num_traits::Float + core::fmt::Debug,
     D: rand::distributions::Distribution<V::Scalar> + Copy,
-    V: Signed,
-    VFunc: Fn(V, V::Scalar) -> V,
+    V: Ops,
+    VFunc: Fn(V, V) -> V,
     SFunc: Fn(V::Scalar, V::Scalar) -> V::Scalar,
 {
     let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
-    let b = rng.sample(distribution);
     for x in a.as_slice_mut() {
         *x = rng.sample(distribution);
     }
+    for x in b.as_slice_mut() {
+        *x = rng.sample(distribution);
+    }
 
     let output = vfunc(a, b);
     for i in 0..V::width() {
-        assert_eq!(output[i], sfunc(a[i], b))
+        let expected = sfunc(a[i], b[i]);
+        assert!(
+            output[i] == expected
+                || (num_traits::Float::is_nan(output[i]) && num_traits::Float::is_nan(expected)),
+            "{:?} != {:?}",
+            output[i],
+            expected
+        )
     }
 }
 
+/// Checks a unary op on the bits of each lane, since float lanes may become NaN.
 #[inline]
-fn assign_op_impl<D, V, VFunc, SFunc>(
+fn bitwise_unary_op_impl<D, V, VFunc, SFunc>(
+    distribution: D,
+    mut vector: V,
+    vfunc: VFunc,
+    sfunc: SFunc,
+) where
+    <V::Bits as Vector>::Scalar: PartialEq + core::fmt::Debug,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Bits,
+    VFunc: Fn(V) -> V,
+    SFunc: Fn(<V::Bits as Vector>::Scalar) -> <V::Bits as Vector>::Scalar,
+{
+    let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
+    for x in vector.as_slice_mut() {
+        *x = rng.sample(distribution);
+    }
+
+    let output = vfunc(vector).to_bits();
+    let vector = vector.to_bits();
+    for i in 0..V::width() {
+        assert_eq!(output.as_slice()[i], sfunc(vector.as_slice()[i]))
+    }
+}
+
+/// Checks a binary op on the bits of each lane, since float lanes may become NaN.
+#[inline]
+fn bitwise_op_impl<D, V, VFunc, SFunc>(
     distribution: D,
     (mut a, mut b): (V, V),
     vfunc: VFunc,
     sfunc: SFunc,
 ) where
-    V::Scalar: Num + core::ops::Neg<Output = V::Scalar> + core::fmt::Debug + Copy,
+    <V::Bits as Vector>::Scalar: PartialEq + core::fmt::Debug,
     D: rand::distributions::Distribution<V::Scalar> + Copy,
-    V: Signed,
-    VFunc: Fn(&mut V, V),
-    SFunc: Fn(&mut V::Scalar, V::Scalar),
+    V: Bits,
+    VFunc: Fn(V, V) -> V,
+    SFunc:
+        Fn(<V::Bits as Vector>::Scalar, <V::Bits as Vector>::Scalar) -> <V::Bits as Vector>::Scalar,
 {
     let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
     for x in a.as_slice_mut() {
@@ -111,87 +621,1265 @@ fn assign_op_impl<D, V, VFunc, SFunc>(
         *x = rng.sample(distribution);
     }
 
-    let mut output: V = a;
-    vfunc(&mut output, b);
+    let output = vfunc(a, b).to_bits();
+    let (a, b) = (a.to_bits(), b.to_bits());
     for i in 0..V::width() {
-        sfunc(&mut a[i], b[i]);
-        assert_eq!(output[i], a[i])
+        assert_eq!(
+            output.as_slice()[i],
+            sfunc(a.as_slice()[i], b.as_slice()[i])
+        )
+    }
+}
+
+/// Checks a unary op against a scalar function, allowing a relative error.
+#[inline]
+fn approx_op_impl<D, V, VFunc, SFunc>(
+    distribution: D,
+    mut vector: V,
+    vfunc: VFunc,
+    sfunc: SFunc,
+    max_error: f64,
+) where
+    V::Scalar: num_traits::Float + core::fmt::Debug,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Vector,
+    VFunc: Fn(V) -> V,
+    SFunc: Fn(V::Scalar) -> V::Scalar,
+{
+    let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
+    for x in vector.as_slice_mut() {
+        *x = rng.sample(distribution);
+    }
+
+    let max_error: V::Scalar = num_traits::cast(max_error).unwrap();
+    let output = vfunc(vector);
+    for i in 0..V::width() {
+        let expected = sfunc(vector.as_slice()[i]);
+        assert!(
+            num_traits::Float::abs((output.as_slice()[i] - expected) / expected) <= max_error,
+            "{:?} != {:?}",
+            output.as_slice()[i],
+            expected
+        )
+    }
+}
+
+#[inline]
+fn estimate_op_impl<D, V, VFunc, SFunc>(distribution: D, vector: V, vfunc: VFunc, sfunc: SFunc)
+where
+    V::Scalar: num_traits::Float + core::fmt::Debug,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Vector,
+    VFunc: Fn(V) -> V,
+    SFunc: Fn(V::Scalar) -> V::Scalar,
+{
+    approx_op_impl(distribution, vector, vfunc, sfunc, 2f64.powi(-8))
+}
+
+#[inline]
+fn refined_op_impl<D, V, VFunc, SFunc>(distribution: D, vector: V, vfunc: VFunc, sfunc: SFunc)
+where
+    V::Scalar: num_traits::Float + core::fmt::Debug,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Vector,
+    VFunc: Fn(V) -> V,
+    SFunc: Fn(V::Scalar) -> V::Scalar,
+{
+    approx_op_impl(distribution, vector, vfunc, sfunc, 2f64.powi(-14))
+}
+
+/// Checks a unary op against a scalar function, allowing an error of a few units in the last
+/// place.
+#[inline]
+fn ulp_op_impl<D, V, VFunc, SFunc>(distribution: D, vector: V, vfunc: VFunc, sfunc: SFunc)
+where
+    V::Scalar: num_traits::Float + core::fmt::Debug,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Vector,
+    VFunc: Fn(V) -> V,
+    SFunc: Fn(V::Scalar) -> V::Scalar,
+{
+    let epsilon: f64 = num_traits::cast(<V::Scalar as num_traits::Float>::epsilon()).unwrap();
+    approx_op_impl(distribution, vector, vfunc, sfunc, 4. * epsilon)
+}
+
+/// Checks a binary op against a scalar function, allowing an error of a few units in the last
+/// place.
+#[inline]
+fn ulp_binary_op_impl<D, V, VFunc, SFunc>(
+    distribution: D,
+    (mut a, mut b): (V, V),
+    vfunc: VFunc,
+    sfunc: SFunc,
+) where
+    V::Scalar: num_traits::Float + core::fmt::Debug,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Vector,
+    VFunc: Fn(V, V) -> V,
+    SFunc: Fn(V::Scalar, V::Scalar) -> V::Scalar,
+{
+    let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
+    for x in a.as_slice_mut() {
+        *x = rng.sample(distribution);
+    }
+    for x in b.as_slice_mut() {
+        *x = rng.sample(distribution);
+    }
+
+    let max_error = <V::Scalar as num_traits::Float>::epsilon() * num_traits::cast(4).unwrap();
+    let output = vfunc(a, b);
+    for i in 0..V::width() {
+        let expected = sfunc(a.as_slice()[i], b.as_slice()[i]);
+        assert!(
+            num_traits::Float::abs((output.as_slice()[i] - expected) / expected) <= max_error,
+            "{:?} != {:?}",
+            output.as_slice()[i],
+            expected
+        )
+    }
+}
+
+/// Returns whether `y` is an acceptable result for a special argument, given the `expected` result.
+///
+/// Zeros and infinities must match exactly, including their sign, and NaN must produce NaN.
+fn special_result<T: Reference>(y: T, expected: T) -> bool {
+    if expected.is_nan() {
+        y.is_nan()
+    } else if num_traits::Zero::is_zero(&expected) || expected.is_infinite() {
+        y == expected && y.is_sign_negative() == expected.is_sign_negative()
+    } else {
+        T::ulp_error(y, expected.to_f64().unwrap()) <= 4.
+    }
+}
+
+/// Checks a unary op against a scalar function for each of `values`, which may be special values
+/// such as zeros, infinities and NaN.
+#[inline]
+fn special_op_impl<V, VFunc, SFunc>(values: &[V::Scalar], mut vector: V, vfunc: VFunc, sfunc: SFunc)
+where
+    V::Scalar: Reference,
+    V: Vector,
+    VFunc: Fn(V) -> V,
+    SFunc: Fn(V::Scalar) -> V::Scalar,
+{
+    for chunk in values.chunks(V::width()) {
+        for (x, value) in vector.as_slice_mut().iter_mut().zip(chunk.iter().cycle()) {
+            *x = *value;
+        }
+        let output = vfunc(vector);
+        for (x, y) in vector.as_slice().iter().zip(output.as_slice()) {
+            let expected = sfunc(*x);
+            assert!(
+                special_result(*y, expected),
+                "f({:?}) = {:?} != {:?}",
+                x,
+                y,
+                expected
+            );
+        }
+    }
+}
+
+/// Checks a binary op against a scalar function for each pair of `values`, which may be special
+/// values such as zeros, infinities and NaN.
+#[inline]
+fn special_binary_op_impl<V, VFunc, SFunc>(
+    values: &[(V::Scalar, V::Scalar)],
+    (mut a, mut b): (V, V),
+    vfunc: VFunc,
+    sfunc: SFunc,
+) where
+    V::Scalar: Reference,
+    V: Vector,
+    VFunc: Fn(V, V) -> V,
+    SFunc: Fn(V::Scalar, V::Scalar) -> V::Scalar,
+{
+    for chunk in values.chunks(V::width()) {
+        for ((x, y), value) in a
+            .as_slice_mut()
+            .iter_mut()
+            .zip(b.as_slice_mut().iter_mut())
+            .zip(chunk.iter().cycle())
+        {
+            *x = value.0;
+            *y = value.1;
+        }
+        let output = vfunc(a, b);
+        for ((x, y), z) in a.as_slice().iter().zip(b.as_slice()).zip(output.as_slice()) {
+            let expected = sfunc(*x, *y);
+            assert!(
+                special_result(*z, expected),
+                "f({:?}, {:?}) = {:?} != {:?}",
+                x,
+                y,
+                z,
+                expected
+            );
+        }
+    }
+}
+
+/// Checks that a unary op is within `max_ulp` units in the last place of a reference over a dense
+/// sweep of arguments.
+#[inline]
+fn sweep_op_impl<V, VFunc, SFunc>(
+    (sweep, max_ulp): (Sweep, f64),
+    mut vector: V,
+    vfunc: VFunc,
+    sfunc: SFunc,
+) where
+    V::Scalar: Reference,
+    V: Vector,
+    VFunc: Fn(V) -> V,
+    SFunc: Fn(V::Scalar) -> f64,
+{
+    let points = sweep.points(1 << 14).collect::<Vec<_>>();
+    for chunk in points.chunks(V::width()) {
+        for (x, value) in vector.as_slice_mut().iter_mut().zip(chunk.iter().cycle()) {
+            *x = *value;
+        }
+        let output = vfunc(vector);
+        for (x, y) in vector.as_slice().iter().zip(output.as_slice()) {
+            let error = V::Scalar::ulp_error(*y, sfunc(*x));
+            assert!(
+                error <= max_ulp + V::Scalar::REFERENCE_ERROR,
+                "f({:?}) = {:?} is {} ULP from {:?}",
+                x,
+                y,
+                error,
+                sfunc(*x)
+            );
+        }
+    }
+}
+
+/// Checks that a binary op is within `max_ulp` units in the last place of a reference over a dense
+/// grid of arguments.
+#[inline]
+fn sweep_binary_op_impl<V, VFunc, SFunc>(
+    ((a_sweep, b_sweep), max_ulp): ((Sweep, Sweep), f64),
+    (mut a, mut b): (V, V),
+    vfunc: VFunc,
+    sfunc: SFunc,
+) where
+    V::Scalar: Reference,
+    V: Vector,
+    VFunc: Fn(V, V) -> V,
+    SFunc: Fn(V::Scalar, V::Scalar) -> f64,
+{
+    let points = a_sweep
+        .points(1 << 7)
+        .flat_map(|x| b_sweep.points(1 << 7).map(move |y| (x, y)))
+        .collect::<Vec<_>>();
+    for chunk in points.chunks(V::width()) {
+        for ((x, y), value) in a
+            .as_slice_mut()
+            .iter_mut()
+            .zip(b.as_slice_mut().iter_mut())
+            .zip(chunk.iter().cycle())
+        {
+            *x = value.0;
+            *y = value.1;
+        }
+        let output = vfunc(a, b);
+        for ((x, y), z) in a.as_slice().iter().zip(b.as_slice()).zip(output.as_slice()) {
+            let error = V::Scalar::ulp_error(*z, sfunc(*x, *y));
+            assert!(
+                error <= max_ulp + V::Scalar::REFERENCE_ERROR,
+                "f({:?}, {:?}) = {:?} is {} ULP from {:?}",
+                x,
+                y,
+                z,
+                error,
+                sfunc(*x, *y)
+            );
+        }
+    }
+}
+
+#[inline]
+fn ternary_op_impl<D, V, VFunc, SFunc>(
+    distribution: D,
+    (mut a, mut b, mut c): (V, V, V),
+    vfunc: VFunc,
+    sfunc: SFunc,
+) where
+    V::Scalar: Num + core::fmt::Debug + Copy,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Ops,
+    VFunc: Fn(V, V, V) -> V,
+    SFunc: Fn(V::Scalar, V::Scalar, V::Scalar) -> V::Scalar,
+{
+    let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
+    for x in a.as_slice_mut() {
+        *x = rng.sample(distribution);
+    }
+    for x in b.as_slice_mut() {
+        *x = rng.sample(distribution);
+    }
+    for x in c.as_slice_mut() {
+        *x = rng.sample(distribution);
+    }
+
+    let output = vfunc(a, b, c);
+    for i in 0..V::width() {
+        assert_eq!(output[i], sfunc(a[i], b[i], c[i]))
+    }
+}
+
+#[inline]
+fn binary_scalar_op_impl<D, V, VFunc, SFunc>(distribution: D, mut a: V, vfunc: VFunc, sfunc: SFunc)
+where
+    V::Scalar: Num + core::fmt::Debug + Copy,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Ops,
+    VFunc: Fn(V, V::Scalar) -> V,
+    SFunc: Fn(V::Scalar, V::Scalar) -> V::Scalar,
+{
+    let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
+    let b = rng.sample(distribution);
+    for x in a.as_slice_mut() {
+        *x = rng.sample(distribution);
+    }
+
+    let output = vfunc(a, b);
+    for i in 0..V::width() {
+        assert_eq!(output[i], sfunc(a[i], b))
+    }
+}
+
+#[inline]
+fn assign_op_impl<D, V, VFunc, SFunc>(
+    distribution: D,
+    (mut a, mut b): (V, V),
+    vfunc: VFunc,
+    sfunc: SFunc,
+) where
+    V::Scalar: Num + core::fmt::Debug + Copy,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Ops,
+    VFunc: Fn(&mut V, V),
+    SFunc: Fn(&mut V::Scalar, V::Scalar),
+{
+    let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
+    for x in a.as_slice_mut() {
+        *x = rng.sample(distribution);
+    }
+    for x in b.as_slice_mut() {
+        *x = rng.sample(distribution);
+    }
+
+    let mut output: V = a;
+    vfunc(&mut output, b);
+    for i in 0..V::width() {
+        sfunc(&mut a[i], b[i]);
+        assert_eq!(output[i], a[i])
+    }
+}
+
+#[inline]
+fn assign_scalar_op_impl<D, V, VFunc, SFunc>(distribution: D, mut a: V, vfunc: VFunc, sfunc: SFunc)
+where
+    V::Scalar: Num + core::fmt::Debug + Copy,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Ops,
+    VFunc: Fn(&mut V, V::Scalar),
+    SFunc: Fn(&mut V::Scalar, V::Scalar),
+{
+    let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
+    let b = rng.sample(distribution);
+    for x in a.as_slice_mut() {
+        *x = rng.sample(distribution);
+    }
+
+    let mut output: V = a;
+    vfunc(&mut output, b);
+    for i in 0..V::width() {
+        sfunc(&mut a[i], b);
+        assert_eq!(output[i], a[i])
+    }
+}
+
+#[inline]
+fn compare_op_impl<D, V, VFunc, SFunc>(
+    distribution: D,
+    (mut a, mut b): (V, V),
+    vfunc: VFunc,
+    sfunc: SFunc,
+) where
+    V::Scalar: PartialOrd + core::fmt::Debug + Copy,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Compare,
+    VFunc: Fn(V, V) -> V::Mask,
+    SFunc: Fn(&V::Scalar, &V::Scalar) -> bool,
+{
+    let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
+    for x in a.as_slice_mut() {
+        *x = rng.sample(distribution);
+    }
+    for x in b.as_slice_mut() {
+        *x = rng.sample(distribution);
+    }
+
+    // Make some lanes equal
+    for (x, y) in a.as_slice().iter().zip(b.as_slice_mut()).step_by(2) {
+        *y = *x;
+    }
+
+    let output = vfunc(a, b);
+    let (a, b) = (a.as_slice(), b.as_slice());
+    for i in 0..V::width() {
+        assert_eq!(output.test(i), sfunc(&a[i], &b[i]), "lane {}", i);
+    }
+    assert_eq!(output.any(), (0..V::width()).any(|i| sfunc(&a[i], &b[i])));
+    assert_eq!(output.all(), (0..V::width()).all(|i| sfunc(&a[i], &b[i])));
+}
+
+#[inline]
+fn select_op_impl<D, V, VFunc, SFunc>(
+    distribution: D,
+    (mut a, mut b): (V, V),
+    vfunc: VFunc,
+    sfunc: SFunc,
+) where
+    V::Scalar: PartialOrd + core::fmt::Debug + Copy,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Compare,
+    VFunc: Fn(V::Mask, V, V) -> V,
+    SFunc: Fn(bool, V::Scalar, V::Scalar) -> V::Scalar,
+{
+    let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
+    for x in a.as_slice_mut() {
+        *x = rng.sample(distribution);
+    }
+    for x in b.as_slice_mut() {
+        *x = rng.sample(distribution);
+    }
+
+    let mask = a.lanes_lt(b);
+    let output = vfunc(mask, a, b);
+    for i in 0..V::width() {
+        assert_eq!(
+            output.as_slice()[i],
+            sfunc(mask.test(i), a.as_slice()[i], b.as_slice()[i])
+        );
+    }
+}
+
+#[inline]
+fn read_partial_op_impl<D, V, VFunc, SFunc>(distribution: D, mut a: V, vfunc: VFunc, sfunc: SFunc)
+where
+    V::Scalar: PartialEq + core::fmt::Debug + Copy,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Vector,
+    VFunc: Fn(V::Token, &[V::Scalar], V::Scalar) -> V,
+    SFunc: Fn(bool, V::Scalar, V::Scalar) -> V::Scalar,
+{
+    let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
+    for x in a.as_slice_mut() {
+        *x = rng.sample(distribution);
+    }
+    let fill = rng.sample(distribution);
+
+    for len in 0..=V::width() {
+        let output = vfunc(a.to_token(), &a.as_slice()[..len], fill);
+        for i in 0..V::width() {
+            assert_eq!(
+                output.as_slice()[i],
+                sfunc(i < len, a.as_slice()[i], fill),
+                "length {}, lane {}",
+                len,
+                i
+            );
+        }
+    }
+}
+
+#[inline]
+fn write_partial_op_impl<D, V, VFunc, SFunc>(
+    distribution: D,
+    (mut a, mut b): (V, V),
+    vfunc: VFunc,
+    sfunc: SFunc,
+) where
+    V::Scalar: PartialEq + core::fmt::Debug + Copy,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Vector,
+    VFunc: Fn(V, &mut [V::Scalar]),
+    SFunc: Fn(bool, V::Scalar, V::Scalar) -> V::Scalar,
+{
+    let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
+    for x in a.as_slice_mut() {
+        *x = rng.sample(distribution);
+    }
+    for x in b.as_slice_mut() {
+        *x = rng.sample(distribution);
+    }
+
+    for len in 0..=V::width() {
+        let mut output = b;
+        vfunc(a, &mut output.as_slice_mut()[..len]);
+        for i in 0..V::width() {
+            assert_eq!(
+                output.as_slice()[i],
+                sfunc(i < len, a.as_slice()[i], b.as_slice()[i]),
+                "length {}, lane {}",
+                len,
+                i
+            );
+        }
+    }
+}
+
+#[inline]
+fn gather_op_impl<D, V, VFunc, SFunc>(distribution: D, vector: V, vfunc: VFunc, sfunc: SFunc)
+where
+    V::Scalar: PartialEq + core::fmt::Debug + Copy,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Vector,
+    VFunc: Fn(V::Token, &[V::Scalar], &[usize]) -> V,
+    SFunc: Fn(&[V::Scalar], usize) -> V::Scalar,
+{
+    let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
+    let source = (0..V::width() * 3)
+        .map(|_| rng.sample(distribution))
+        .collect::<Vec<_>>();
+    let indices = (0..V::width())
+        .map(|_| rng.gen_range(0, source.len()))
+        .collect::<Vec<_>>();
+
+    let output = vfunc(vector.to_token(), &source, &indices);
+    for (i, index) in indices.iter().enumerate() {
+        assert_eq!(
+            output.as_slice()[i],
+            sfunc(&source, *index),
+            "index {}, lane {}",
+            index,
+            i
+        );
+    }
+}
+
+#[inline]
+fn read_strided_op_impl<D, V, VFunc, SFunc>(distribution: D, vector: V, vfunc: VFunc, sfunc: SFunc)
+where
+    V::Scalar: PartialEq + core::fmt::Debug + Copy,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Vector,
+    VFunc: Fn(V::Token, &[V::Scalar], usize) -> V,
+    SFunc: Fn(&[V::Scalar], usize) -> V::Scalar,
+{
+    let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
+    for stride in 0..=4 {
+        // The source is as short as allowed
+        let source = (0..(V::width() - 1) * stride + 1)
+            .map(|_| rng.sample(distribution))
+            .collect::<Vec<_>>();
+
+        let output = vfunc(vector.to_token(), &source, stride);
+        for i in 0..V::width() {
+            assert_eq!(
+                output.as_slice()[i],
+                sfunc(&source, i * stride),
+                "stride {}, lane {}",
+                stride,
+                i
+            );
+        }
+    }
+}
+
+#[inline]
+fn scatter_op_impl<D, V, VFunc, SFunc>(distribution: D, mut a: V, vfunc: VFunc, sfunc: SFunc)
+where
+    V::Scalar: PartialEq + core::fmt::Debug + Copy,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Vector,
+    VFunc: Fn(V, &mut [V::Scalar], &[usize]),
+    SFunc: Fn(&mut [V::Scalar], usize, V::Scalar),
+{
+    let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
+    for x in a.as_slice_mut() {
+        *x = rng.sample(distribution);
+    }
+    let destination = (0..V::width() * 3)
+        .map(|_| rng.sample(distribution))
+        .collect::<Vec<_>>();
+    let mut indices = (0..V::width())
+        .map(|_| rng.gen_range(0, destination.len()))
+        .collect::<Vec<_>>();
+
+    // Repeat an index, which must be written by the highest lane
+    indices[V::width() - 1] = indices[0];
+
+    let mut expected = destination.clone();
+    for (index, lane) in indices.iter().zip(a.as_slice()) {
+        sfunc(&mut expected, *index, *lane);
+    }
+
+    let mut output = destination;
+    vfunc(a, &mut output, &indices);
+    assert_eq!(output, expected, "indices {:?}", indices);
+}
+
+#[inline]
+fn write_strided_op_impl<D, V, VFunc, SFunc>(distribution: D, mut a: V, vfunc: VFunc, sfunc: SFunc)
+where
+    V::Scalar: PartialEq + core::fmt::Debug + Copy,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Vector,
+    VFunc: Fn(V, &mut [V::Scalar], usize),
+    SFunc: Fn(&mut [V::Scalar], usize, V::Scalar),
+{
+    let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
+    for x in a.as_slice_mut() {
+        *x = rng.sample(distribution);
+    }
+    for stride in 0..=4 {
+        // The destination is as short as allowed
+        let destination = (0..(V::width() - 1) * stride + 1)
+            .map(|_| rng.sample(distribution))
+            .collect::<Vec<_>>();
+
+        let mut expected = destination.clone();
+        for (i, lane) in a.as_slice().iter().enumerate() {
+            sfunc(&mut expected, i * stride, *lane);
+        }
+
+        let mut output = destination;
+        vfunc(a, &mut output, stride);
+        assert_eq!(output, expected, "stride {}", stride);
+    }
+}
+
+fn deinterleave2_array<V: Vector>(token: V::Token, from: &[V::Scalar]) -> [V; 2] {
+    let (a, b) = V::deinterleave2(token, from);
+    [a, b]
+}
+
+fn deinterleave3_array<V: Vector>(token: V::Token, from: &[V::Scalar]) -> [V; 3] {
+    let (a, b, c) = V::deinterleave3(token, from);
+    [a, b, c]
+}
+
+fn deinterleave4_array<V: Vector>(token: V::Token, from: &[V::Scalar]) -> [V; 4] {
+    let (a, b, c, d) = V::deinterleave4(token, from);
+    [a, b, c, d]
+}
+
+fn interleave2_array<V: Vector>([a, b]: [V; 2], to: &mut [V::Scalar]) {
+    a.interleave2(b, to)
+}
+
+fn interleave3_array<V: Vector>([a, b, c]: [V; 3], to: &mut [V::Scalar]) {
+    a.interleave3(b, c, to)
+}
+
+fn interleave4_array<V: Vector>([a, b, c, d]: [V; 4], to: &mut [V::Scalar]) {
+    a.interleave4(b, c, d, to)
+}
+
+#[inline]
+fn deinterleave_op_impl<D, V, VFunc, SFunc, const N: usize>(
+    distribution: D,
+    vector: V,
+    vfunc: VFunc,
+    sfunc: SFunc,
+) where
+    V::Scalar: PartialEq + core::fmt::Debug + Copy,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Vector,
+    VFunc: Fn(V::Token, &[V::Scalar]) -> [V; N],
+    SFunc: Fn(&[V::Scalar], usize) -> V::Scalar,
+{
+    let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
+    let source = (0..V::width() * N)
+        .map(|_| rng.sample(distribution))
+        .collect::<Vec<_>>();
+
+    let output = vfunc(vector.to_token(), &source);
+    for (j, output) in output.iter().enumerate() {
+        for i in 0..V::width() {
+            assert_eq!(
+                output.as_slice()[i],
+                sfunc(&source, N * i + j),
+                "vector {}, lane {}",
+                j,
+                i
+            );
+        }
+    }
+}
+
+#[inline]
+fn interleave_op_impl<D, V, VFunc, SFunc, const N: usize>(
+    distribution: D,
+    vector: V,
+    vfunc: VFunc,
+    sfunc: SFunc,
+) where
+    V::Scalar: PartialEq + core::fmt::Debug + Copy,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Vector,
+    VFunc: Fn([V; N], &mut [V::Scalar]),
+    SFunc: Fn(&mut [V::Scalar], usize, V::Scalar),
+{
+    let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
+    let mut vectors = [vector; N];
+    for x in vectors.iter_mut().flat_map(|v| v.as_slice_mut()) {
+        *x = rng.sample(distribution);
+    }
+
+    // Extra elements ensure nothing is written past the interleaved vectors
+    let destination = (0..V::width() * N + 1)
+        .map(|_| rng.sample(distribution))
+        .collect::<Vec<_>>();
+
+    let mut expected = destination.clone();
+    for (j, vector) in vectors.iter().enumerate() {
+        for (i, lane) in vector.as_slice().iter().enumerate() {
+            sfunc(&mut expected, N * i + j, *lane);
+        }
+    }
+
+    let mut output = destination;
+    vfunc(vectors, &mut output);
+    assert_eq!(output, expected);
+}
+
+/// Duplicates the even lanes, which is valid for any width.
+struct DuplicateEven;
+
+impl ShuffleIndices for DuplicateEven {
+    const INDICES: &'static [usize] = &{
+        let mut indices = [0; 64];
+        let mut i = 0;
+        while i < indices.len() {
+            indices[i] = i & !1;
+            i += 1;
+        }
+        indices
+    };
+}
+
+fn rotate_lanes_left3<V: Vector>(vector: V) -> V {
+    vector.rotate_lanes_left::<3>()
+}
+
+fn broadcast_last_lane<V: Vector>(vector: V) -> V {
+    match V::width() {
+        1 => vector.broadcast_lane::<0>(),
+        2 => vector.broadcast_lane::<1>(),
+        4 => vector.broadcast_lane::<3>(),
+        8 => vector.broadcast_lane::<7>(),
+        16 => vector.broadcast_lane::<15>(),
+        32 => vector.broadcast_lane::<31>(),
+        _ => vector.broadcast_lane::<63>(),
+    }
+}
+
+fn shuffle_duplicate_even<V: Vector>(vector: V) -> V {
+    vector.shuffle::<DuplicateEven>()
+}
+
+fn swap_halves<V>(vector: V) -> V
+where
+    V: Vector,
+    V::Width: Half,
+    V::Scalar: Scalar<V::Token, <V::Width as Half>::Halved>,
+{
+    let (low, high) = vector.split();
+    V::concat(high, low)
+}
+
+#[inline]
+fn permute_op_impl<D, V, VFunc, SFunc>(distribution: D, mut vector: V, vfunc: VFunc, sfunc: SFunc)
+where
+    V::Scalar: PartialEq + core::fmt::Debug + Copy,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Vector,
+    VFunc: Fn(V) -> V,
+    SFunc: Fn(&[V::Scalar], usize) -> V::Scalar,
+{
+    let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
+    for x in vector.as_slice_mut() {
+        *x = rng.sample(distribution);
+    }
+
+    let output = vfunc(vector);
+    for i in 0..V::width() {
+        assert_eq!(
+            output.as_slice()[i],
+            sfunc(vector.as_slice(), i),
+            "lane {}",
+            i
+        );
+    }
+}
+
+#[inline]
+fn vectors_op_impl<D, V, VFunc, SFunc>(distribution: D, vector: V, vfunc: VFunc, sfunc: SFunc)
+where
+    V::Scalar: PartialEq + core::fmt::Debug + Copy,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Vector,
+    VFunc: Fn(V, V) -> V,
+    SFunc: Fn(V::Scalar, V::Scalar) -> V::Scalar,
+{
+    let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
+    let source = (0..2 * V::width() + 1)
+        .map(|_| rng.sample(distribution))
+        .collect::<Vec<_>>();
+    let zero = V::zeroed(vector.to_token()).as_slice()[0];
+
+    for len in 0..=source.len() {
+        let mut count = 0;
+        for (i, (v, lanes)) in Vectors::<V>::new(vector.to_token(), &source[..len]).enumerate() {
+            let start = i * V::width();
+            assert_eq!(lanes, (len - start).min(V::width()));
+            for lane in 0..V::width() {
+                let expected = if lane < lanes {
+                    source[start + lane]
+                } else {
+                    zero
+                };
+                assert_eq!(
+                    v.as_slice()[lane],
+                    expected,
+                    "length {}, lane {}",
+                    len,
+                    lane
+                );
+            }
+            count += 1;
+        }
+        assert_eq!(count, source[..len].chunks(V::width()).count());
+
+        let mut output = source.clone();
+        for (mut v, _) in VectorsMut::<V>::new(vector.to_token(), &mut output[..len]) {
+            *v = vfunc(*v, *v);
+        }
+        for i in 0..source.len() {
+            let expected = if i < len {
+                sfunc(source[i], source[i])
+            } else {
+                source[i]
+            };
+            assert_eq!(output[i], expected, "length {}, index {}", len, i);
+        }
+    }
+}
+
+#[inline]
+fn map_into_op_impl<D, V, VFunc, SFunc>(distribution: D, vector: V, vfunc: VFunc, sfunc: SFunc)
+where
+    V::Scalar: generic_simd::scalar::Scalar<V::Token, V::Width, Vector = V>
+        + Native<V::Token, Width = V::Width>
+        + PartialEq
+        + core::fmt::Debug
+        + Copy,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Vector,
+    VFunc: Fn(V, V) -> V,
+    SFunc: Fn(V::Scalar, V::Scalar) -> V::Scalar,
+{
+    let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
+    let source = (0..4 * V::width() + 3)
+        .map(|_| rng.sample(distribution))
+        .collect::<Vec<_>>();
+
+    // Offset the slices to exercise both aligned and unaligned starts.
+    for offset in 0..2 {
+        for len in 0..source.len() - offset {
+            let from = &source[offset..offset + len];
+            let mut output = vec![vector.as_slice()[0]; len + 1];
+            let to = &mut output[1 - offset..1 - offset + len];
+            slice::map_into(
+                vector.to_token(),
+                from,
+                to,
+                |v| vfunc(v, v),
+                |x| sfunc(x, x),
+            );
+            for i in 0..len {
+                assert_eq!(
+                    to[i],
+                    sfunc(from[i], from[i]),
+                    "length {}, index {}",
+                    len,
+                    i
+                );
+            }
+        }
+    }
+}
+
+#[inline]
+fn zip_map_op_impl<D, V, VFunc, SFunc>(distribution: D, vector: V, vfunc: VFunc, sfunc: SFunc)
+where
+    V::Scalar: generic_simd::scalar::Scalar<V::Token, V::Width, Vector = V>
+        + Native<V::Token, Width = V::Width>
+        + PartialEq
+        + core::fmt::Debug
+        + Copy,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Vector,
+    VFunc: Fn(V, V) -> V,
+    SFunc: Fn(V::Scalar, V::Scalar) -> V::Scalar,
+{
+    let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
+    let source = (0..8 * V::width() + 4)
+        .map(|_| rng.sample(distribution))
+        .collect::<Vec<_>>();
+
+    // Offset the slices to exercise both aligned and unaligned starts.
+    for offset in 0..2 {
+        for len in 0..source.len() / 2 - offset {
+            let a = &source[offset..offset + len];
+            let b = &source[source.len() / 2..source.len() / 2 + len];
+            let mut output = vec![vector.as_slice()[0]; len + 1];
+            let to = &mut output[1 - offset..1 - offset + len];
+            slice::zip_map(vector.to_token(), a, b, to, &vfunc, &sfunc);
+            for i in 0..len {
+                assert_eq!(to[i], sfunc(a[i], b[i]), "length {}, index {}", len, i);
+            }
+        }
+    }
+}
+
+#[inline]
+fn slice_reduce_op_impl<D, V, VFunc, SFunc>(distribution: D, vector: V, vfunc: VFunc, sfunc: SFunc)
+where
+    V::Scalar: generic_simd::scalar::Scalar<V::Token, V::Width, Vector = V>
+        + Native<V::Token, Width = V::Width>
+        + PartialEq
+        + core::fmt::Debug
+        + Copy,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Vector,
+    VFunc: Fn(V, V) -> V,
+    SFunc: Fn(V::Scalar, V::Scalar) -> V::Scalar,
+{
+    let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
+    let source = (0..4 * V::width() + 3)
+        .map(|_| rng.sample(distribution))
+        .collect::<Vec<_>>();
+    let init = V::zeroed(vector.to_token()).as_slice()[0];
+
+    // Offset the slice to exercise both aligned and unaligned starts.
+    for offset in 0..2 {
+        for len in 0..source.len() - offset {
+            let from = &source[offset..offset + len];
+            let expected = from.iter().fold(init, |acc, x| sfunc(acc, *x));
+            assert_eq!(
+                slice::reduce(vector.to_token(), from, init, &vfunc, &sfunc),
+                expected,
+                "length {}",
+                len
+            );
+        }
+    }
+}
+
+#[inline]
+fn overlapping_op_impl<D, V, VFunc, SFunc>(distribution: D, vector: V, vfunc: VFunc, sfunc: SFunc)
+where
+    V::Scalar: PartialEq + core::fmt::Debug + Copy,
+    D: rand::distributions::Distribution<V::Scalar> + Copy,
+    V: Vector,
+    VFunc: Fn(V, V) -> V,
+    SFunc: Fn(V::Scalar, V::Scalar) -> V::Scalar,
+{
+    let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
+    let mut source = (0..2 * V::width() + 3)
+        .map(|_| rng.sample(distribution))
+        .collect::<Vec<_>>();
+    let len = source.len() - V::width() + 1;
+
+    for step in 1..=3 {
+        let overlapping = Overlapping::<V>::new(vector.to_token(), &source);
+        let expected = (0..len).step_by(step).collect::<Vec<_>>();
+        let iter = overlapping.windows_step(step);
+        assert_eq!(iter.len(), expected.len(), "step {}", step);
+        for (v, index) in iter.zip(expected) {
+            assert_eq!(v.as_slice(), &source[index..index + V::width()]);
+        }
     }
+    assert_eq!(
+        Overlapping::<V>::new(vector.to_token(), &source)
+            .iter()
+            .len(),
+        len
+    );
+
+    // Each vector is written back before the next overlapping vector is read.
+    let mut expected = source.clone();
+    for index in 0..len {
+        for x in &mut expected[index..index + V::width()] {
+            *x = sfunc(*x, *x);
+        }
+    }
+    let mut overlapping = OverlappingMut::<V>::new(vector.to_token(), &mut source);
+    overlapping.for_each_mut(|v| *v = vfunc(*v, *v));
+    assert_eq!(source, expected);
 }
 
 #[inline]
-fn assign_scalar_op_impl<D, V, VFunc, SFunc>(distribution: D, mut a: V, vfunc: VFunc, sfunc: SFunc)
+fn reduce_op_impl<D, V, VFunc, SFunc>(distribution: D, mut a: V, vfunc: VFunc, sfunc: SFunc)
 where
-    V::Scalar: Num + core::ops::Neg<Output = V::Scalar> + core::fmt::Debug + Copy,
+    V::Scalar: PartialEq + core::fmt::Debug + Copy,
     D: rand::distributions::Distribution<V::Scalar> + Copy,
-    V: Signed,
-    VFunc: Fn(&mut V, V::Scalar),
-    SFunc: Fn(&mut V::Scalar, V::Scalar),
+    V: Vector,
+    VFunc: Fn(V) -> V::Scalar,
+    SFunc: Fn(V::Scalar, V::Scalar) -> V::Scalar,
 {
     let mut rng = rand_pcg::Pcg32::seed_from_u64(999);
-    let b = rng.sample(distribution);
     for x in a.as_slice_mut() {
         *x = rng.sample(distribution);
     }
 
-    let mut output: V = a;
-    vfunc(&mut output, b);
-    for i in 0..V::width() {
-        sfunc(&mut a[i], b);
-        assert_eq!(output[i], a[i])
-    }
+    let expected = a.as_slice()[1..]
+        .iter()
+        .fold(a.as_slice()[0], |acc, x| sfunc(acc, *x));
+    assert_eq!(vfunc(a), expected);
 }
 
 macro_rules! ops_test {
     {
-        $token:ident, $type:ty
+        $token:ident, $type:ty, $distribution:expr, $kind:ident
     } => {
         pub mod width_native {
             use super::*;
-            ops_test! { @wrapper $token, $type, zeroed_native }
+            ops_test! { @wrapper $token, $type, $distribution, $kind, zeroed_native }
+            ops_test! { @slice $token, $type, $distribution, $kind, zeroed_native }
         }
         pub mod width_1 {
             use super::*;
-            ops_test! { @wrapper $token, $type, zeroed1 }
+            ops_test! { @wrapper $token, $type, $distribution, $kind, zeroed1 }
         }
         pub mod width_2 {
             use super::*;
-            ops_test! { @wrapper $token, $type, zeroed2 }
+            ops_test! { @wrapper $token, $type, $distribution, $kind, zeroed2 }
+            ops_test! { @split $token, $type, $distribution, zeroed2 }
         }
         pub mod width_4 {
             use super::*;
-            ops_test! { @wrapper $token, $type, zeroed4 }
+            ops_test! { @wrapper $token, $type, $distribution, $kind, zeroed4 }
+            ops_test! { @split $token, $type, $distribution, zeroed4 }
         }
         pub mod width_8 {
             use super::*;
-            ops_test! { @wrapper $token, $type, zeroed8 }
-        }
-    };
-    {
-        @wrapper $token:ident, $type:ty, $init:ident
-    } => {
-        ops_test! { @impl $type, $init, add,               binary_op_impl,        $token, core::ops::Add::add }
-        ops_test! { @impl $type, $init, sub,               binary_op_impl,        $token, core::ops::Sub::sub }
-        ops_test! { @impl $type, $init, mul,               binary_op_impl,        $token, core::ops::Mul::mul }
-        ops_test! { @impl $type, $init, div,               binary_op_impl,        $token, core::ops::Div::div }
-        ops_test! { @impl $type, $init, add_scalar,        binary_scalar_op_impl, $token, core::ops::Add::add }
-        ops_test! { @impl $type, $init, sub_scalar,        binary_scalar_op_impl, $token, core::ops::Sub::sub }
-        ops_test! { @impl $type, $init, mul_scalar,        binary_scalar_op_impl, $token, core::ops::Mul::mul }
-        ops_test! { @impl $type, $init, div_scalar,        binary_scalar_op_impl, $token, core::ops::Div::div }
-        ops_test! { @impl $type, $init, add_assign,        assign_op_impl,        $token, core::ops::AddAssign::add_assign }
-        ops_test! { @impl $type, $init, sub_assign,        assign_op_impl,        $token, core::ops::SubAssign::sub_assign }
-        ops_test! { @impl $type, $init, mul_assign,        assign_op_impl,        $token, core::ops::MulAssign::mul_assign }
-        ops_test! { @impl $type, $init, div_assign,        assign_op_impl,        $token, core::ops::DivAssign::div_assign }
-        ops_test! { @impl $type, $init, add_assign_scalar, assign_scalar_op_impl, $token, core::ops::AddAssign::add_assign }
-        ops_test! { @impl $type, $init, sub_assign_scalar, assign_scalar_op_impl, $token, core::ops::SubAssign::sub_assign }
-        ops_test! { @impl $type, $init, mul_assign_scalar, assign_scalar_op_impl, $token, core::ops::MulAssign::mul_assign }
-        ops_test! { @impl $type, $init, div_assign_scalar, assign_scalar_op_impl, $token, core::ops::DivAssign::div_assign }
-        ops_test! { @impl $type, $init, neg,               unary_op_impl,         $token, core::ops::Neg::neg }
-    };
-    { @distribution f32 } => { Standard };
-    { @distribution f64 } => { Standard };
-    { @distribution Complex<f32> } => { ComplexDistribution::new(Standard, Standard) };
-    { @distribution Complex<f64> } => { ComplexDistribution::new(Standard, Standard) };
+            ops_test! { @wrapper $token, $type, $distribution, $kind, zeroed8 }
+            ops_test! { @split $token, $type, $distribution, zeroed8 }
+        }
+        pub mod width_16 {
+            use super::*;
+            ops_test! { @wrapper $token, $type, $distribution, $kind, zeroed16 }
+            ops_test! { @split $token, $type, $distribution, zeroed16 }
+        }
+        pub mod width_32 {
+            use super::*;
+            ops_test! { @wrapper $token, $type, $distribution, $kind, zeroed32 }
+            ops_test! { @split $token, $type, $distribution, zeroed32 }
+        }
+        pub mod width_64 {
+            use super::*;
+            ops_test! { @wrapper $token, $type, $distribution, $kind, zeroed64 }
+            ops_test! { @split $token, $type, $distribution, zeroed64 }
+        }
+    };
+    {
+        @split $token:ident, $type:ty, $distribution:expr, $init:ident
+    } => {
+        ops_test! { @impl $type, $distribution, $init, swap_halves,       permute_op_impl,       $token, swap_halves,                           |x: &[$type], i| x[(i + x.len() / 2) % x.len()] }
+    };
+    {
+        @slice $token:ident, $type:ty, $distribution:expr, float, $init:ident
+    } => {
+        ops_test! { @slice $token, $type, Dyadic, exact, $init }
+    };
+    {
+        @slice $token:ident, $type:ty, $distribution:expr, complex, $init:ident
+    } => {
+        ops_test! { @slice $token, $type, ComplexDistribution::new(Dyadic, Dyadic), exact, $init }
+    };
+    {
+        @slice $token:ident, $type:ty, $distribution:expr, exact, $init:ident
+    } => {
+        ops_test! { @impl $type, $distribution, $init, map_into,          map_into_op_impl,      $token, core::ops::Add::add,                   core::ops::Add::add }
+        ops_test! { @impl $type, $distribution, $init, zip_map,           zip_map_op_impl,       $token, core::ops::Add::add,                   core::ops::Add::add }
+        ops_test! { @impl $type, $distribution, $init, slice_reduce,      slice_reduce_op_impl,  $token, core::ops::Add::add,                   core::ops::Add::add }
+        ops_test! { @impl $type, $distribution, $init, overlapping,       overlapping_op_impl,   $token, core::ops::Add::add,                   core::ops::Add::add }
+    };
+    {
+        @slice $token:ident, $type:ty, $distribution:expr, signed, $init:ident
+    } => {
+        ops_test! { @slice $token, $type, $distribution, unsigned, $init }
+    };
+    {
+        @slice $token:ident, $type:ty, $distribution:expr, unsigned, $init:ident
+    } => {
+        ops_test! { @impl $type, $distribution, $init, map_into,          map_into_op_impl,      $token, core::ops::Add::add,                   <$type>::wrapping_add }
+        ops_test! { @impl $type, $distribution, $init, zip_map,           zip_map_op_impl,       $token, core::ops::Add::add,                   <$type>::wrapping_add }
+        ops_test! { @impl $type, $distribution, $init, slice_reduce,      slice_reduce_op_impl,  $token, core::ops::Add::add,                   <$type>::wrapping_add }
+        ops_test! { @impl $type, $distribution, $init, overlapping,       overlapping_op_impl,   $token, core::ops::Add::add,                   <$type>::wrapping_add }
+    };
+    {
+        @wrapper $token:ident, $type:ty, $distribution:expr, float, $init:ident
+    } => {
+        ops_test! { @wrapper $token, $type, $distribution, arithmetic, $init }
+        ops_test! { @wrapper $token, $type, $distribution, compare, $init }
+        ops_test! { @impl $type, Dyadic, $init,        reduce_sum,        reduce_op_impl,        $token, Reduce::reduce_sum,                    core::ops::Add::add }
+        ops_test! { @impl $type, Dyadic, $init,        reduce_product,    reduce_op_impl,        $token, Reduce::reduce_product,                core::ops::Mul::mul }
+        ops_test! { @impl $type, $distribution, $init, reduce_min,        reduce_op_impl,        $token, ReduceOrd::reduce_min,                 <$type>::min }
+        ops_test! { @impl $type, $distribution, $init, reduce_max,        reduce_op_impl,        $token, ReduceOrd::reduce_max,                 <$type>::max }
+        ops_test! { @impl $type, Dyadic, $init,        mul_add,           ternary_op_impl,       $token, MulAdd::mul_add,                       |x, a, b| x * a + b }
+        ops_test! { @impl $type, Dyadic, $init,        mul_sub,           ternary_op_impl,       $token, MulAdd::mul_sub,                       |x, a, b| x * a - b }
+        ops_test! { @impl $type, Dyadic, $init,        min,               binary_op_impl,        $token, Float::min,                            <$type>::min }
+        ops_test! { @impl $type, Dyadic, $init,        max,               binary_op_impl,        $token, Float::max,                            <$type>::max }
+        ops_test! { @impl $type, Dyadic, $init,        clamp,             ternary_op_impl,       $token, Float::clamp,                          |x: $type, lo, hi| x.max(lo).min(hi) }
+        ops_test! { @impl $type, Dyadic, $init,        abs,               unary_op_impl,         $token, Float::abs,                            <$type>::abs }
+        ops_test! { @impl $type, Dyadic, $init,        copysign,          binary_op_impl,        $token, Float::copysign,                       <$type>::copysign }
+        ops_test! { @impl $type, WithNan, $init,       min_nan,           binary_nan_op_impl,    $token, Float::min,                            |a: $type, b| if a.is_nan() || b.is_nan() { <$type>::NAN } else { a.min(b) } }
+        ops_test! { @impl $type, WithNan, $init,       max_nan,           binary_nan_op_impl,    $token, Float::max,                            |a: $type, b| if a.is_nan() || b.is_nan() { <$type>::NAN } else { a.max(b) } }
+        ops_test! { @impl $type, Positive, $init,      sqrt,              unary_op_impl,         $token, Sqrt::sqrt,                            <$type>::sqrt }
+        ops_test! { @impl $type, Positive, $init,      recip,             unary_op_impl,         $token, Sqrt::recip,                           <$type>::recip }
+        ops_test! { @impl $type, Positive, $init,      rsqrt,             unary_op_impl,         $token, Sqrt::rsqrt,                           |x: $type| 1. / x.sqrt() }
+        ops_test! { @impl $type, Positive, $init,      recip_estimate,    estimate_op_impl,      $token, Sqrt::recip_estimate,                  <$type>::recip }
+        ops_test! { @impl $type, Positive, $init,      rsqrt_estimate,    estimate_op_impl,      $token, Sqrt::rsqrt_estimate,                  |x: $type| 1. / x.sqrt() }
+        ops_test! { @impl $type, Positive, $init,      recip_refined,     refined_op_impl,       $token, Sqrt::recip_refined,                   <$type>::recip }
+        ops_test! { @impl $type, Positive, $init,      rsqrt_refined,     refined_op_impl,       $token, Sqrt::rsqrt_refined,                   |x: $type| 1. / x.sqrt() }
+        ops_test! { @impl $type, Quarters, $init,      floor,             unary_op_impl,         $token, Round::floor,                          <$type>::floor }
+        ops_test! { @impl $type, Quarters, $init,      ceil,              unary_op_impl,         $token, Round::ceil,                           <$type>::ceil }
+        ops_test! { @impl $type, Quarters, $init,      round,             unary_op_impl,         $token, Round::round,                          <$type>::round }
+        ops_test! { @impl $type, Quarters, $init,      round_ties_even,   unary_op_impl,         $token, Round::round_ties_even,                |x: $type| if (x.round() - x).abs() == 0.5 { 2. * (x / 2.).round() } else { x.round() } }
+        ops_test! { @impl $type, Quarters, $init,      trunc,             unary_op_impl,         $token, Round::trunc,                          <$type>::trunc }
+        ops_test! { @impl $type, Quarters, $init,      fract,             unary_op_impl,         $token, Round::fract,                          <$type>::fract }
+        ops_test! { @impl $type, $distribution, $init, bitand,            bitwise_op_impl,       $token, core::ops::BitAnd::bitand,             core::ops::BitAnd::bitand }
+        ops_test! { @impl $type, $distribution, $init, bitor,             bitwise_op_impl,       $token, core::ops::BitOr::bitor,               core::ops::BitOr::bitor }
+        ops_test! { @impl $type, $distribution, $init, bitxor,            bitwise_op_impl,       $token, core::ops::BitXor::bitxor,             core::ops::BitXor::bitxor }
+        ops_test! { @impl $type, $distribution, $init, not,               bitwise_unary_op_impl, $token, core::ops::Not::not,                   core::ops::Not::not }
+        ops_test! { @impl $type, $distribution, $init, and_not,           bitwise_op_impl,       $token, Bitwise::and_not,                      |a, b| a & !b }
+    };
+    {
+        @wrapper $token:ident, $type:ty, $distribution:expr, complex, $init:ident
+    } => {
+        ops_test! { @wrapper $token, $type, $distribution, arithmetic, $init }
+        ops_test! { @impl $type, ComplexDistribution::new(Dyadic, Dyadic), $init, reduce_sum,     reduce_op_impl, $token, Reduce::reduce_sum,     core::ops::Add::add }
+        ops_test! { @impl $type, Dyadic,                                   $init, reduce_product, reduce_op_impl, $token, Reduce::reduce_product, core::ops::Mul::mul }
+        ops_test! { @impl $type, ComplexDistribution::new(Dyadic, Dyadic), $init, mul_add,        ternary_op_impl, $token, MulAdd::mul_add,        |x, a, b| x * a + b }
+        ops_test! { @impl $type, ComplexDistribution::new(Dyadic, Dyadic), $init, mul_sub,        ternary_op_impl, $token, MulAdd::mul_sub,        |x, a, b| x * a - b }
+    };
+    {
+        @wrapper $token:ident, $type:ty, $distribution:expr, compare, $init:ident
+    } => {
+        ops_test! { @impl $type, $distribution, $init, lanes_eq,          compare_op_impl,       $token, Compare::lanes_eq,                     PartialEq::eq }
+        ops_test! { @impl $type, $distribution, $init, lanes_ne,          compare_op_impl,       $token, Compare::lanes_ne,                     PartialEq::ne }
+        ops_test! { @impl $type, $distribution, $init, lanes_lt,          compare_op_impl,       $token, Compare::lanes_lt,                     PartialOrd::lt }
+        ops_test! { @impl $type, $distribution, $init, lanes_le,          compare_op_impl,       $token, Compare::lanes_le,                     PartialOrd::le }
+        ops_test! { @impl $type, $distribution, $init, lanes_gt,          compare_op_impl,       $token, Compare::lanes_gt,                     PartialOrd::gt }
+        ops_test! { @impl $type, $distribution, $init, lanes_ge,          compare_op_impl,       $token, Compare::lanes_ge,                     PartialOrd::ge }
+        ops_test! { @impl $type, $distribution, $init, select,            select_op_impl,        $token, Select::select,                        |mask, a, b| if mask { a } else { b } }
+    };
+    {
+        @wrapper $token:ident, $type:ty, $distribution:expr, arithmetic, $init:ident
+    } => {
+        ops_test! { @impl $type, $distribution, $init, add,               binary_op_impl,        $token, core::ops::Add::add,                   core::ops::Add::add }
+        ops_test! { @impl $type, $distribution, $init, sub,               binary_op_impl,        $token, core::ops::Sub::sub,                   core::ops::Sub::sub }
+        ops_test! { @impl $type, $distribution, $init, mul,               binary_op_impl,        $token, core::ops::Mul::mul,                   core::ops::Mul::mul }
+        ops_test! { @impl $type, $distribution, $init, div,               binary_op_impl,        $token, core::ops::Div::div,                   core::ops::Div::div }
+        ops_test! { @impl $type, $distribution, $init, add_scalar,        binary_scalar_op_impl, $token, core::ops::Add::add,                   core::ops::Add::add }
+        ops_test! { @impl $type, $distribution, $init, sub_scalar,        binary_scalar_op_impl, $token, core::ops::Sub::sub,                   core::ops::Sub::sub }
+        ops_test! { @impl $type, $distribution, $init, mul_scalar,        binary_scalar_op_impl, $token, core::ops::Mul::mul,                   core::ops::Mul::mul }
+        ops_test! { @impl $type, $distribution, $init, div_scalar,        binary_scalar_op_impl, $token, core::ops::Div::div,                   core::ops::Div::div }
+        ops_test! { @impl $type, $distribution, $init, add_assign,        assign_op_impl,        $token, core::ops::AddAssign::add_assign,      core::ops::AddAssign::add_assign }
+        ops_test! { @impl $type, $distribution, $init, sub_assign,        assign_op_impl,        $token, core::ops::SubAssign::sub_assign,      core::ops::SubAssign::sub_assign }
+        ops_test! { @impl $type, $distribution, $init, mul_assign,        assign_op_impl,        $token, core::ops::MulAssign::mul_assign,      core::ops::MulAssign::mul_assign }
+        ops_test! { @impl $type, $distribution, $init, div_assign,        assign_op_impl,        $token, core::ops::DivAssign::div_assign,      core::ops::DivAssign::div_assign }
+        ops_test! { @impl $type, $distribution, $init, add_assign_scalar, assign_scalar_op_impl, $token, core::ops::AddAssign::add_assign,      core::ops::AddAssign::add_assign }
+        ops_test! { @impl $type, $distribution, $init, sub_assign_scalar, assign_scalar_op_impl, $token, core::ops::SubAssign::sub_assign,      core::ops::SubAssign::sub_assign }
+        ops_test! { @impl $type, $distribution, $init, mul_assign_scalar, assign_scalar_op_impl, $token, core::ops::MulAssign::mul_assign,      core::ops::MulAssign::mul_assign }
+        ops_test! { @impl $type, $distribution, $init, div_assign_scalar, assign_scalar_op_impl, $token, core::ops::DivAssign::div_assign,      core::ops::DivAssign::div_assign }
+        ops_test! { @impl $type, $distribution, $init, neg,               unary_op_impl,         $token, core::ops::Neg::neg,                   core::ops::Neg::neg }
+        ops_test! { @impl $type, $distribution, $init, vectors,           vectors_op_impl,       $token, core::ops::Add::add,                   core::ops::Add::add }
+        ops_test! { @impl $type, $distribution, $init, pointer,           unary_op_impl,         $token, pointer_round_trip,                    core::convert::identity }
+        ops_test! { @impl $type, $distribution, $init, pointer_aligned,   unary_op_impl,         $token, pointer_round_trip_aligned,            core::convert::identity }
+        ops_test! { @impl $type, $distribution, $init, underlying,        unary_op_impl,         $token, underlying_round_trip,                 core::convert::identity }
+        ops_test! { @impl $type, $distribution, $init, read_partial,      read_partial_op_impl,  $token, Vector::read_partial,                  |in_range, a, b| if in_range { a } else { b } }
+        ops_test! { @impl $type, $distribution, $init, write_partial,     write_partial_op_impl, $token, Vector::write_partial,                 |in_range, a, b| if in_range { a } else { b } }
+        ops_test! { @impl $type, $distribution, $init, gather,            gather_op_impl,        $token, Gather::gather,                        |from: &[$type], index| from[index] }
+        ops_test! { @impl $type, $distribution, $init, read_strided,      read_strided_op_impl,  $token, Gather::read_strided,                  |from: &[$type], index| from[index] }
+        ops_test! { @impl $type, $distribution, $init, scatter,           scatter_op_impl,       $token, Vector::scatter,                       |to: &mut [$type], index, value| to[index] = value }
+        ops_test! { @impl $type, $distribution, $init, write_strided,     write_strided_op_impl, $token, Vector::write_strided,                 |to: &mut [$type], index, value| to[index] = value }
+        ops_test! { @impl $type, $distribution, $init, deinterleave2,     deinterleave_op_impl,  $token, deinterleave2_array,                   |from: &[$type], index| from[index] }
+        ops_test! { @impl $type, $distribution, $init, deinterleave3,     deinterleave_op_impl,  $token, deinterleave3_array,                   |from: &[$type], index| from[index] }
+        ops_test! { @impl $type, $distribution, $init, deinterleave4,     deinterleave_op_impl,  $token, deinterleave4_array,                   |from: &[$type], index| from[index] }
+        ops_test! { @impl $type, $distribution, $init, interleave2,       interleave_op_impl,    $token, interleave2_array,                     |to: &mut [$type], index, value| to[index] = value }
+        ops_test! { @impl $type, $distribution, $init, interleave3,       interleave_op_impl,    $token, interleave3_array,                     |to: &mut [$type], index, value| to[index] = value }
+        ops_test! { @impl $type, $distribution, $init, interleave4,       interleave_op_impl,    $token, interleave4_array,                     |to: &mut [$type], index, value| to[index] = value }
+        ops_test! { @impl $type, $distribution, $init, reverse,           permute_op_impl,       $token, Vector::reverse,                       |x: &[$type], i| x[x.len() - 1 - i] }
+        ops_test! { @impl $type, $distribution, $init, rotate_lanes_left, permute_op_impl,       $token, rotate_lanes_left3,                    |x: &[$type], i| x[(i + 3) % x.len()] }
+        ops_test! { @impl $type, $distribution, $init, broadcast_lane,    permute_op_impl,       $token, broadcast_last_lane,                   |x: &[$type], _| x[x.len() - 1] }
+        ops_test! { @impl $type, $distribution, $init, shuffle,           permute_op_impl,       $token, shuffle_duplicate_even,                |x: &[$type], i| x[i & !1] }
+    };
+    {
+        @wrapper $token:ident, $type:ty, $distribution:expr, unsigned, $init:ident
+    } => {
+        ops_test! { @impl $type, $distribution, $init, add,               binary_op_impl,        $token, core::ops::Add::add,                   <$type>::wrapping_add }
+        ops_test! { @impl $type, $distribution, $init, sub,               binary_op_impl,        $token, core::ops::Sub::sub,                   <$type>::wrapping_sub }
+        ops_test! { @impl $type, $distribution, $init, mul,               binary_op_impl,        $token, core::ops::Mul::mul,                   <$type>::wrapping_mul }
+        ops_test! { @impl $type, $distribution, $init, div,               binary_op_impl,        $token, core::ops::Div::div,                   <$type>::wrapping_div }
+        ops_test! { @impl $type, $distribution, $init, add_scalar,        binary_scalar_op_impl, $token, core::ops::Add::add,                   <$type>::wrapping_add }
+        ops_test! { @impl $type, $distribution, $init, sub_scalar,        binary_scalar_op_impl, $token, core::ops::Sub::sub,                   <$type>::wrapping_sub }
+        ops_test! { @impl $type, $distribution, $init, mul_scalar,        binary_scalar_op_impl, $token, core::ops::Mul::mul,                   <$type>::wrapping_mul }
+        ops_test! { @impl $type, $distribution, $init, div_scalar,        binary_scalar_op_impl, $token, core::ops::Div::div,                   <$type>::wrapping_div }
+        ops_test! { @impl $type, $distribution, $init, add_assign,        assign_op_impl,        $token, core::ops::AddAssign::add_assign,      |a: &mut $type, b| *a = a.wrapping_add(b) }
+        ops_test! { @impl $type, $distribution, $init, sub_assign,        assign_op_impl,        $token, core::ops::SubAssign::sub_assign,      |a: &mut $type, b| *a = a.wrapping_sub(b) }
+        ops_test! { @impl $type, $distribution, $init, mul_assign,        assign_op_impl,        $token, core::ops::MulAssign::mul_assign,      |a: &mut $type, b| *a = a.wrapping_mul(b) }
+        ops_test! { @impl $type, $distribution, $init, div_assign,        assign_op_impl,        $token, core::ops::DivAssign::div_assign,      |a: &mut $type, b| *a = a.wrapping_div(b) }
+        ops_test! { @impl $type, $distribution, $init, add_assign_scalar, assign_scalar_op_impl, $token, core::ops::AddAssign::add_assign,      |a: &mut $type, b| *a = a.wrapping_add(b) }
+        ops_test! { @impl $type, $distribution, $init, sub_assign_scalar, assign_scalar_op_impl, $token, core::ops::SubAssign::sub_assign,      |a: &mut $type, b| *a = a.wrapping_sub(b) }
+        ops_test! { @impl $type, $distribution, $init, mul_assign_scalar, assign_scalar_op_impl, $token, core::ops::MulAssign::mul_assign,      |a: &mut $type, b| *a = a.wrapping_mul(b) }
+        ops_test! { @impl $type, $distribution, $init, div_assign_scalar, assign_scalar_op_impl, $token, core::ops::DivAssign::div_assign,      |a: &mut $type, b| *a = a.wrapping_div(b) }
+        ops_test! { @impl $type, $distribution, $init, reduce_sum,        reduce_op_impl,        $token, Reduce::reduce_sum,                    <$type>::wrapping_add }
+        ops_test! { @impl $type, $distribution, $init, reduce_product,    reduce_op_impl,        $token, Reduce::reduce_product,                <$type>::wrapping_mul }
+        ops_test! { @impl $type, $distribution, $init, reduce_min,        reduce_op_impl,        $token, ReduceOrd::reduce_min,                 <$type>::min }
+        ops_test! { @impl $type, $distribution, $init, reduce_max,        reduce_op_impl,        $token, ReduceOrd::reduce_max,                 <$type>::max }
+        ops_test! { @impl $type, $distribution, $init, mul_add,           ternary_op_impl,       $token, MulAdd::mul_add,                       |x: $type, a, b| x.wrapping_mul(a).wrapping_add(b) }
+        ops_test! { @impl $type, $distribution, $init, mul_sub,           ternary_op_impl,       $token, MulAdd::mul_sub,                       |x: $type, a, b| x.wrapping_mul(a).wrapping_sub(b) }
+        ops_test! { @impl $type, $distribution, $init, bitand,            binary_op_impl,        $token, core::ops::BitAnd::bitand,             core::ops::BitAnd::bitand }
+        ops_test! { @impl $type, $distribution, $init, bitor,             binary_op_impl,        $token, core::ops::BitOr::bitor,               core::ops::BitOr::bitor }
+        ops_test! { @impl $type, $distribution, $init, bitxor,            binary_op_impl,        $token, core::ops::BitXor::bitxor,             core::ops::BitXor::bitxor }
+        ops_test! { @impl $type, $distribution, $init, not,               unary_op_impl,         $token, core::ops::Not::not,                   core::ops::Not::not }
+        ops_test! { @impl $type, $distribution, $init, and_not,           binary_op_impl,        $token, Bitwise::and_not,                      |a: $type, b: $type| a & !b }
+        ops_test! { @impl $type, $distribution, $init, vectors,           vectors_op_impl,       $token, core::ops::Add::add,                   <$type>::wrapping_add }
+        ops_test! { @impl $type, $distribution, $init, pointer,           unary_op_impl,         $token, pointer_round_trip,                    core::convert::identity }
+        ops_test! { @impl $type, $distribution, $init, pointer_aligned,   unary_op_impl,         $token, pointer_round_trip_aligned,            core::convert::identity }
+        ops_test! { @impl $type, $distribution, $init, underlying,        unary_op_impl,         $token, underlying_round_trip,                 core::convert::identity }
+        ops_test! { @impl $type, $distribution, $init, read_partial,      read_partial_op_impl,  $token, Vector::read_partial,                  |in_range, a, b| if in_range { a } else { b } }
+        ops_test! { @impl $type, $distribution, $init, write_partial,     write_partial_op_impl, $token, Vector::write_partial,                 |in_range, a, b| if in_range { a } else { b } }
+        ops_test! { @impl $type, $distribution, $init, gather,            gather_op_impl,        $token, Gather::gather,                        |from: &[$type], index| from[index] }
+        ops_test! { @impl $type, $distribution, $init, read_strided,      read_strided_op_impl,  $token, Gather::read_strided,                  |from: &[$type], index| from[index] }
+        ops_test! { @impl $type, $distribution, $init, scatter,           scatter_op_impl,       $token, Vector::scatter,                       |to: &mut [$type], index, value| to[index] = value }
+        ops_test! { @impl $type, $distribution, $init, write_strided,     write_strided_op_impl, $token, Vector::write_strided,                 |to: &mut [$type], index, value| to[index] = value }
+        ops_test! { @impl $type, $distribution, $init, deinterleave2,     deinterleave_op_impl,  $token, deinterleave2_array,                   |from: &[$type], index| from[index] }
+        ops_test! { @impl $type, $distribution, $init, deinterleave3,     deinterleave_op_impl,  $token, deinterleave3_array,                   |from: &[$type], index| from[index] }
+        ops_test! { @impl $type, $distribution, $init, deinterleave4,     deinterleave_op_impl,  $token, deinterleave4_array,                   |from: &[$type], index| from[index] }
+        ops_test! { @impl $type, $distribution, $init, interleave2,       interleave_op_impl,    $token, interleave2_array,                     |to: &mut [$type], index, value| to[index] = value }
+        ops_test! { @impl $type, $distribution, $init, interleave3,       interleave_op_impl,    $token, interleave3_array,                     |to: &mut [$type], index, value| to[index] = value }
+        ops_test! { @impl $type, $distribution, $init, interleave4,       interleave_op_impl,    $token, interleave4_array,                     |to: &mut [$type], index, value| to[index] = value }
+        ops_test! { @impl $type, $distribution, $init, reverse,           permute_op_impl,       $token, Vector::reverse,                       |x: &[$type], i| x[x.len() - 1 - i] }
+        ops_test! { @impl $type, $distribution, $init, rotate_lanes_left, permute_op_impl,       $token, rotate_lanes_left3,                    |x: &[$type], i| x[(i + 3) % x.len()] }
+        ops_test! { @impl $type, $distribution, $init, broadcast_lane,    permute_op_impl,       $token, broadcast_last_lane,                   |x: &[$type], _| x[x.len() - 1] }
+        ops_test! { @impl $type, $distribution, $init, shuffle,           permute_op_impl,       $token, shuffle_duplicate_even,                |x: &[$type], i| x[i & !1] }
+        ops_test! { @wrapper $token, $type, $distribution, compare, $init }
+    };
+    {
+        @wrapper $token:ident, $type:ty, $distribution:expr, signed, $init:ident
+    } => {
+        ops_test! { @wrapper $token, $type, $distribution, unsigned, $init }
+        ops_test! { @impl $type, $distribution, $init, neg,               unary_op_impl,         $token, core::ops::Neg::neg,                   <$type>::wrapping_neg }
+    };
     {
         @init unary_op_impl, $type:ty, $token:ident, $init:ident
     } => {
@@ -202,6 +1890,66 @@ macro_rules! ops_test {
     } => {
         (<$type>::$init($token), <$type>::$init($token))
     };
+    {
+        @init binary_nan_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        (<$type>::$init($token), <$type>::$init($token))
+    };
+    {
+        @init bitwise_unary_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        <$type>::$init($token)
+    };
+    {
+        @init bitwise_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        (<$type>::$init($token), <$type>::$init($token))
+    };
+    {
+        @init estimate_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        <$type>::$init($token)
+    };
+    {
+        @init refined_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        <$type>::$init($token)
+    };
+    {
+        @init ulp_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        <$type>::$init($token)
+    };
+    {
+        @init ulp_binary_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        (<$type>::$init($token), <$type>::$init($token))
+    };
+    {
+        @init special_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        <$type>::$init($token)
+    };
+    {
+        @init special_binary_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        (<$type>::$init($token), <$type>::$init($token))
+    };
+    {
+        @init sweep_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        <$type>::$init($token)
+    };
+    {
+        @init sweep_binary_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        (<$type>::$init($token), <$type>::$init($token))
+    };
+    {
+        @init ternary_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        (<$type>::$init($token), <$type>::$init($token), <$type>::$init($token))
+    };
     {
         @init binary_scalar_op_impl, $type:ty, $token:ident, $init:ident
     } => {
@@ -212,18 +1960,108 @@ macro_rules! ops_test {
     } => {
         (<$type>::$init($token), <$type>::$init($token))
     };
+    {
+        @init compare_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        (<$type>::$init($token), <$type>::$init($token))
+    };
+    {
+        @init select_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        (<$type>::$init($token), <$type>::$init($token))
+    };
+    {
+        @init read_partial_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        <$type>::$init($token)
+    };
+    {
+        @init write_partial_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        (<$type>::$init($token), <$type>::$init($token))
+    };
+    {
+        @init gather_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        <$type>::$init($token)
+    };
+    {
+        @init read_strided_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        <$type>::$init($token)
+    };
+    {
+        @init scatter_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        <$type>::$init($token)
+    };
+    {
+        @init write_strided_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        <$type>::$init($token)
+    };
+    {
+        @init deinterleave_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        <$type>::$init($token)
+    };
+    {
+        @init interleave_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        <$type>::$init($token)
+    };
+    {
+        @init permute_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        <$type>::$init($token)
+    };
+    {
+        @init vectors_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        <$type>::$init($token)
+    };
+    {
+        @init map_into_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        <$type>::$init($token)
+    };
+    {
+        @init zip_map_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        <$type>::$init($token)
+    };
+    {
+        @init slice_reduce_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        <$type>::$init($token)
+    };
+    {
+        @init overlapping_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        <$type>::$init($token)
+    };
+    {
+        @init reduce_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        <$type>::$init($token)
+    };
+    {
+        @init convert_op_impl, $type:ty, $token:ident, $init:ident
+    } => {
+        <$type>::$init($token)
+    };
     {
         @init assign_scalar_op_impl, $type:ty, $token:ident, $init:ident
     } => {
         <$type>::$init($token)
     };
     {
-        @impl $type:ty, $init:ident, $name:ident, $test:ident, $token:ident, $func:path
+        @impl $type:ty, $distribution:expr, $init:ident, $name:ident, $test:ident, $token:ident, $vfunc:path, $sfunc:expr
     } => {
         paste::paste! {
             #[dispatch($token)]
             pub fn [<$name _dispatch>]() {
-                $test(ops_test!(@distribution $type), ops_test!(@init $test, $type, $token, $init), $func, $func);
+                $test($distribution, ops_test!(@init $test, $type, $token, $init), $vfunc, $sfunc);
             }
 
             #[test]
@@ -250,6 +2088,24 @@ macro_rules! ops_test {
                 }
             }
 
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            #[test]
+            pub fn [<$name _avx2>]() {
+                use generic_simd::arch::Token as _;
+                if generic_simd::arch::x86::Avx2::new().is_some() {
+                    unsafe { [<$name _dispatch_avx2_fma_version>]() }
+                }
+            }
+
+            #[cfg(all(feature = "nightly", any(target_arch = "x86", target_arch = "x86_64")))]
+            #[test]
+            pub fn [<$name _avx512>]() {
+                use generic_simd::arch::Token as _;
+                if generic_simd::arch::x86::Avx512::new().is_some() {
+                    unsafe { [<$name _dispatch_avx512f_version>]() }
+                }
+            }
+
             #[cfg(all(feature = "nightly", target_arch = "aarch64"))]
             #[test]
             pub fn [<$name _neon>]() {
@@ -270,24 +2126,330 @@ macro_rules! ops_test {
     };
 }
 
+macro_rules! convert_test {
+    {
+        $token:ident, $type:ty, [$($name:ident: $distribution:expr, $vfunc:path => $sfunc:expr);*]
+    } => {
+        pub mod convert {
+            use super::*;
+            convert_test! { @width width_native, zeroed_native, $token, $type, [$($name: $distribution, $vfunc => $sfunc);*] }
+            convert_test! { @width width_1, zeroed1, $token, $type, [$($name: $distribution, $vfunc => $sfunc);*] }
+            convert_test! { @width width_2, zeroed2, $token, $type, [$($name: $distribution, $vfunc => $sfunc);*] }
+            convert_test! { @width width_4, zeroed4, $token, $type, [$($name: $distribution, $vfunc => $sfunc);*] }
+            convert_test! { @width width_8, zeroed8, $token, $type, [$($name: $distribution, $vfunc => $sfunc);*] }
+            convert_test! { @width width_16, zeroed16, $token, $type, [$($name: $distribution, $vfunc => $sfunc);*] }
+            convert_test! { @width width_32, zeroed32, $token, $type, [$($name: $distribution, $vfunc => $sfunc);*] }
+            convert_test! { @width width_64, zeroed64, $token, $type, [$($name: $distribution, $vfunc => $sfunc);*] }
+        }
+    };
+    {
+        @width $module:ident, $init:ident, $token:ident, $type:ty, [$($name:ident: $distribution:expr, $vfunc:path => $sfunc:expr);*]
+    } => {
+        pub mod $module {
+            use super::*;
+            $(
+            ops_test! { @impl $type, $distribution, $init, $name, convert_op_impl, $token, $vfunc, $sfunc }
+            )*
+        }
+    };
+}
+
+macro_rules! math_test {
+    {
+        $token:ident, $type:ty, [$($name:ident: $distribution:expr, $test:ident, $vfunc:path => $sfunc:expr);*]
+    } => {
+        pub mod transcendental {
+            use super::*;
+            math_test! { @width width_native, zeroed_native, $token, $type, [$($name: $distribution, $test, $vfunc => $sfunc);*] }
+            math_test! { @width width_1, zeroed1, $token, $type, [$($name: $distribution, $test, $vfunc => $sfunc);*] }
+            math_test! { @width width_8, zeroed8, $token, $type, [$($name: $distribution, $test, $vfunc => $sfunc);*] }
+        }
+    };
+    {
+        @width $module:ident, $init:ident, $token:ident, $type:ty, [$($name:ident: $distribution:expr, $test:ident, $vfunc:path => $sfunc:expr);*]
+    } => {
+        pub mod $module {
+            use super::*;
+            $(
+            ops_test! { @impl $type, $distribution, $init, $name, $test, $token, $vfunc, $sfunc }
+            )*
+        }
+    };
+}
+
+/// The const-generic extension methods produce the same vectors as the fixed-width methods.
+#[test]
+#[wasm_bindgen_test::wasm_bindgen_test]
+fn lanes_match_fixed_widths() {
+    use generic_simd::{
+        arch::{generic::Generic, Token},
+        pointer::PointerExt,
+        slice::SliceExt,
+    };
+
+    let token = Generic::new().unwrap();
+    let mut source = [1f32, 2., 3., 4., 5., 6., 7., 8.];
+
+    assert_eq!(
+        2f32.splat_lanes::<4>(token).as_slice(),
+        2f32.splat4(token).as_slice()
+    );
+    assert_eq!(
+        f32::zeroed_lanes::<8>(token).as_slice(),
+        f32::zeroed8(token).as_slice()
+    );
+    assert_eq!(
+        source.read_lanes::<4>(token).as_slice(),
+        source.read4(token).as_slice()
+    );
+    assert_eq!(
+        source.read_strided_lanes::<4>(token, 2).as_slice(),
+        source.read_strided4(token, 2).as_slice()
+    );
+    assert_eq!(source.vectors_lanes::<2>(token).len(), 4);
+
+    let pointer = source.as_mut_ptr();
+    unsafe {
+        let vector = pointer.vector_read_lanes::<8>(token);
+        assert_eq!(vector.as_slice(), pointer.vector_read8(token).as_slice());
+        pointer.vector_write_lanes::<8>(token, vector * 2.);
+    }
+    assert_eq!(source, [2., 4., 6., 8., 10., 12., 14., 16.]);
+}
+
+/// Splitting and concatenating a `Shim2` moves its halves unchanged at every level of nesting.
+#[test]
+#[wasm_bindgen_test::wasm_bindgen_test]
+fn shim_split_concat() {
+    use generic_simd::{
+        arch::{
+            generic::{f32x1, Generic},
+            Token,
+        },
+        shim::{Shim2, Shim4, Shim8},
+        slice::SliceExt,
+    };
+
+    let token = Generic::new().unwrap();
+    let source = [1f32, 2., 3., 4., 5., 6., 7., 8.];
+
+    let vector: Shim8<f32x1, f32> = source.read8(token);
+    let (low, high) = vector.split();
+    assert_eq!(low.as_slice(), &source[..4]);
+    assert_eq!(high.as_slice(), &source[4..]);
+
+    let (low_low, low_high) = low.split();
+    assert_eq!(low_low.as_slice(), &source[..2]);
+    assert_eq!(low_high.as_slice(), &source[2..4]);
+
+    let (first, second) = low_low.split();
+    assert_eq!(first.as_slice(), &source[..1]);
+    assert_eq!(second.as_slice(), &source[1..2]);
+
+    let low_low = Shim2::<f32x1, f32>::concat(first, second);
+    let low = Shim4::<f32x1, f32>::concat(low_low, low_high);
+    assert_eq!(low.as_slice(), &source[..4]);
+    let swapped = Shim8::<f32x1, f32>::concat(high, low);
+    assert_eq!(swapped.as_slice(), [5., 6., 7., 8., 1., 2., 3., 4.]);
+}
+
 pub mod r#f32 {
     use super::*;
-    ops_test! { token, f32 }
+    ops_test! { token, f32, Standard, float }
+    convert_test! {
+        token, f32, [
+            to_f64: Standard, Convert::<f64>::convert => |x: f32| x as f64;
+            to_i32: Quarters, Convert::<i32>::convert => |x: f32| x as i32;
+            round_to_i32: Quarters, ConvertRound::<i32>::convert_round => round_ties_even;
+            to_bits: Standard, Bits::to_bits => f32::to_bits;
+            bits_round_trip: Standard, bits_round_trip => core::convert::identity
+        ]
+    }
+    math_test! {
+        token, f32, [
+            exp: Moderate, ulp_op_impl, math::exp => f32::exp;
+            exp2: Moderate, ulp_op_impl, math::exp2 => f32::exp2;
+            ln: Positive, ulp_op_impl, math::ln => f32::ln;
+            log2: Positive, ulp_op_impl, math::log2 => f32::log2;
+            log10: Positive, ulp_op_impl, math::log10 => f32::log10;
+            sin: Moderate, ulp_op_impl, math::sin => f32::sin;
+            cos: Moderate, ulp_op_impl, math::cos => f32::cos;
+            tan: Moderate, ulp_op_impl, math::tan => f32::tan;
+            sin_large: Large, ulp_op_impl, math::sin => f32::sin;
+            cos_large: Large, ulp_op_impl, math::cos => f32::cos;
+            tan_large: Large, ulp_op_impl, math::tan => f32::tan;
+            atan: Moderate, ulp_op_impl, math::atan => f32::atan;
+            atan2: Moderate, ulp_binary_op_impl, math::atan2 => f32::atan2;
+            pow: Moderate, ulp_binary_op_impl, pow_abs => |x: f32, y| x.abs().powf(y);
+            tanh: Moderate, ulp_op_impl, math::tanh => f32::tanh;
+            erf: Moderate, ulp_op_impl, math::erf => libm::erff;
+            exp_sweep: (Sweep::linear(-103., 88.7), 1.5), sweep_op_impl, math::exp => |x: f32| (x as f64).exp();
+            exp2_sweep: (Sweep::linear(-149., 127.9), 1.5), sweep_op_impl, math::exp2 => |x: f32| (x as f64).exp2();
+            ln_sweep: (Sweep::geometric(1e-45, 3e38), 1.), sweep_op_impl, math::ln => |x: f32| (x as f64).ln();
+            ln_near_one_sweep: (Sweep::linear(0.5, 2.), 1.), sweep_op_impl, math::ln => |x: f32| (x as f64).ln();
+            log2_sweep: (Sweep::geometric(1e-45, 3e38), 1.), sweep_op_impl, math::log2 => |x: f32| (x as f64).log2();
+            log2_near_one_sweep: (Sweep::linear(0.5, 2.), 1.), sweep_op_impl, math::log2 => |x: f32| (x as f64).log2();
+            log10_sweep: (Sweep::geometric(1e-45, 3e38), 1.), sweep_op_impl, math::log10 => |x: f32| (x as f64).log10();
+            log10_near_one_sweep: (Sweep::linear(0.5, 2.), 1.), sweep_op_impl, math::log10 => |x: f32| (x as f64).log10();
+            sin_sweep: (Sweep::linear(-100., 100.), 1.), sweep_op_impl, math::sin => |x: f32| (x as f64).sin();
+            sin_magnitude_sweep: (Sweep::geometric(1e-40, 3e38).signed(), 1.), sweep_op_impl, math::sin => |x: f32| (x as f64).sin();
+            cos_sweep: (Sweep::linear(-100., 100.), 1.), sweep_op_impl, math::cos => |x: f32| (x as f64).cos();
+            cos_magnitude_sweep: (Sweep::geometric(1e-40, 3e38).signed(), 1.), sweep_op_impl, math::cos => |x: f32| (x as f64).cos();
+            tan_sweep: (Sweep::linear(-100., 100.), 2.5), sweep_op_impl, math::tan => |x: f32| (x as f64).tan();
+            tan_magnitude_sweep: (Sweep::geometric(1e-40, 3e38).signed(), 2.5), sweep_op_impl, math::tan => |x: f32| (x as f64).tan();
+            atan_sweep: (Sweep::linear(-100., 100.), 1.), sweep_op_impl, math::atan => |x: f32| (x as f64).atan();
+            atan_magnitude_sweep: (Sweep::geometric(1e-40, 3e38).signed(), 1.), sweep_op_impl, math::atan => |x: f32| (x as f64).atan();
+            atan2_sweep: ((Sweep::linear(-10., 10.), Sweep::linear(-10., 10.)), 2.), sweep_binary_op_impl, math::atan2 => |y: f32, x: f32| (y as f64).atan2(x as f64);
+            atan2_magnitude_sweep: ((Sweep::geometric(1e-30, 1e30).signed(), Sweep::geometric(1e-30, 1e30).signed()), 2.), sweep_binary_op_impl, math::atan2 => |y: f32, x: f32| (y as f64).atan2(x as f64);
+            pow_sweep: ((Sweep::geometric(1e-3, 1e3), Sweep::linear(-10., 10.)), 2.), sweep_binary_op_impl, math::pow => |x: f32, y: f32| (x as f64).powf(y as f64);
+            tanh_sweep: (Sweep::linear(-10., 10.), 1.5), sweep_op_impl, math::tanh => |x: f32| (x as f64).tanh();
+            tanh_magnitude_sweep: (Sweep::geometric(1e-40, 20.).signed(), 1.5), sweep_op_impl, math::tanh => |x: f32| (x as f64).tanh();
+            erf_sweep: (Sweep::linear(-6., 6.), 1.5), sweep_op_impl, math::erf => |x: f32| libm::erf(x as f64);
+            erf_magnitude_sweep: (Sweep::geometric(1e-40, 6.).signed(), 1.5), sweep_op_impl, math::erf => |x: f32| libm::erf(x as f64);
+            exp_special: special_values!(f32), special_op_impl, math::exp => f32::exp;
+            exp2_special: special_values!(f32), special_op_impl, math::exp2 => f32::exp2;
+            ln_special: special_values!(f32), special_op_impl, math::ln => f32::ln;
+            log2_special: special_values!(f32), special_op_impl, math::log2 => f32::log2;
+            log10_special: special_values!(f32), special_op_impl, math::log10 => f32::log10;
+            sin_special: special_values!(f32), special_op_impl, math::sin => f32::sin;
+            cos_special: special_values!(f32), special_op_impl, math::cos => f32::cos;
+            tan_special: special_values!(f32), special_op_impl, math::tan => f32::tan;
+            atan_special: special_values!(f32), special_op_impl, math::atan => f32::atan;
+            tanh_special: special_values!(f32), special_op_impl, math::tanh => f32::tanh;
+            erf_special: special_values!(f32), special_op_impl, math::erf => libm::erff;
+            sin_large_special: large_trig_values!(f32), special_op_impl, math::sin => f32::sin;
+            cos_large_special: large_trig_values!(f32), special_op_impl, math::cos => f32::cos;
+            tan_large_special: large_trig_values!(f32), special_op_impl, math::tan => f32::tan;
+            atan2_special: atan2_special_values!(f32), special_binary_op_impl, math::atan2 => f32::atan2;
+            pow_special: pow_special_values!(f32), special_binary_op_impl, math::pow => f32::powf
+        ]
+    }
 }
 
 pub mod r#f64 {
     use super::*;
-    ops_test! { token, f64 }
+    ops_test! { token, f64, Standard, float }
+    convert_test! {
+        token, f64, [
+            to_f32: Standard, Convert::<f32>::convert => |x: f64| x as f32;
+            to_bits: Standard, Bits::to_bits => f64::to_bits;
+            bits_round_trip: Standard, bits_round_trip => core::convert::identity
+        ]
+    }
+    math_test! {
+        token, f64, [
+            exp: Moderate, ulp_op_impl, math::exp => f64::exp;
+            exp2: Moderate, ulp_op_impl, math::exp2 => f64::exp2;
+            ln: Positive, ulp_op_impl, math::ln => f64::ln;
+            log2: Positive, ulp_op_impl, math::log2 => f64::log2;
+            log10: Positive, ulp_op_impl, math::log10 => f64::log10;
+            sin: Moderate, ulp_op_impl, math::sin => f64::sin;
+            cos: Moderate, ulp_op_impl, math::cos => f64::cos;
+            tan: Moderate, ulp_op_impl, math::tan => f64::tan;
+            sin_large: Large, ulp_op_impl, math::sin => f64::sin;
+            cos_large: Large, ulp_op_impl, math::cos => f64::cos;
+            tan_large: Large, ulp_op_impl, math::tan => f64::tan;
+            atan: Moderate, ulp_op_impl, math::atan => f64::atan;
+            atan2: Moderate, ulp_binary_op_impl, math::atan2 => f64::atan2;
+            pow: Moderate, ulp_binary_op_impl, pow_abs => |x: f64, y| x.abs().powf(y);
+            tanh: Moderate, ulp_op_impl, math::tanh => f64::tanh;
+            erf: Moderate, ulp_op_impl, math::erf => libm::erf;
+            exp_sweep: (Sweep::linear(-745., 709.7), 1.), sweep_op_impl, math::exp => |x: f64| x.exp();
+            exp2_sweep: (Sweep::linear(-1074., 1023.9), 1.5), sweep_op_impl, math::exp2 => |x: f64| x.exp2();
+            ln_sweep: (Sweep::geometric(5e-324, 1.7e308), 1.), sweep_op_impl, math::ln => |x: f64| x.ln();
+            ln_near_one_sweep: (Sweep::linear(0.5, 2.), 1.), sweep_op_impl, math::ln => |x: f64| x.ln();
+            log2_sweep: (Sweep::geometric(5e-324, 1.7e308), 1.), sweep_op_impl, math::log2 => |x: f64| x.log2();
+            log2_near_one_sweep: (Sweep::linear(0.5, 2.), 1.), sweep_op_impl, math::log2 => |x: f64| x.log2();
+            log10_sweep: (Sweep::geometric(5e-324, 1.7e308), 1.), sweep_op_impl, math::log10 => |x: f64| x.log10();
+            log10_near_one_sweep: (Sweep::linear(0.5, 2.), 1.), sweep_op_impl, math::log10 => |x: f64| x.log10();
+            sin_sweep: (Sweep::linear(-100., 100.), 1.), sweep_op_impl, math::sin => |x: f64| x.sin();
+            sin_magnitude_sweep: (Sweep::geometric(1e-300, 1.7e308).signed(), 1.), sweep_op_impl, math::sin => |x: f64| x.sin();
+            cos_sweep: (Sweep::linear(-100., 100.), 1.), sweep_op_impl, math::cos => |x: f64| x.cos();
+            cos_magnitude_sweep: (Sweep::geometric(1e-300, 1.7e308).signed(), 1.), sweep_op_impl, math::cos => |x: f64| x.cos();
+            tan_sweep: (Sweep::linear(-100., 100.), 2.5), sweep_op_impl, math::tan => |x: f64| x.tan();
+            tan_magnitude_sweep: (Sweep::geometric(1e-300, 1.7e308).signed(), 2.5), sweep_op_impl, math::tan => |x: f64| x.tan();
+            atan_sweep: (Sweep::linear(-100., 100.), 1.), sweep_op_impl, math::atan => |x: f64| x.atan();
+            atan_magnitude_sweep: (Sweep::geometric(1e-300, 1.7e308).signed(), 1.), sweep_op_impl, math::atan => |x: f64| x.atan();
+            atan2_sweep: ((Sweep::linear(-10., 10.), Sweep::linear(-10., 10.)), 2.), sweep_binary_op_impl, math::atan2 => |y: f64, x: f64| y.atan2(x);
+            atan2_magnitude_sweep: ((Sweep::geometric(1e-30, 1e30).signed(), Sweep::geometric(1e-30, 1e30).signed()), 2.), sweep_binary_op_impl, math::atan2 => |y: f64, x: f64| y.atan2(x);
+            pow_sweep: ((Sweep::geometric(1e-3, 1e3), Sweep::linear(-10., 10.)), 2.), sweep_binary_op_impl, math::pow => |x: f64, y: f64| x.powf(y);
+            tanh_sweep: (Sweep::linear(-10., 10.), 1.5), sweep_op_impl, math::tanh => |x: f64| x.tanh();
+            tanh_magnitude_sweep: (Sweep::geometric(1e-300, 40.).signed(), 1.5), sweep_op_impl, math::tanh => |x: f64| x.tanh();
+            erf_sweep: (Sweep::linear(-6., 6.), 1.5), sweep_op_impl, math::erf => |x: f64| libm::erf(x);
+            erf_magnitude_sweep: (Sweep::geometric(1e-300, 6.).signed(), 1.5), sweep_op_impl, math::erf => |x: f64| libm::erf(x);
+            exp_special: special_values!(f64), special_op_impl, math::exp => f64::exp;
+            exp2_special: special_values!(f64), special_op_impl, math::exp2 => f64::exp2;
+            ln_special: special_values!(f64), special_op_impl, math::ln => f64::ln;
+            log2_special: special_values!(f64), special_op_impl, math::log2 => f64::log2;
+            log10_special: special_values!(f64), special_op_impl, math::log10 => f64::log10;
+            sin_special: special_values!(f64), special_op_impl, math::sin => f64::sin;
+            cos_special: special_values!(f64), special_op_impl, math::cos => f64::cos;
+            tan_special: special_values!(f64), special_op_impl, math::tan => f64::tan;
+            atan_special: special_values!(f64), special_op_impl, math::atan => f64::atan;
+            tanh_special: special_values!(f64), special_op_impl, math::tanh => f64::tanh;
+            erf_special: special_values!(f64), special_op_impl, math::erf => libm::erf;
+            sin_large_special: large_trig_values!(f64), special_op_impl, math::sin => f64::sin;
+            cos_large_special: large_trig_values!(f64), special_op_impl, math::cos => f64::cos;
+            tan_large_special: large_trig_values!(f64), special_op_impl, math::tan => f64::tan;
+            atan2_special: atan2_special_values!(f64), special_binary_op_impl, math::atan2 => f64::atan2;
+            pow_special: pow_special_values!(f64), special_binary_op_impl, math::pow => f64::powf
+        ]
+    }
+}
+
+pub mod r#i8 {
+    use super::*;
+    ops_test! { token, i8, NonZero, signed }
+}
+
+pub mod r#i16 {
+    use super::*;
+    ops_test! { token, i16, NonZero, signed }
+}
+
+pub mod r#i32 {
+    use super::*;
+    ops_test! { token, i32, NonZero, signed }
+    convert_test! {
+        token, i32, [
+            to_f32: NonZero, Convert::<f32>::convert => |x: i32| x as f32
+        ]
+    }
+}
+
+pub mod r#i64 {
+    use super::*;
+    ops_test! { token, i64, NonZero, signed }
+}
+
+pub mod r#u8 {
+    use super::*;
+    ops_test! { token, u8, NonZero, unsigned }
+}
+
+pub mod r#u16 {
+    use super::*;
+    ops_test! { token, u16, NonZero, unsigned }
+}
+
+pub mod r#u32 {
+    use super::*;
+    ops_test! { token, u32, NonZero, unsigned }
+}
+
+pub mod r#u64 {
+    use super::*;
+    ops_test! { token, u64, NonZero, unsigned }
 }
 
 #[cfg(feature = "complex")]
 pub mod complex_f32 {
     use super::*;
-    ops_test! { token, Complex<f32> }
+    ops_test! { token, Complex<f32>, ComplexDistribution::new(Standard, Standard), complex }
 }
 
 #[cfg(feature = "complex")]
 pub mod complex_f64 {
     use super::*;
-    ops_test! { token, Complex<f64> }
+    ops_test! { token, Complex<f64>, ComplexDistribution::new(Standard, Standard), complex }
 }
@@ -14,16 +14,17 @@ use std::alloc::{alloc, Layout};
 
 use crate::{
     arch, scalar,
-    vector::{width, VectorOf},
+    vector::{NativeVector, VectorOf},
 };
 
 #[repr(C)]
 #[derive(Copy, Clone)]
 struct Vectors<Token: arch::Token, Scalar: scalar::ScalarExt<Token>>(
-    VectorOf<Scalar, width::W1, Token>,
-    VectorOf<Scalar, width::W2, Token>,
-    VectorOf<Scalar, width::W4, Token>,
-    VectorOf<Scalar, width::W8, Token>,
+    VectorOf<Scalar, 1, Token>,
+    VectorOf<Scalar, 2, Token>,
+    VectorOf<Scalar, 4, Token>,
+    VectorOf<Scalar, 8, Token>,
+    NativeVector<Scalar, Token>,
 );
 
 macro_rules! max_alignment {
@@ -164,11 +165,30 @@ pub type MaxAligned<Scalar, T> = Aligned<AllVectors<Scalar>, T>;
 mod test {
     use super::*;
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[cfg(all(
+        not(feature = "nightly"),
+        any(target_arch = "x86", target_arch = "x86_64")
+    ))]
     #[test]
     fn check_x86() {
         type Foo = [f32; 8];
         type AlignedFoo = MaxAligned<f32, Foo>;
         assert_eq!(core::mem::align_of::<AlignedFoo>(), 32);
     }
+
+    #[cfg(all(feature = "nightly", any(target_arch = "x86", target_arch = "x86_64")))]
+    #[test]
+    fn check_x86_avx512() {
+        type Foo = [f32; 16];
+        type AlignedFoo = MaxAligned<f32, Foo>;
+        assert_eq!(core::mem::align_of::<AlignedFoo>(), 64);
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[test]
+    fn check_x86_integer() {
+        type Foo = [u8; 32];
+        type AlignedFoo = MaxAligned<u8, Foo>;
+        assert_eq!(core::mem::align_of::<AlignedFoo>(), 32);
+    }
 }
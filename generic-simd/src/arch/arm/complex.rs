@@ -82,6 +82,8 @@ impl Scalar<Neon, width::W8> for Complex<f64> {
     type Vector = Shim8<<Self as Scalar<Neon, width::W1>>::Vector, Complex<f64>>;
 }
 
+shim_widths! { Neon, [Complex<f32>, Complex<f64>], [W8 -> W16, W16 -> W32, W32 -> W64] }
+
 arithmetic_ops! {
     feature: Neon::new_unchecked(),
     for cf32x1:
@@ -91,6 +93,15 @@ arithmetic_ops! {
         div -> ()
 }
 
+reduce_ops! {
+    for cf32x1:
+        sum -> [+],
+        product -> [*]
+}
+
+mul_add_ops! { for cf32x1 }
+gather_ops! { for cf32x1 }
+
 arithmetic_ops! {
     feature: Neon::new_unchecked(),
     for cf32x2:
@@ -100,6 +111,15 @@ arithmetic_ops! {
         div -> ()
 }
 
+reduce_ops! {
+    for cf32x2:
+        sum -> [+],
+        product -> [*]
+}
+
+mul_add_ops! { for cf32x2 }
+gather_ops! { for cf32x2 }
+
 #[cfg(target_arch = "aarch64")]
 arithmetic_ops! {
     feature: Neon::new_unchecked(),
@@ -110,6 +130,17 @@ arithmetic_ops! {
         div -> ()
 }
 
+#[cfg(target_arch = "aarch64")]
+reduce_ops! {
+    for cf64x1:
+        sum -> [+],
+        product -> [*]
+}
+
+#[cfg(target_arch = "aarch64")]
+mul_add_ops! { for cf64x1 }
+gather_ops! { for cf64x1 }
+
 impl core::ops::Neg for cf32x1 {
     type Output = Self;
 
@@ -147,6 +178,35 @@ impl core::ops::Neg for cf64x1 {
     }
 }
 
+bitwise_ops! {
+    for cf32x1:
+        and -> (|a, b| vreinterpret_f32_u32(vand_u32(vreinterpret_u32_f32(a), vreinterpret_u32_f32(b)))),
+        or -> (|a, b| vreinterpret_f32_u32(vorr_u32(vreinterpret_u32_f32(a), vreinterpret_u32_f32(b)))),
+        xor -> (|a, b| vreinterpret_f32_u32(veor_u32(vreinterpret_u32_f32(a), vreinterpret_u32_f32(b)))),
+        not -> (|a| vreinterpret_f32_u32(vmvn_u32(vreinterpret_u32_f32(a)))),
+        and_not -> (|a, b| vreinterpret_f32_u32(vbic_u32(vreinterpret_u32_f32(a), vreinterpret_u32_f32(b))))
+}
+
+bitwise_ops! {
+    for cf32x2:
+        and -> (|a, b| vreinterpretq_f32_u32(vandq_u32(vreinterpretq_u32_f32(a), vreinterpretq_u32_f32(b)))),
+        or -> (|a, b| vreinterpretq_f32_u32(vorrq_u32(vreinterpretq_u32_f32(a), vreinterpretq_u32_f32(b)))),
+        xor -> (|a, b| vreinterpretq_f32_u32(veorq_u32(vreinterpretq_u32_f32(a), vreinterpretq_u32_f32(b)))),
+        not -> (|a| vreinterpretq_f32_u32(vmvnq_u32(vreinterpretq_u32_f32(a)))),
+        and_not -> (|a, b| vreinterpretq_f32_u32(vbicq_u32(vreinterpretq_u32_f32(a), vreinterpretq_u32_f32(b))))
+}
+
+// There is no 64-bit `vmvn`, so the lanes are complemented as 32-bit lanes.
+#[cfg(target_arch = "aarch64")]
+bitwise_ops! {
+    for cf64x1:
+        and -> (|a, b| vreinterpretq_f64_u64(vandq_u64(vreinterpretq_u64_f64(a), vreinterpretq_u64_f64(b)))),
+        or -> (|a, b| vreinterpretq_f64_u64(vorrq_u64(vreinterpretq_u64_f64(a), vreinterpretq_u64_f64(b)))),
+        xor -> (|a, b| vreinterpretq_f64_u64(veorq_u64(vreinterpretq_u64_f64(a), vreinterpretq_u64_f64(b)))),
+        not -> (|a| vreinterpretq_f64_u32(vmvnq_u32(vreinterpretq_u32_f64(a)))),
+        and_not -> (|a, b| vreinterpretq_f64_u64(vbicq_u64(vreinterpretq_u64_f64(a), vreinterpretq_u64_f64(b))))
+}
+
 as_slice! { cf32x1 }
 as_slice! { cf32x2 }
 #[cfg(target_arch = "aarch64")]
@@ -0,0 +1,615 @@
+use crate::{
+    arch::{arm::*, generic, Token},
+    scalar::Scalar,
+    shim::{Shim2, Shim4, Shim8, ShimToken},
+    vector::{width, Native, Vector},
+};
+
+#[cfg(target_arch = "aarch64")]
+use core::arch::aarch64::*;
+#[cfg(target_arch = "arm")]
+use core::arch::arm::*;
+
+/// A NEON vector of 16 `i8`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct i8x16(int8x16_t);
+
+/// A NEON vector of 8 `i16`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct i16x8(int16x8_t);
+
+/// A NEON vector of 4 `i32`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct i32x4(int32x4_t);
+
+/// A NEON vector of 2 `i64`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct i64x2(int64x2_t);
+
+/// A NEON vector of 16 `u8`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u8x16(uint8x16_t);
+
+/// A NEON vector of 8 `u16`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u16x8(uint16x8_t);
+
+/// A NEON vector of 4 `u32`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u32x4(uint32x4_t);
+
+/// A NEON vector of 2 `u64`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u64x2(uint64x2_t);
+
+impl Native<Neon> for i8 {
+    type Width = width::W16;
+}
+
+impl Native<Neon> for u8 {
+    type Width = width::W16;
+}
+
+impl Native<Neon> for i16 {
+    type Width = width::W8;
+}
+
+impl Native<Neon> for u16 {
+    type Width = width::W8;
+}
+
+impl Native<Neon> for i32 {
+    type Width = width::W4;
+}
+
+impl Native<Neon> for u32 {
+    type Width = width::W4;
+}
+
+impl Native<Neon> for i64 {
+    type Width = width::W2;
+}
+
+impl Native<Neon> for u64 {
+    type Width = width::W2;
+}
+
+impl Scalar<Neon, width::W1> for i8 {
+    type Vector = ShimToken<generic::i8x1, Self, Neon>;
+}
+
+impl Scalar<Neon, width::W2> for i8 {
+    type Vector = ShimToken<Shim2<generic::i8x1, Self>, Self, Neon>;
+}
+
+impl Scalar<Neon, width::W4> for i8 {
+    type Vector = ShimToken<Shim4<generic::i8x1, Self>, Self, Neon>;
+}
+
+impl Scalar<Neon, width::W8> for i8 {
+    type Vector = ShimToken<Shim8<generic::i8x1, Self>, Self, Neon>;
+}
+
+impl Scalar<Neon, width::W16> for i8 {
+    type Vector = i8x16;
+}
+
+impl Scalar<Neon, width::W1> for u8 {
+    type Vector = ShimToken<generic::u8x1, Self, Neon>;
+}
+
+impl Scalar<Neon, width::W2> for u8 {
+    type Vector = ShimToken<Shim2<generic::u8x1, Self>, Self, Neon>;
+}
+
+impl Scalar<Neon, width::W4> for u8 {
+    type Vector = ShimToken<Shim4<generic::u8x1, Self>, Self, Neon>;
+}
+
+impl Scalar<Neon, width::W8> for u8 {
+    type Vector = ShimToken<Shim8<generic::u8x1, Self>, Self, Neon>;
+}
+
+impl Scalar<Neon, width::W16> for u8 {
+    type Vector = u8x16;
+}
+
+impl Scalar<Neon, width::W1> for i16 {
+    type Vector = ShimToken<generic::i16x1, Self, Neon>;
+}
+
+impl Scalar<Neon, width::W2> for i16 {
+    type Vector = ShimToken<Shim2<generic::i16x1, Self>, Self, Neon>;
+}
+
+impl Scalar<Neon, width::W4> for i16 {
+    type Vector = ShimToken<Shim4<generic::i16x1, Self>, Self, Neon>;
+}
+
+impl Scalar<Neon, width::W8> for i16 {
+    type Vector = i16x8;
+}
+
+impl Scalar<Neon, width::W1> for u16 {
+    type Vector = ShimToken<generic::u16x1, Self, Neon>;
+}
+
+impl Scalar<Neon, width::W2> for u16 {
+    type Vector = ShimToken<Shim2<generic::u16x1, Self>, Self, Neon>;
+}
+
+impl Scalar<Neon, width::W4> for u16 {
+    type Vector = ShimToken<Shim4<generic::u16x1, Self>, Self, Neon>;
+}
+
+impl Scalar<Neon, width::W8> for u16 {
+    type Vector = u16x8;
+}
+
+impl Scalar<Neon, width::W1> for i32 {
+    type Vector = ShimToken<generic::i32x1, Self, Neon>;
+}
+
+impl Scalar<Neon, width::W2> for i32 {
+    type Vector = ShimToken<Shim2<generic::i32x1, Self>, Self, Neon>;
+}
+
+impl Scalar<Neon, width::W4> for i32 {
+    type Vector = i32x4;
+}
+
+impl Scalar<Neon, width::W8> for i32 {
+    type Vector = Shim2<i32x4, Self>;
+}
+
+impl Scalar<Neon, width::W1> for u32 {
+    type Vector = ShimToken<generic::u32x1, Self, Neon>;
+}
+
+impl Scalar<Neon, width::W2> for u32 {
+    type Vector = ShimToken<Shim2<generic::u32x1, Self>, Self, Neon>;
+}
+
+impl Scalar<Neon, width::W4> for u32 {
+    type Vector = u32x4;
+}
+
+impl Scalar<Neon, width::W8> for u32 {
+    type Vector = Shim2<u32x4, Self>;
+}
+
+impl Scalar<Neon, width::W1> for i64 {
+    type Vector = ShimToken<generic::i64x1, Self, Neon>;
+}
+
+impl Scalar<Neon, width::W2> for i64 {
+    type Vector = i64x2;
+}
+
+impl Scalar<Neon, width::W4> for i64 {
+    type Vector = Shim2<i64x2, Self>;
+}
+
+impl Scalar<Neon, width::W8> for i64 {
+    type Vector = Shim4<i64x2, Self>;
+}
+
+impl Scalar<Neon, width::W1> for u64 {
+    type Vector = ShimToken<generic::u64x1, Self, Neon>;
+}
+
+impl Scalar<Neon, width::W2> for u64 {
+    type Vector = u64x2;
+}
+
+impl Scalar<Neon, width::W4> for u64 {
+    type Vector = Shim2<u64x2, Self>;
+}
+
+impl Scalar<Neon, width::W8> for u64 {
+    type Vector = Shim4<u64x2, Self>;
+}
+
+shim_widths! { Neon, [i8, u8], [W16 -> W32, W32 -> W64] }
+shim_widths! { Neon, [i16, u16, i32, u32, i64, u64], [W8 -> W16, W16 -> W32, W32 -> W64] }
+
+// Integer vectors wrap on overflow.  Operations without a NEON instruction are performed
+// lane-wise.
+macro_rules! implement {
+    {
+        $vector:ty, $scalar:ty, $width:ty, $underlying:ty,
+        add -> $add:tt,
+        sub -> $sub:tt,
+        mul -> $mul:tt,
+        div -> $div:tt
+    } => {
+        arithmetic_ops! {
+            feature: Neon::new_unchecked(),
+            for $vector:
+                add -> $add,
+                sub -> $sub,
+                mul -> $mul,
+                div -> $div
+        }
+
+        mul_add_ops! { for $vector }
+        gather_ops! { for $vector }
+
+        as_slice! { $vector }
+
+        unsafe impl Vector for $vector {
+            type Scalar = $scalar;
+
+            type Token = Neon;
+
+            type Width = $width;
+
+            type Underlying = $underlying;
+
+            #[inline]
+            fn zeroed(_: Self::Token) -> Self {
+                // TODO use vdup
+                Self(unsafe { core::mem::zeroed() })
+            }
+
+            #[inline]
+            fn splat(_: Self::Token, from: Self::Scalar) -> Self {
+                // TODO use vdup
+                let mut v: Self = unsafe { core::mem::zeroed() };
+                for x in v.as_slice_mut() {
+                    *x = from;
+                }
+                v
+            }
+        }
+    };
+    {
+        $vector:ty, $scalar:ty, $width:ty, $underlying:ty,
+        add -> $add:tt,
+        sub -> $sub:tt,
+        mul -> $mul:tt,
+        div -> $div:tt,
+        signed
+    } => {
+        implement! {
+            $vector, $scalar, $width, $underlying,
+            add -> $add,
+            sub -> $sub,
+            mul -> $mul,
+            div -> $div
+        }
+
+        impl core::ops::Neg for $vector {
+            type Output = Self;
+
+            #[inline]
+            fn neg(mut self) -> Self {
+                for v in self.as_slice_mut() {
+                    *v = v.wrapping_neg();
+                }
+                self
+            }
+        }
+    };
+}
+
+implement! {
+    i8x16, i8, width::W16, int8x16_t,
+    add -> (vaddq_s8),
+    sub -> (vsubq_s8),
+    mul -> (vmulq_s8),
+    div -> [wrapping_div],
+    signed
+}
+
+implement! {
+    i16x8, i16, width::W8, int16x8_t,
+    add -> (vaddq_s16),
+    sub -> (vsubq_s16),
+    mul -> (vmulq_s16),
+    div -> [wrapping_div],
+    signed
+}
+
+implement! {
+    i32x4, i32, width::W4, int32x4_t,
+    add -> (vaddq_s32),
+    sub -> (vsubq_s32),
+    mul -> (vmulq_s32),
+    div -> [wrapping_div],
+    signed
+}
+
+implement! {
+    i64x2, i64, width::W2, int64x2_t,
+    add -> (vaddq_s64),
+    sub -> (vsubq_s64),
+    mul -> [wrapping_mul],
+    div -> [wrapping_div],
+    signed
+}
+
+implement! {
+    u8x16, u8, width::W16, uint8x16_t,
+    add -> (vaddq_u8),
+    sub -> (vsubq_u8),
+    mul -> (vmulq_u8),
+    div -> [wrapping_div]
+}
+
+implement! {
+    u16x8, u16, width::W8, uint16x8_t,
+    add -> (vaddq_u16),
+    sub -> (vsubq_u16),
+    mul -> (vmulq_u16),
+    div -> [wrapping_div]
+}
+
+implement! {
+    u32x4, u32, width::W4, uint32x4_t,
+    add -> (vaddq_u32),
+    sub -> (vsubq_u32),
+    mul -> (vmulq_u32),
+    div -> [wrapping_div]
+}
+
+implement! {
+    u64x2, u64, width::W2, uint64x2_t,
+    add -> (vaddq_u64),
+    sub -> (vsubq_u64),
+    mul -> [wrapping_mul],
+    div -> [wrapping_div]
+}
+
+#[inline]
+unsafe fn vmvnq_s64(a: int64x2_t) -> int64x2_t {
+    vreinterpretq_s64_u32(vmvnq_u32(vreinterpretq_u32_s64(a)))
+}
+
+macro_rules! implement_bitwise {
+    {
+        $($vector:ty => $and:path, $or:path, $xor:path, $not:path, $and_not:path);*
+    } => {
+        $(
+        bitwise_ops! {
+            for $vector:
+                and -> ($and),
+                or -> ($or),
+                xor -> ($xor),
+                not -> ($not),
+                and_not -> ($and_not)
+        }
+        )*
+    }
+}
+
+implement_bitwise! {
+    i8x16 => vandq_s8, vorrq_s8, veorq_s8, vmvnq_s8, vbicq_s8;
+    i16x8 => vandq_s16, vorrq_s16, veorq_s16, vmvnq_s16, vbicq_s16;
+    i32x4 => vandq_s32, vorrq_s32, veorq_s32, vmvnq_s32, vbicq_s32;
+    i64x2 => vandq_s64, vorrq_s64, veorq_s64, vmvnq_s64, vbicq_s64;
+    u8x16 => vandq_u8, vorrq_u8, veorq_u8, vmvnq_u8, vbicq_u8;
+    u16x8 => vandq_u16, vorrq_u16, veorq_u16, vmvnq_u16, vbicq_u16;
+    u32x4 => vandq_u32, vorrq_u32, veorq_u32, vmvnq_u32, vbicq_u32;
+    u64x2 => vandq_u64, vorrq_u64, veorq_u64, vmvnq_u64, vbicq_u64
+}
+
+// NEON shifts each lane left by a signed count, so right shifts negate the count.
+macro_rules! implement_shift {
+    {
+        $($vector:ty => $shift:path, $dup:path, $count:ty);*
+    } => {
+        $(
+        shift_ops! {
+            for $vector:
+                shift_left -> (|a, count: u32| $shift(a, $dup(count as $count))),
+                shift_right -> (|a, count: u32| $shift(a, $dup(-(count as $count))))
+        }
+        )*
+    }
+}
+
+implement_shift! {
+    u32x4 => vshlq_u32, vdupq_n_s32, i32;
+    u64x2 => vshlq_u64, vdupq_n_s64, i64
+}
+
+macro_rules! compare_64 {
+    {
+        $scalar:ty, $op:tt
+    } => {
+        |a, b| {
+            let a: [$scalar; 2] = core::mem::transmute(a);
+            let b: [$scalar; 2] = core::mem::transmute(b);
+            let lanes = [
+                ((a[0] $op b[0]) as u64).wrapping_neg(),
+                ((a[1] $op b[1]) as u64).wrapping_neg(),
+            ];
+            core::mem::transmute::<[u64; 2], uint64x2_t>(lanes)
+        }
+    }
+}
+
+macro_rules! implement_compare {
+    {
+        $vector:ty => $mask:ident, not -> $not:path,
+        eq -> $eq:path,
+        lt -> $lt:path,
+        le -> $le:path,
+        gt -> $gt:path,
+        ge -> $ge:path
+    } => {
+        compare_ops! {
+            for $vector => $mask:
+                eq -> $eq,
+                ne -> |a, b| $not($eq(a, b)),
+                lt -> $lt,
+                le -> $le,
+                gt -> $gt,
+                ge -> $ge
+        }
+    }
+}
+
+implement_compare! {
+    i8x16 => mask8x16, not -> vmvnq_u8,
+    eq -> vceqq_s8,
+    lt -> vcltq_s8,
+    le -> vcleq_s8,
+    gt -> vcgtq_s8,
+    ge -> vcgeq_s8
+}
+
+implement_compare! {
+    i16x8 => mask16x8, not -> vmvnq_u16,
+    eq -> vceqq_s16,
+    lt -> vcltq_s16,
+    le -> vcleq_s16,
+    gt -> vcgtq_s16,
+    ge -> vcgeq_s16
+}
+
+implement_compare! {
+    i32x4 => mask32x4, not -> vmvnq_u32,
+    eq -> vceqq_s32,
+    lt -> vcltq_s32,
+    le -> vcleq_s32,
+    gt -> vcgtq_s32,
+    ge -> vcgeq_s32
+}
+
+implement_compare! {
+    u8x16 => mask8x16, not -> vmvnq_u8,
+    eq -> vceqq_u8,
+    lt -> vcltq_u8,
+    le -> vcleq_u8,
+    gt -> vcgtq_u8,
+    ge -> vcgeq_u8
+}
+
+implement_compare! {
+    u16x8 => mask16x8, not -> vmvnq_u16,
+    eq -> vceqq_u16,
+    lt -> vcltq_u16,
+    le -> vcleq_u16,
+    gt -> vcgtq_u16,
+    ge -> vcgeq_u16
+}
+
+implement_compare! {
+    u32x4 => mask32x4, not -> vmvnq_u32,
+    eq -> vceqq_u32,
+    lt -> vcltq_u32,
+    le -> vcleq_u32,
+    gt -> vcgtq_u32,
+    ge -> vcgeq_u32
+}
+
+// 64-bit comparisons are performed lane-wise.
+compare_ops! {
+    for i64x2 => mask64x2:
+        eq -> compare_64!(i64, ==),
+        ne -> compare_64!(i64, !=),
+        lt -> compare_64!(i64, <),
+        le -> compare_64!(i64, <=),
+        gt -> compare_64!(i64, >),
+        ge -> compare_64!(i64, >=)
+}
+
+compare_ops! {
+    for u64x2 => mask64x2:
+        eq -> compare_64!(u64, ==),
+        ne -> compare_64!(u64, !=),
+        lt -> compare_64!(u64, <),
+        le -> compare_64!(u64, <=),
+        gt -> compare_64!(u64, >),
+        ge -> compare_64!(u64, >=)
+}
+
+select_ops! {
+    for mask8x16 =>
+        i8x16: vbslq_s8,
+        u8x16: vbslq_u8
+}
+
+select_ops! {
+    for mask16x8 =>
+        i16x8: vbslq_s16,
+        u16x8: vbslq_u16
+}
+
+select_ops! {
+    for mask32x4 =>
+        i32x4: vbslq_s32,
+        u32x4: vbslq_u32
+}
+
+select_ops! {
+    for mask64x2 =>
+        i64x2: vbslq_s64,
+        u64x2: vbslq_u64
+}
+
+// Horizontal reductions use the across-vector instructions where available, and are otherwise
+// performed lane-wise.
+macro_rules! implement_reduce {
+    {
+        $vector:ty,
+        sum -> $sum:path,
+        min -> $min:tt,
+        max -> $max:tt
+    } => {
+        #[cfg(target_arch = "aarch64")]
+        reduce_ops! {
+            for $vector:
+                sum -> ($sum),
+                product -> [wrapping_mul]
+        }
+
+        #[cfg(target_arch = "aarch64")]
+        reduce_ops! {
+            for $vector:
+                min -> $min,
+                max -> $max
+        }
+
+        #[cfg(target_arch = "arm")]
+        reduce_ops! {
+            for $vector:
+                sum -> [wrapping_add],
+                product -> [wrapping_mul]
+        }
+
+        #[cfg(target_arch = "arm")]
+        reduce_ops! {
+            for $vector:
+                min -> [min],
+                max -> [max]
+        }
+    }
+}
+
+implement_reduce! { i8x16, sum -> vaddvq_s8, min -> (vminvq_s8), max -> (vmaxvq_s8) }
+implement_reduce! { i16x8, sum -> vaddvq_s16, min -> (vminvq_s16), max -> (vmaxvq_s16) }
+implement_reduce! { i32x4, sum -> vaddvq_s32, min -> (vminvq_s32), max -> (vmaxvq_s32) }
+implement_reduce! { i64x2, sum -> vaddvq_s64, min -> [min], max -> [max] }
+implement_reduce! { u8x16, sum -> vaddvq_u8, min -> (vminvq_u8), max -> (vmaxvq_u8) }
+implement_reduce! { u16x8, sum -> vaddvq_u16, min -> (vminvq_u16), max -> (vmaxvq_u16) }
+implement_reduce! { u32x4, sum -> vaddvq_u32, min -> (vminvq_u32), max -> (vmaxvq_u32) }
+implement_reduce! { u64x2, sum -> vaddvq_u64, min -> [min], max -> [max] }
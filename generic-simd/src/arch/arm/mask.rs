@@ -0,0 +1,122 @@
+use crate::{
+    arch::arm::Neon,
+    vector::{width, Mask},
+};
+
+#[cfg(target_arch = "aarch64")]
+use core::arch::aarch64::*;
+#[cfg(target_arch = "arm")]
+use core::arch::arm::*;
+
+/// A NEON mask of 16 8-bit lanes.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct mask8x16(pub(super) uint8x16_t);
+
+/// A NEON mask of 8 16-bit lanes.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct mask16x8(pub(super) uint16x8_t);
+
+/// A NEON mask of 2 32-bit lanes.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct mask32x2(pub(super) uint32x2_t);
+
+/// A NEON mask of 4 32-bit lanes.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct mask32x4(pub(super) uint32x4_t);
+
+/// A NEON mask of 2 64-bit lanes.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct mask64x2(pub(super) uint64x2_t);
+
+#[inline]
+pub(super) unsafe fn vmvnq_u64(a: uint64x2_t) -> uint64x2_t {
+    vreinterpretq_u64_u32(vmvnq_u32(vreinterpretq_u32_u64(a)))
+}
+
+// Each lane of a mask is either all ones or all zeros.
+macro_rules! implement {
+    {
+        $mask:ty, $width:ty, $lane:ty,
+        and -> $and:path,
+        or -> $or:path,
+        xor -> $xor:path,
+        not -> $not:path
+    } => {
+        bitwise_ops! {
+            for $mask:
+                and -> ($and),
+                or -> ($or),
+                xor -> ($xor),
+                not -> ($not)
+        }
+
+        impl Mask for $mask {
+            type Token = Neon;
+            type Width = $width;
+
+            #[inline]
+            fn splat(_: Self::Token, value: bool) -> Self {
+                let lanes = [(value as $lane).wrapping_neg(); <$width as width::Width>::VALUE];
+                Self(unsafe { core::mem::transmute(lanes) })
+            }
+
+            #[inline]
+            fn test(&self, lane: usize) -> bool {
+                assert!(lane < Self::width(), "lane index out of range");
+                let lanes: [$lane; <$width as width::Width>::VALUE] =
+                    unsafe { core::mem::transmute(self.0) };
+                lanes[lane] != 0
+            }
+        }
+    }
+}
+
+implement! {
+    mask8x16, width::W16, u8,
+    and -> vandq_u8,
+    or -> vorrq_u8,
+    xor -> veorq_u8,
+    not -> vmvnq_u8
+}
+
+implement! {
+    mask16x8, width::W8, u16,
+    and -> vandq_u16,
+    or -> vorrq_u16,
+    xor -> veorq_u16,
+    not -> vmvnq_u16
+}
+
+implement! {
+    mask32x2, width::W2, u32,
+    and -> vand_u32,
+    or -> vorr_u32,
+    xor -> veor_u32,
+    not -> vmvn_u32
+}
+
+implement! {
+    mask32x4, width::W4, u32,
+    and -> vandq_u32,
+    or -> vorrq_u32,
+    xor -> veorq_u32,
+    not -> vmvnq_u32
+}
+
+implement! {
+    mask64x2, width::W2, u64,
+    and -> vandq_u64,
+    or -> vorrq_u64,
+    xor -> veorq_u64,
+    not -> vmvnq_u64
+}
@@ -5,6 +5,14 @@ mod complex;
 #[cfg(feature = "complex")]
 pub use complex::*;
 
+mod integer;
+pub use integer::*;
+
+mod mask;
+pub use mask::*;
+
+#[cfg(target_arch = "arm")]
+use crate::arch::generic::Math;
 use crate::{
     arch::{generic, Token},
     scalar::Scalar,
@@ -88,6 +96,8 @@ impl Scalar<Neon, width::W8> for f64 {
     type Vector = Shim4<<Self as Scalar<Neon, width::W2>>::Vector, Self>;
 }
 
+shim_widths! { Neon, [f32, f64], [W8 -> W16, W16 -> W32, W32 -> W64] }
+
 arithmetic_ops! {
     feature: Neon::new_unchecked(),
     for f32x2:
@@ -116,6 +126,153 @@ arithmetic_ops! {
         div -> ()
 }
 
+// Fused multiply-add is only available on aarch64.  `vfms` subtracts the product, so the
+// result of `mul_sub` is negated, which is exact.
+#[cfg(target_arch = "aarch64")]
+mul_add_ops! {
+    for f32x2 => Neon:
+        mul_add -> |x, a, b| vfma_f32(b, x, a),
+        mul_sub -> |x, a, b| vneg_f32(vfms_f32(b, x, a))
+}
+
+#[cfg(target_arch = "aarch64")]
+mul_add_ops! {
+    for f32x4 => Neon:
+        mul_add -> |x, a, b| vfmaq_f32(b, x, a),
+        mul_sub -> |x, a, b| vnegq_f32(vfmsq_f32(b, x, a))
+}
+
+#[cfg(target_arch = "aarch64")]
+mul_add_ops! {
+    for f64x2 => Neon:
+        mul_add -> |x, a, b| vfmaq_f64(b, x, a),
+        mul_sub -> |x, a, b| vnegq_f64(vfmsq_f64(b, x, a))
+}
+
+#[cfg(target_arch = "arm")]
+mul_add_ops! { for f32x2: Neon }
+
+#[cfg(target_arch = "arm")]
+mul_add_ops! { for f32x4: Neon }
+
+gather_ops! { for f32x2 }
+gather_ops! { for f32x4 }
+gather_ops! { for f64x2 }
+
+compare_ops! {
+    for f32x2 => mask32x2:
+        eq -> vceq_f32,
+        ne -> |a, b| vmvn_u32(vceq_f32(a, b)),
+        lt -> vclt_f32,
+        le -> vcle_f32,
+        gt -> vcgt_f32,
+        ge -> vcge_f32
+}
+
+compare_ops! {
+    for f32x4 => mask32x4:
+        eq -> vceqq_f32,
+        ne -> |a, b| vmvnq_u32(vceqq_f32(a, b)),
+        lt -> vcltq_f32,
+        le -> vcleq_f32,
+        gt -> vcgtq_f32,
+        ge -> vcgeq_f32
+}
+
+#[cfg(target_arch = "aarch64")]
+compare_ops! {
+    for f64x2 => mask64x2:
+        eq -> vceqq_f64,
+        ne -> |a, b| vmvnq_u64(vceqq_f64(a, b)),
+        lt -> vcltq_f64,
+        le -> vcleq_f64,
+        gt -> vcgtq_f64,
+        ge -> vcgeq_f64
+}
+
+select_ops! {
+    for mask32x2 => f32x2: vbsl_f32
+}
+
+select_ops! {
+    for mask32x4 => f32x4: vbslq_f32
+}
+
+#[cfg(target_arch = "aarch64")]
+select_ops! {
+    for mask64x2 => f64x2: vbslq_f64
+}
+
+#[cfg(target_arch = "aarch64")]
+reduce_ops! {
+    for f32x2:
+        sum -> (vaddv_f32),
+        product -> [*]
+}
+
+#[cfg(target_arch = "aarch64")]
+reduce_ops! {
+    for f32x2:
+        min -> (vminv_f32),
+        max -> (vmaxv_f32)
+}
+
+#[cfg(target_arch = "aarch64")]
+reduce_ops! {
+    for f32x4:
+        sum -> (vaddvq_f32),
+        product -> [*]
+}
+
+#[cfg(target_arch = "aarch64")]
+reduce_ops! {
+    for f32x4:
+        min -> (vminvq_f32),
+        max -> (vmaxvq_f32)
+}
+
+#[cfg(target_arch = "aarch64")]
+reduce_ops! {
+    for f64x2:
+        sum -> (vaddvq_f64),
+        product -> [*]
+}
+
+#[cfg(target_arch = "aarch64")]
+reduce_ops! {
+    for f64x2:
+        min -> (vminvq_f64),
+        max -> (vmaxvq_f64)
+}
+
+#[cfg(target_arch = "arm")]
+reduce_ops! {
+    for f32x2:
+        sum -> [+],
+        product -> [*]
+}
+
+#[cfg(target_arch = "arm")]
+reduce_ops! {
+    for f32x2:
+        min -> [min],
+        max -> [max]
+}
+
+#[cfg(target_arch = "arm")]
+reduce_ops! {
+    for f32x4:
+        sum -> [+],
+        product -> [*]
+}
+
+#[cfg(target_arch = "arm")]
+reduce_ops! {
+    for f32x4:
+        min -> [min],
+        max -> [max]
+}
+
 impl core::ops::Neg for f32x2 {
     type Output = Self;
 
@@ -153,12 +310,326 @@ impl core::ops::Neg for f64x2 {
     }
 }
 
+float_ops! {
+    for f32x2:
+        min -> (vmin_f32),
+        max -> (vmax_f32),
+        abs -> (vabs_f32),
+        copysign -> (|a, b| vbsl_f32(vdup_n_u32(0x8000_0000), b, a))
+}
+
+float_ops! {
+    for f32x4:
+        min -> (vminq_f32),
+        max -> (vmaxq_f32),
+        abs -> (vabsq_f32),
+        copysign -> (|a, b| vbslq_f32(vdupq_n_u32(0x8000_0000), b, a))
+}
+
+#[cfg(target_arch = "aarch64")]
+float_ops! {
+    for f64x2:
+        min -> (vminq_f64),
+        max -> (vmaxq_f64),
+        abs -> (vabsq_f64),
+        copysign -> (|a, b| vbslq_f64(vdupq_n_u64(0x8000_0000_0000_0000), b, a))
+}
+
+macro_rules! implement_bitwise {
+    {
+        $vector:ty, $to_bits:path, $from_bits:path,
+        and -> $and:path,
+        or -> $or:path,
+        xor -> $xor:path,
+        not -> $not:path,
+        and_not -> $and_not:path
+    } => {
+        bitwise_ops! {
+            for $vector:
+                and -> (|a, b| $from_bits($and($to_bits(a), $to_bits(b)))),
+                or -> (|a, b| $from_bits($or($to_bits(a), $to_bits(b)))),
+                xor -> (|a, b| $from_bits($xor($to_bits(a), $to_bits(b)))),
+                not -> (|a| $from_bits($not($to_bits(a)))),
+                and_not -> (|a, b| $from_bits($and_not($to_bits(a), $to_bits(b))))
+        }
+    }
+}
+
+implement_bitwise! {
+    f32x2, vreinterpret_u32_f32, vreinterpret_f32_u32,
+    and -> vand_u32,
+    or -> vorr_u32,
+    xor -> veor_u32,
+    not -> vmvn_u32,
+    and_not -> vbic_u32
+}
+
+implement_bitwise! {
+    f32x4, vreinterpretq_u32_f32, vreinterpretq_f32_u32,
+    and -> vandq_u32,
+    or -> vorrq_u32,
+    xor -> veorq_u32,
+    not -> vmvnq_u32,
+    and_not -> vbicq_u32
+}
+
+#[cfg(target_arch = "aarch64")]
+implement_bitwise! {
+    f64x2, vreinterpretq_u64_f64, vreinterpretq_f64_u64,
+    and -> vandq_u64,
+    or -> vorrq_u64,
+    xor -> veorq_u64,
+    not -> vmvnq_u64,
+    and_not -> vbicq_u64
+}
+
+// The estimate instructions have a relative error of at most 2^-8.
+#[cfg(target_arch = "aarch64")]
+sqrt_ops! {
+    for f32x2:
+        sqrt -> (vsqrt_f32),
+        recip -> (|a| vdiv_f32(vdup_n_f32(1.), a)),
+        rsqrt -> (|a| vdiv_f32(vdup_n_f32(1.), vsqrt_f32(a))),
+        recip_estimate -> (vrecpe_f32),
+        rsqrt_estimate -> (vrsqrte_f32)
+}
+
+#[cfg(target_arch = "aarch64")]
+sqrt_ops! {
+    for f32x4:
+        sqrt -> (vsqrtq_f32),
+        recip -> (|a| vdivq_f32(vdupq_n_f32(1.), a)),
+        rsqrt -> (|a| vdivq_f32(vdupq_n_f32(1.), vsqrtq_f32(a))),
+        recip_estimate -> (vrecpeq_f32),
+        rsqrt_estimate -> (vrsqrteq_f32)
+}
+
+#[cfg(target_arch = "aarch64")]
+sqrt_ops! {
+    for f64x2:
+        sqrt -> (vsqrtq_f64),
+        recip -> (|a| vdivq_f64(vdupq_n_f64(1.), a)),
+        rsqrt -> (|a| vdivq_f64(vdupq_n_f64(1.), vsqrtq_f64(a))),
+        recip_estimate -> (vrecpeq_f64),
+        rsqrt_estimate -> (vrsqrteq_f64)
+}
+
+// ARMv7 has no vector square root, division or rounding, so exact results are computed for each
+// lane.
+#[cfg(target_arch = "arm")]
+#[inline]
+unsafe fn map_f32x2(x: float32x2_t, f: impl Fn(f32) -> f32) -> float32x2_t {
+    core::mem::transmute(core::mem::transmute::<_, [f32; 2]>(x).map(f))
+}
+
+#[cfg(target_arch = "arm")]
+#[inline]
+unsafe fn map_f32x4(x: float32x4_t, f: impl Fn(f32) -> f32) -> float32x4_t {
+    core::mem::transmute(core::mem::transmute::<_, [f32; 4]>(x).map(f))
+}
+
+#[cfg(target_arch = "arm")]
+sqrt_ops! {
+    for f32x2:
+        sqrt -> (|a| map_f32x2(a, Math::sqrt)),
+        recip -> (|a| map_f32x2(a, |a| 1. / a)),
+        rsqrt -> (|a| map_f32x2(a, |a| 1. / Math::sqrt(a))),
+        recip_estimate -> (vrecpe_f32),
+        rsqrt_estimate -> (vrsqrte_f32)
+}
+
+#[cfg(target_arch = "arm")]
+sqrt_ops! {
+    for f32x4:
+        sqrt -> (|a| map_f32x4(a, Math::sqrt)),
+        recip -> (|a| map_f32x4(a, |a| 1. / a)),
+        rsqrt -> (|a| map_f32x4(a, |a| 1. / Math::sqrt(a))),
+        recip_estimate -> (vrecpeq_f32),
+        rsqrt_estimate -> (vrsqrteq_f32)
+}
+
+// ARMv8 provides an instruction for each rounding mode.
+#[cfg(target_arch = "aarch64")]
+round_ops! {
+    for f32x2:
+        floor -> (vrndm_f32),
+        ceil -> (vrndp_f32),
+        round -> (vrnda_f32),
+        round_ties_even -> (vrndn_f32),
+        trunc -> (vrnd_f32)
+}
+
+#[cfg(target_arch = "aarch64")]
+round_ops! {
+    for f32x4:
+        floor -> (vrndmq_f32),
+        ceil -> (vrndpq_f32),
+        round -> (vrndaq_f32),
+        round_ties_even -> (vrndnq_f32),
+        trunc -> (vrndq_f32)
+}
+
+#[cfg(target_arch = "aarch64")]
+round_ops! {
+    for f64x2:
+        floor -> (vrndmq_f64),
+        ceil -> (vrndpq_f64),
+        round -> (vrndaq_f64),
+        round_ties_even -> (vrndnq_f64),
+        trunc -> (vrndq_f64)
+}
+
+#[cfg(target_arch = "arm")]
+round_ops! {
+    for f32x2:
+        floor -> (|a| map_f32x2(a, Math::floor)),
+        ceil -> (|a| map_f32x2(a, Math::ceil)),
+        round -> (|a| map_f32x2(a, Math::round)),
+        round_ties_even -> (|a| map_f32x2(a, Math::round_ties_even)),
+        trunc -> (|a| map_f32x2(a, Math::trunc))
+}
+
+#[cfg(target_arch = "arm")]
+round_ops! {
+    for f32x4:
+        floor -> (|a| map_f32x4(a, Math::floor)),
+        ceil -> (|a| map_f32x4(a, Math::ceil)),
+        round -> (|a| map_f32x4(a, Math::round)),
+        round_ties_even -> (|a| map_f32x4(a, Math::round_ties_even)),
+        trunc -> (|a| map_f32x4(a, Math::trunc))
+}
+
+// `f64` vectors and rounding conversions are only available on aarch64.
+#[cfg(target_arch = "aarch64")]
+convert_ops! {
+    for f32x2 => f64 as f64x2:
+        convert -> (vcvt_f64_f32)
+}
+
+#[cfg(target_arch = "aarch64")]
+convert_ops! {
+    for f32x4 => f64 as Shim2<f64x2, f64>:
+        convert -> (|x| [vcvt_f64_f32(vget_low_f32(x)), vcvt_high_f64_f32(x)])
+}
+
+#[cfg(target_arch = "aarch64")]
+convert_ops! {
+    for f64x2 => f32 as f32x2:
+        convert -> (vcvt_f32_f64)
+}
+
+#[cfg(target_arch = "arm")]
+convert_ops! {
+    for f32x2 => f64 as <f64 as Scalar<Neon, width::W2>>::Vector:
+        convert -> [|x| x as f64]
+}
+
+#[cfg(target_arch = "arm")]
+convert_ops! {
+    for f32x4 => f64 as <f64 as Scalar<Neon, width::W4>>::Vector:
+        convert -> [|x| x as f64]
+}
+
+// Two `i32` lanes are not stored in a NEON register.
+convert_ops! {
+    for f32x2 => i32 as <i32 as Scalar<Neon, width::W2>>::Vector:
+        convert -> [|x| x as i32],
+        round -> [crate::vector::round_to_i32]
+}
+
+#[cfg(target_arch = "aarch64")]
+convert_ops! {
+    for f32x4 => i32 as i32x4:
+        convert -> (vcvtq_s32_f32),
+        round -> (vcvtnq_s32_f32)
+}
+
+#[cfg(target_arch = "arm")]
+convert_ops! {
+    for f32x4 => i32 as i32x4:
+        convert -> (vcvtq_s32_f32),
+        round -> [crate::vector::round_to_i32]
+}
+
+convert_ops! {
+    for i32x4 => f32 as f32x4:
+        convert -> (vcvtq_f32_s32)
+}
+
+bits_ops! {
+    for f32x2 => <u32 as Scalar<Neon, width::W2>>::Vector:
+        to_bits -> [f32::to_bits],
+        from_bits -> [f32::from_bits]
+}
+
+bits_ops! {
+    for f32x4 => u32x4:
+        to_bits -> (vreinterpretq_u32_f32),
+        from_bits -> (vreinterpretq_f32_u32)
+}
+
+#[cfg(target_arch = "aarch64")]
+bits_ops! {
+    for f64x2 => u64x2:
+        to_bits -> (vreinterpretq_u64_f64),
+        from_bits -> (vreinterpretq_f64_u64)
+}
+
 as_slice! { f32x2 }
 as_slice! { f32x4 }
 
 #[cfg(target_arch = "aarch64")]
 as_slice! { f64x2 }
 
+macro_rules! interleave_ops {
+    {
+        $vld2:ident, $vld3:ident, $vld4:ident,
+        $vst2:ident($x2:ident), $vst3:ident($x3:ident), $vst4:ident($x4:ident)
+    } => {
+        #[inline]
+        unsafe fn deinterleave2_unchecked(
+            _: Self::Token,
+            from: &[Self::Scalar],
+        ) -> (Self, Self) {
+            let v = $vld2(from.as_ptr());
+            (Self(v.0), Self(v.1))
+        }
+
+        #[inline]
+        unsafe fn deinterleave3_unchecked(
+            _: Self::Token,
+            from: &[Self::Scalar],
+        ) -> (Self, Self, Self) {
+            let v = $vld3(from.as_ptr());
+            (Self(v.0), Self(v.1), Self(v.2))
+        }
+
+        #[inline]
+        unsafe fn deinterleave4_unchecked(
+            _: Self::Token,
+            from: &[Self::Scalar],
+        ) -> (Self, Self, Self, Self) {
+            let v = $vld4(from.as_ptr());
+            (Self(v.0), Self(v.1), Self(v.2), Self(v.3))
+        }
+
+        #[inline]
+        unsafe fn interleave2_unchecked(self, b: Self, to: &mut [Self::Scalar]) {
+            $vst2(to.as_mut_ptr(), $x2(self.0, b.0))
+        }
+
+        #[inline]
+        unsafe fn interleave3_unchecked(self, b: Self, c: Self, to: &mut [Self::Scalar]) {
+            $vst3(to.as_mut_ptr(), $x3(self.0, b.0, c.0))
+        }
+
+        #[inline]
+        unsafe fn interleave4_unchecked(self, b: Self, c: Self, d: Self, to: &mut [Self::Scalar]) {
+            $vst4(to.as_mut_ptr(), $x4(self.0, b.0, c.0, d.0))
+        }
+    }
+}
+
 unsafe impl Vector for f32x2 {
     type Scalar = f32;
 
@@ -182,6 +653,33 @@ unsafe impl Vector for f32x2 {
         v[1] = from;
         v
     }
+
+    interleave_ops! { vld2_f32, vld3_f32, vld4_f32, vst2_f32(float32x2x2_t), vst3_f32(float32x2x3_t), vst4_f32(float32x2x4_t) }
+
+    #[inline]
+    fn reverse(self) -> Self {
+        Self(unsafe { vrev64_f32(self.0) })
+    }
+
+    #[inline]
+    fn rotate_lanes_left_impl(self, n: usize) -> Self {
+        if n == 1 {
+            self.reverse()
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    fn broadcast_lane_impl(self, lane: usize) -> Self {
+        Self(unsafe {
+            if lane == 0 {
+                vdup_lane_f32::<0>(self.0)
+            } else {
+                vdup_lane_f32::<1>(self.0)
+            }
+        })
+    }
 }
 
 unsafe impl Vector for f32x4 {
@@ -209,6 +707,50 @@ unsafe impl Vector for f32x4 {
         v[3] = from;
         v
     }
+
+    interleave_ops! { vld2q_f32, vld3q_f32, vld4q_f32, vst2q_f32(float32x4x2_t), vst3q_f32(float32x4x3_t), vst4q_f32(float32x4x4_t) }
+
+    #[inline]
+    fn reverse(self) -> Self {
+        Self(unsafe {
+            let reversed_pairs = vrev64q_f32(self.0);
+            vextq_f32::<2>(reversed_pairs, reversed_pairs)
+        })
+    }
+
+    #[inline]
+    fn rotate_lanes_left_impl(self, n: usize) -> Self {
+        Self(unsafe {
+            match n {
+                1 => vextq_f32::<1>(self.0, self.0),
+                2 => vextq_f32::<2>(self.0, self.0),
+                3 => vextq_f32::<3>(self.0, self.0),
+                _ => self.0,
+            }
+        })
+    }
+
+    #[inline]
+    fn broadcast_lane_impl(self, lane: usize) -> Self {
+        Self(unsafe {
+            match lane {
+                0 => vdupq_laneq_f32::<0>(self.0),
+                1 => vdupq_laneq_f32::<1>(self.0),
+                2 => vdupq_laneq_f32::<2>(self.0),
+                _ => vdupq_laneq_f32::<3>(self.0),
+            }
+        })
+    }
+
+    #[inline]
+    fn split(self) -> (f32x2, f32x2) {
+        unsafe { (f32x2(vget_low_f32(self.0)), f32x2(vget_high_f32(self.0))) }
+    }
+
+    #[inline]
+    fn concat(low: f32x2, high: f32x2) -> Self {
+        Self(unsafe { vcombine_f32(low.0, high.0) })
+    }
 }
 
 #[cfg(target_arch = "aarch64")]
@@ -235,4 +777,31 @@ unsafe impl Vector for f64x2 {
         v[1] = from;
         v
     }
+
+    interleave_ops! { vld2q_f64, vld3q_f64, vld4q_f64, vst2q_f64(float64x2x2_t), vst3q_f64(float64x2x3_t), vst4q_f64(float64x2x4_t) }
+
+    #[inline]
+    fn reverse(self) -> Self {
+        Self(unsafe { vextq_f64::<1>(self.0, self.0) })
+    }
+
+    #[inline]
+    fn rotate_lanes_left_impl(self, n: usize) -> Self {
+        if n == 1 {
+            self.reverse()
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    fn broadcast_lane_impl(self, lane: usize) -> Self {
+        Self(unsafe {
+            if lane == 0 {
+                vdupq_laneq_f64::<0>(self.0)
+            } else {
+                vdupq_laneq_f64::<1>(self.0)
+            }
+        })
+    }
 }
@@ -4,7 +4,7 @@ use crate::{
     arch::Token,
     scalar::Scalar,
     shim::{Shim2, Shim4, Shim8},
-    vector::{width, Native, Vector},
+    vector::{width, Mask, Native, Vector},
 };
 
 #[cfg(feature = "complex")]
@@ -38,6 +38,54 @@ pub struct f32x1(f32);
 #[allow(non_camel_case_types)]
 pub struct f64x1(f64);
 
+/// A generic vector of one `i8`.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct i8x1(i8);
+
+/// A generic vector of one `i16`.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct i16x1(i16);
+
+/// A generic vector of one `i32`.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct i32x1(i32);
+
+/// A generic vector of one `i64`.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct i64x1(i64);
+
+/// A generic vector of one `u8`.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u8x1(u8);
+
+/// A generic vector of one `u16`.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u16x1(u16);
+
+/// A generic vector of one `u32`.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u32x1(u32);
+
+/// A generic vector of one `u64`.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u64x1(u64);
+
 /// A generic vector of one `Complex<f32>`.
 ///
 /// Requires feature `"complex"`.
@@ -56,6 +104,36 @@ pub struct cf32x1(Complex<f32>);
 #[allow(non_camel_case_types)]
 pub struct cf64x1(Complex<f64>);
 
+/// A generic mask of one lane.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct mask1(bool);
+
+bitwise_ops! {
+    for mask1:
+        and -> (core::ops::BitAnd::bitand),
+        or -> (core::ops::BitOr::bitor),
+        xor -> (core::ops::BitXor::bitxor),
+        not -> (core::ops::Not::not)
+}
+
+impl Mask for mask1 {
+    type Token = Generic;
+    type Width = width::W1;
+
+    #[inline]
+    fn splat(_: Self::Token, value: bool) -> Self {
+        Self(value)
+    }
+
+    #[inline]
+    fn test(&self, lane: usize) -> bool {
+        assert!(lane < Self::width(), "lane index out of range");
+        self.0
+    }
+}
+
 macro_rules! implement {
     {
         $vector:ty, $scalar:ty
@@ -79,11 +157,21 @@ macro_rules! implement {
         impl Native<Generic> for $scalar {
             type Width = width::W1;
         }
+
+        shim_widths! { Generic, [$scalar], [W8 -> W16, W16 -> W32, W32 -> W64] }
     }
 }
 
 implement! { f32x1, f32 }
 implement! { f64x1, f64 }
+implement! { i8x1, i8 }
+implement! { i16x1, i16 }
+implement! { i32x1, i32 }
+implement! { i64x1, i64 }
+implement! { u8x1, u8 }
+implement! { u16x1, u16 }
+implement! { u32x1, u32 }
+implement! { u64x1, u64 }
 
 #[cfg(feature = "complex")]
 implement! { cf32x1, Complex<f32> }
@@ -92,26 +180,26 @@ implement! { cf64x1, Complex<f64> }
 
 macro_rules! implement {
     {
-        $vector:ty, $scalar:ty
+        $vector:ty, $scalar:ty, add -> $add:tt, sub -> $sub:tt, mul -> $mul:tt, div -> $div:tt
     } => {
         arithmetic_ops! {
             feature: Generic::new_unchecked(),
             for $vector:
-                add -> (),
-                sub -> (),
-                mul -> (),
-                div -> ()
+                add -> $add,
+                sub -> $sub,
+                mul -> $mul,
+                div -> $div
         }
 
-        impl core::ops::Neg for $vector {
-            type Output = Self;
-
-            #[inline]
-            fn neg(self) -> Self {
-                Self(-self.0)
-            }
+        reduce_ops! {
+            for $vector:
+                sum -> (|x| x),
+                product -> (|x| x)
         }
 
+        mul_add_ops! { for $vector }
+        gather_ops! { for $vector }
+
         as_slice! { $vector }
 
         unsafe impl Vector for $vector {
@@ -136,13 +224,328 @@ macro_rules! implement {
     }
 }
 
-implement! { f32x1, f32 }
-implement! { f64x1, f64 }
+macro_rules! implement_float {
+    {
+        $vector:ty, $scalar:ty
+    } => {
+        implement! { $vector, $scalar, add -> (), sub -> (), mul -> (), div -> () }
+
+        impl core::ops::Neg for $vector {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+    }
+}
+
+// Integer vectors wrap on overflow, like the native integer instructions.
+macro_rules! implement_integer {
+    {
+        $vector:ty, $scalar:ty
+    } => {
+        implement! {
+            $vector, $scalar,
+            add -> [wrapping_add],
+            sub -> [wrapping_sub],
+            mul -> [wrapping_mul],
+            div -> [wrapping_div]
+        }
+
+        bitwise_ops! {
+            for $vector:
+                and -> (core::ops::BitAnd::bitand),
+                or -> (core::ops::BitOr::bitor),
+                xor -> (core::ops::BitXor::bitxor),
+                not -> (core::ops::Not::not),
+                and_not -> (|a: $scalar, b: $scalar| a & !b)
+        }
+    };
+    {
+        $vector:ty, $scalar:ty, signed
+    } => {
+        implement_integer! { $vector, $scalar }
+
+        impl core::ops::Neg for $vector {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self(self.0.wrapping_neg())
+            }
+        }
+    };
+}
+
+macro_rules! implement_compare {
+    {
+        $($vector:ident),*
+    } => {
+        $(
+        compare_ops! {
+            for $vector => mask1:
+                eq -> |a, b| a == b,
+                ne -> |a, b| a != b,
+                lt -> |a, b| a < b,
+                le -> |a, b| a <= b,
+                gt -> |a, b| a > b,
+                ge -> |a, b| a >= b
+        }
+
+        select_ops! {
+            for mask1 => $vector: |mask, a, b| if mask { a } else { b }
+        }
+
+        reduce_ops! {
+            for $vector:
+                min -> (|x| x),
+                max -> (|x| x)
+        }
+        )*
+    }
+}
+
+implement_compare! { f32x1, f64x1, i8x1, i16x1, i32x1, i64x1, u8x1, u16x1, u32x1, u64x1 }
+
+implement_float! { f32x1, f32 }
+implement_float! { f64x1, f64 }
+
+// The sign is manipulated through the bit representation, since `abs` and `copysign` require std.
+macro_rules! implement_float_ops {
+    {
+        $($vector:ty, $scalar:ty);*
+    } => {
+        $(
+        float_ops! {
+            for $vector:
+                min -> (|a: $scalar, b: $scalar| if a.is_nan() || b.is_nan() { <$scalar>::NAN } else { a.min(b) }),
+                max -> (|a: $scalar, b: $scalar| if a.is_nan() || b.is_nan() { <$scalar>::NAN } else { a.max(b) }),
+                abs -> (|a: $scalar| <$scalar>::from_bits(a.to_bits() & !(-0. as $scalar).to_bits())),
+                copysign -> (|a: $scalar, b: $scalar| {
+                    let sign = (-0. as $scalar).to_bits();
+                    <$scalar>::from_bits((a.to_bits() & !sign) | (b.to_bits() & sign))
+                })
+        }
+        )*
+    }
+}
+
+implement_float_ops! { f32x1, f32; f64x1, f64 }
+
+/// Scalar bitwise operations on the bit representation of floats, or the parts of complex numbers.
+trait BitwiseScalar: Copy {
+    fn and(self, other: Self) -> Self;
+    fn or(self, other: Self) -> Self;
+    fn xor(self, other: Self) -> Self;
+    fn not(self) -> Self;
+    fn and_not(self, other: Self) -> Self;
+}
+
+macro_rules! implement_bitwise_scalar {
+    {
+        $($scalar:ty),*
+    } => {
+        $(
+        impl BitwiseScalar for $scalar {
+            #[inline]
+            fn and(self, other: Self) -> Self {
+                Self::from_bits(self.to_bits() & other.to_bits())
+            }
+
+            #[inline]
+            fn or(self, other: Self) -> Self {
+                Self::from_bits(self.to_bits() | other.to_bits())
+            }
+
+            #[inline]
+            fn xor(self, other: Self) -> Self {
+                Self::from_bits(self.to_bits() ^ other.to_bits())
+            }
+
+            #[inline]
+            fn not(self) -> Self {
+                Self::from_bits(!self.to_bits())
+            }
+
+            #[inline]
+            fn and_not(self, other: Self) -> Self {
+                Self::from_bits(self.to_bits() & !other.to_bits())
+            }
+        }
+        )*
+    }
+}
+
+implement_bitwise_scalar! { f32, f64 }
 
 #[cfg(feature = "complex")]
-implement! { cf32x1, Complex<f32> }
+impl<T: BitwiseScalar> BitwiseScalar for Complex<T> {
+    #[inline]
+    fn and(self, other: Self) -> Self {
+        Complex::new(self.re.and(other.re), self.im.and(other.im))
+    }
+
+    #[inline]
+    fn or(self, other: Self) -> Self {
+        Complex::new(self.re.or(other.re), self.im.or(other.im))
+    }
+
+    #[inline]
+    fn xor(self, other: Self) -> Self {
+        Complex::new(self.re.xor(other.re), self.im.xor(other.im))
+    }
+
+    #[inline]
+    fn not(self) -> Self {
+        Complex::new(self.re.not(), self.im.not())
+    }
+
+    #[inline]
+    fn and_not(self, other: Self) -> Self {
+        Complex::new(self.re.and_not(other.re), self.im.and_not(other.im))
+    }
+}
+
+macro_rules! implement_bitwise {
+    {
+        $($vector:ty),*
+    } => {
+        $(
+        bitwise_ops! {
+            for $vector:
+                and -> (BitwiseScalar::and),
+                or -> (BitwiseScalar::or),
+                xor -> (BitwiseScalar::xor),
+                not -> (BitwiseScalar::not),
+                and_not -> (BitwiseScalar::and_not)
+        }
+        )*
+    }
+}
+
+implement_bitwise! { f32x1, f64x1 }
 #[cfg(feature = "complex")]
-implement! { cf64x1, Complex<f64> }
+implement_bitwise! { cf32x1, cf64x1 }
+
+/// Scalar math functions, which are provided by `libm` when std is unavailable.
+pub(crate) trait Math: Copy {
+    fn sqrt(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn round_ties_even(self) -> Self;
+    fn trunc(self) -> Self;
+}
+
+// Rounding ties to even is not provided by std, so `libm` is always used.
+macro_rules! implement_math {
+    {
+        $scalar:ty:
+            $($func:ident -> $libm:path),*;
+            round_ties_even -> $round_ties_even:path
+    } => {
+        impl Math for $scalar {
+            $(
+            #[cfg(feature = "std")]
+            #[inline]
+            fn $func(self) -> Self {
+                <$scalar>::$func(self)
+            }
+
+            #[cfg(not(feature = "std"))]
+            #[inline]
+            fn $func(self) -> Self {
+                $libm(self)
+            }
+            )*
+
+            #[inline]
+            fn round_ties_even(self) -> Self {
+                $round_ties_even(self)
+            }
+        }
+    }
+}
+
+implement_math! {
+    f32:
+        sqrt -> libm::sqrtf,
+        floor -> libm::floorf,
+        ceil -> libm::ceilf,
+        round -> libm::roundf,
+        trunc -> libm::truncf;
+        round_ties_even -> libm::rintf
+}
+
+implement_math! {
+    f64:
+        sqrt -> libm::sqrt,
+        floor -> libm::floor,
+        ceil -> libm::ceil,
+        round -> libm::round,
+        trunc -> libm::trunc;
+        round_ties_even -> libm::rint
+}
+
+sqrt_ops! {
+    for f32x1:
+        sqrt -> (Math::sqrt),
+        recip -> (|a: f32| 1. / a),
+        rsqrt -> (|a: f32| 1. / Math::sqrt(a))
+}
+
+sqrt_ops! {
+    for f64x1:
+        sqrt -> (Math::sqrt),
+        recip -> (|a: f64| 1. / a),
+        rsqrt -> (|a: f64| 1. / Math::sqrt(a))
+}
+
+round_ops! {
+    for f32x1:
+        floor -> (Math::floor),
+        ceil -> (Math::ceil),
+        round -> (Math::round),
+        round_ties_even -> (Math::round_ties_even),
+        trunc -> (Math::trunc)
+}
+
+round_ops! {
+    for f64x1:
+        floor -> (Math::floor),
+        ceil -> (Math::ceil),
+        round -> (Math::round),
+        round_ties_even -> (Math::round_ties_even),
+        trunc -> (Math::trunc)
+}
+
+implement_integer! { i8x1, i8, signed }
+implement_integer! { i16x1, i16, signed }
+implement_integer! { i32x1, i32, signed }
+implement_integer! { i64x1, i64, signed }
+implement_integer! { u8x1, u8 }
+implement_integer! { u16x1, u16 }
+implement_integer! { u32x1, u32 }
+implement_integer! { u64x1, u64 }
+
+shift_ops! {
+    for u32x1:
+        shift_left -> (core::ops::Shl::shl),
+        shift_right -> (core::ops::Shr::shr)
+}
+
+shift_ops! {
+    for u64x1:
+        shift_left -> (core::ops::Shl::shl),
+        shift_right -> (core::ops::Shr::shr)
+}
+
+#[cfg(feature = "complex")]
+implement_float! { cf32x1, Complex<f32> }
+#[cfg(feature = "complex")]
+implement_float! { cf64x1, Complex<f64> }
 
 #[cfg(feature = "complex")]
 macro_rules! implement_complex {
@@ -174,3 +577,36 @@ macro_rules! implement_complex {
 implement_complex! { cf32x1, f32 }
 #[cfg(feature = "complex")]
 implement_complex! { cf64x1, f64 }
+
+convert_ops! {
+    for f32x1 => f64 as f64x1:
+        convert -> (|x| x as f64)
+}
+
+convert_ops! {
+    for f64x1 => f32 as f32x1:
+        convert -> (|x| x as f32)
+}
+
+convert_ops! {
+    for f32x1 => i32 as i32x1:
+        convert -> (|x| x as i32),
+        round -> (crate::vector::round_to_i32)
+}
+
+convert_ops! {
+    for i32x1 => f32 as f32x1:
+        convert -> (|x| x as f32)
+}
+
+bits_ops! {
+    for f32x1 => u32x1:
+        to_bits -> (f32::to_bits),
+        from_bits -> (f32::from_bits)
+}
+
+bits_ops! {
+    for f64x1 => u64x1:
+        to_bits -> (f64::to_bits),
+        from_bits -> (f64::from_bits)
+}
@@ -106,12 +106,31 @@ macro_rules! call_macro_with_tokens_impl {
     }
 }
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(feature = "nightly", any(target_arch = "x86", target_arch = "x86_64")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! call_macro_with_tokens_impl {
+    { $mac:ident } => {
+        $mac! {
+            $crate::arch::x86::Avx512,
+            $crate::arch::x86::Avx2,
+            $crate::arch::x86::Avx,
+            $crate::arch::x86::Sse,
+            $crate::arch::generic::Generic,
+        }
+    }
+}
+
+#[cfg(all(
+    not(feature = "nightly"),
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
 #[doc(hidden)]
 #[macro_export]
 macro_rules! call_macro_with_tokens_impl {
     { $mac:ident } => {
         $mac! {
+            $crate::arch::x86::Avx2,
             $crate::arch::x86::Avx,
             $crate::arch::x86::Sse,
             $crate::arch::generic::Generic,
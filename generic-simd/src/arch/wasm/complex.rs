@@ -64,6 +64,8 @@ impl Scalar<Simd128, width::W8> for Complex<f64> {
     type Vector = Shim8<cf64x1, Complex<f64>>;
 }
 
+shim_widths! { Simd128, [Complex<f32>, Complex<f64>], [W8 -> W16, W16 -> W32, W32 -> W64] }
+
 as_slice! { cf32x2 }
 as_slice! { cf64x1 }
 
@@ -110,6 +112,15 @@ arithmetic_ops! {
         div -> (cf32x2_div)
 }
 
+reduce_ops! {
+    for cf32x2:
+        sum -> [+],
+        product -> [*]
+}
+
+mul_add_ops! { for cf32x2 }
+gather_ops! { for cf32x2 }
+
 arithmetic_ops! {
     feature: Simd128::new_unchecked(),
     for cf64x1:
@@ -119,6 +130,15 @@ arithmetic_ops! {
         div -> (cf64x1_div)
 }
 
+reduce_ops! {
+    for cf64x1:
+        sum -> [+],
+        product -> [*]
+}
+
+mul_add_ops! { for cf64x1 }
+gather_ops! { for cf64x1 }
+
 #[target_feature(enable = "simd128")]
 #[inline]
 unsafe fn f32x4_ldup(x: v128) -> v128 {
@@ -220,3 +240,21 @@ impl core::ops::Neg for cf64x1 {
         Self(unsafe { f64x2_neg(self.0) })
     }
 }
+
+bitwise_ops! {
+    for cf32x2:
+        and -> (v128_and),
+        or -> (v128_or),
+        xor -> (v128_xor),
+        not -> (v128_not),
+        and_not -> (v128_andnot)
+}
+
+bitwise_ops! {
+    for cf64x1:
+        and -> (v128_and),
+        or -> (v128_or),
+        xor -> (v128_xor),
+        not -> (v128_not),
+        and_not -> (v128_andnot)
+}
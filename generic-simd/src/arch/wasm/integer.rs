@@ -0,0 +1,527 @@
+use crate::{
+    arch::{generic, wasm::*, Token},
+    scalar::Scalar,
+    shim::{Shim2, Shim4, Shim8, ShimToken},
+    vector::{width, Native, Vector},
+};
+use core::arch::wasm32::*;
+
+/// A simd128 vector of `i8`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct i8x16(v128);
+
+/// A simd128 vector of `i16`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct i16x8(v128);
+
+/// A simd128 vector of `i32`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct i32x4(v128);
+
+/// A simd128 vector of `i64`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct i64x2(v128);
+
+/// A simd128 vector of `u8`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u8x16(v128);
+
+/// A simd128 vector of `u16`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u16x8(v128);
+
+/// A simd128 vector of `u32`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u32x4(v128);
+
+/// A simd128 vector of `u64`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u64x2(v128);
+
+impl Native<Simd128> for i8 {
+    type Width = width::W16;
+}
+
+impl Native<Simd128> for u8 {
+    type Width = width::W16;
+}
+
+impl Native<Simd128> for i16 {
+    type Width = width::W8;
+}
+
+impl Native<Simd128> for u16 {
+    type Width = width::W8;
+}
+
+impl Native<Simd128> for i32 {
+    type Width = width::W4;
+}
+
+impl Native<Simd128> for u32 {
+    type Width = width::W4;
+}
+
+impl Native<Simd128> for i64 {
+    type Width = width::W2;
+}
+
+impl Native<Simd128> for u64 {
+    type Width = width::W2;
+}
+
+impl Scalar<Simd128, width::W1> for i8 {
+    type Vector = ShimToken<generic::i8x1, Self, Simd128>;
+}
+
+impl Scalar<Simd128, width::W2> for i8 {
+    type Vector = ShimToken<Shim2<generic::i8x1, Self>, Self, Simd128>;
+}
+
+impl Scalar<Simd128, width::W4> for i8 {
+    type Vector = ShimToken<Shim4<generic::i8x1, Self>, Self, Simd128>;
+}
+
+impl Scalar<Simd128, width::W8> for i8 {
+    type Vector = ShimToken<Shim8<generic::i8x1, Self>, Self, Simd128>;
+}
+
+impl Scalar<Simd128, width::W16> for i8 {
+    type Vector = i8x16;
+}
+
+impl Scalar<Simd128, width::W1> for u8 {
+    type Vector = ShimToken<generic::u8x1, Self, Simd128>;
+}
+
+impl Scalar<Simd128, width::W2> for u8 {
+    type Vector = ShimToken<Shim2<generic::u8x1, Self>, Self, Simd128>;
+}
+
+impl Scalar<Simd128, width::W4> for u8 {
+    type Vector = ShimToken<Shim4<generic::u8x1, Self>, Self, Simd128>;
+}
+
+impl Scalar<Simd128, width::W8> for u8 {
+    type Vector = ShimToken<Shim8<generic::u8x1, Self>, Self, Simd128>;
+}
+
+impl Scalar<Simd128, width::W16> for u8 {
+    type Vector = u8x16;
+}
+
+impl Scalar<Simd128, width::W1> for i16 {
+    type Vector = ShimToken<generic::i16x1, Self, Simd128>;
+}
+
+impl Scalar<Simd128, width::W2> for i16 {
+    type Vector = ShimToken<Shim2<generic::i16x1, Self>, Self, Simd128>;
+}
+
+impl Scalar<Simd128, width::W4> for i16 {
+    type Vector = ShimToken<Shim4<generic::i16x1, Self>, Self, Simd128>;
+}
+
+impl Scalar<Simd128, width::W8> for i16 {
+    type Vector = i16x8;
+}
+
+impl Scalar<Simd128, width::W1> for u16 {
+    type Vector = ShimToken<generic::u16x1, Self, Simd128>;
+}
+
+impl Scalar<Simd128, width::W2> for u16 {
+    type Vector = ShimToken<Shim2<generic::u16x1, Self>, Self, Simd128>;
+}
+
+impl Scalar<Simd128, width::W4> for u16 {
+    type Vector = ShimToken<Shim4<generic::u16x1, Self>, Self, Simd128>;
+}
+
+impl Scalar<Simd128, width::W8> for u16 {
+    type Vector = u16x8;
+}
+
+impl Scalar<Simd128, width::W1> for i32 {
+    type Vector = ShimToken<generic::i32x1, Self, Simd128>;
+}
+
+impl Scalar<Simd128, width::W2> for i32 {
+    type Vector = ShimToken<Shim2<generic::i32x1, Self>, Self, Simd128>;
+}
+
+impl Scalar<Simd128, width::W4> for i32 {
+    type Vector = i32x4;
+}
+
+impl Scalar<Simd128, width::W8> for i32 {
+    type Vector = Shim2<i32x4, Self>;
+}
+
+impl Scalar<Simd128, width::W1> for u32 {
+    type Vector = ShimToken<generic::u32x1, Self, Simd128>;
+}
+
+impl Scalar<Simd128, width::W2> for u32 {
+    type Vector = ShimToken<Shim2<generic::u32x1, Self>, Self, Simd128>;
+}
+
+impl Scalar<Simd128, width::W4> for u32 {
+    type Vector = u32x4;
+}
+
+impl Scalar<Simd128, width::W8> for u32 {
+    type Vector = Shim2<u32x4, Self>;
+}
+
+impl Scalar<Simd128, width::W1> for i64 {
+    type Vector = ShimToken<generic::i64x1, Self, Simd128>;
+}
+
+impl Scalar<Simd128, width::W2> for i64 {
+    type Vector = i64x2;
+}
+
+impl Scalar<Simd128, width::W4> for i64 {
+    type Vector = Shim2<i64x2, Self>;
+}
+
+impl Scalar<Simd128, width::W8> for i64 {
+    type Vector = Shim4<i64x2, Self>;
+}
+
+impl Scalar<Simd128, width::W1> for u64 {
+    type Vector = ShimToken<generic::u64x1, Self, Simd128>;
+}
+
+impl Scalar<Simd128, width::W2> for u64 {
+    type Vector = u64x2;
+}
+
+impl Scalar<Simd128, width::W4> for u64 {
+    type Vector = Shim2<u64x2, Self>;
+}
+
+impl Scalar<Simd128, width::W8> for u64 {
+    type Vector = Shim4<u64x2, Self>;
+}
+
+shim_widths! { Simd128, [i8, u8], [W16 -> W32, W32 -> W64] }
+shim_widths! { Simd128, [i16, u16, i32, u32, i64, u64], [W8 -> W16, W16 -> W32, W32 -> W64] }
+
+// Integer vectors wrap on overflow.  Operations without a simd128 instruction are performed
+// lane-wise.
+macro_rules! implement {
+    {
+        $vector:ty, $scalar:ty, $width:ty, $splat:ident, $splat_type:ty,
+        add -> $add:tt,
+        sub -> $sub:tt,
+        mul -> $mul:tt,
+        div -> $div:tt
+    } => {
+        as_slice! { $vector }
+
+        mul_add_ops! { for $vector }
+        gather_ops! { for $vector }
+
+        bitwise_ops! {
+            for $vector:
+                and -> (v128_and),
+                or -> (v128_or),
+                xor -> (v128_xor),
+                not -> (v128_not),
+                and_not -> (v128_andnot)
+        }
+
+        unsafe impl Vector for $vector {
+            type Scalar = $scalar;
+            type Token = Simd128;
+            type Width = $width;
+            type Underlying = v128;
+
+            #[inline]
+            fn zeroed(_: Self::Token) -> Self {
+                Self(unsafe { $splat(0) })
+            }
+
+            #[inline]
+            fn splat(_: Self::Token, value: Self::Scalar) -> Self {
+                Self(unsafe { $splat(value as $splat_type) })
+            }
+        }
+
+        arithmetic_ops! {
+            feature: Simd128::new_unchecked(),
+            for $vector:
+                add -> $add,
+                sub -> $sub,
+                mul -> $mul,
+                div -> $div
+        }
+    };
+    {
+        $vector:ty, $scalar:ty, $width:ty, $splat:ident, $splat_type:ty,
+        add -> $add:tt,
+        sub -> $sub:tt,
+        mul -> $mul:tt,
+        div -> $div:tt,
+        neg -> $neg:path
+    } => {
+        implement! {
+            $vector, $scalar, $width, $splat, $splat_type,
+            add -> $add,
+            sub -> $sub,
+            mul -> $mul,
+            div -> $div
+        }
+
+        impl core::ops::Neg for $vector {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self(unsafe { $neg(self.0) })
+            }
+        }
+    };
+}
+
+implement! {
+    i8x16, i8, width::W16, i8x16_splat, i8,
+    add -> (i8x16_add),
+    sub -> (i8x16_sub),
+    mul -> [wrapping_mul],
+    div -> [wrapping_div],
+    neg -> i8x16_neg
+}
+
+implement! {
+    i16x8, i16, width::W8, i16x8_splat, i16,
+    add -> (i16x8_add),
+    sub -> (i16x8_sub),
+    mul -> (i16x8_mul),
+    div -> [wrapping_div],
+    neg -> i16x8_neg
+}
+
+implement! {
+    i32x4, i32, width::W4, i32x4_splat, i32,
+    add -> (i32x4_add),
+    sub -> (i32x4_sub),
+    mul -> (i32x4_mul),
+    div -> [wrapping_div],
+    neg -> i32x4_neg
+}
+
+implement! {
+    i64x2, i64, width::W2, i64x2_splat, i64,
+    add -> (i64x2_add),
+    sub -> (i64x2_sub),
+    mul -> [wrapping_mul],
+    div -> [wrapping_div],
+    neg -> i64x2_neg
+}
+
+implement! {
+    u8x16, u8, width::W16, i8x16_splat, i8,
+    add -> (i8x16_add),
+    sub -> (i8x16_sub),
+    mul -> [wrapping_mul],
+    div -> [wrapping_div]
+}
+
+implement! {
+    u16x8, u16, width::W8, i16x8_splat, i16,
+    add -> (i16x8_add),
+    sub -> (i16x8_sub),
+    mul -> (i16x8_mul),
+    div -> [wrapping_div]
+}
+
+implement! {
+    u32x4, u32, width::W4, i32x4_splat, i32,
+    add -> (i32x4_add),
+    sub -> (i32x4_sub),
+    mul -> (i32x4_mul),
+    div -> [wrapping_div]
+}
+
+implement! {
+    u64x2, u64, width::W2, i64x2_splat, i64,
+    add -> (i64x2_add),
+    sub -> (i64x2_sub),
+    mul -> [wrapping_mul],
+    div -> [wrapping_div]
+}
+
+shift_ops! {
+    for u32x4:
+        shift_left -> (i32x4_shl),
+        shift_right -> (i32x4_shr_u)
+}
+
+shift_ops! {
+    for u64x2:
+        shift_left -> (i64x2_shl),
+        shift_right -> (i64x2_shr_u)
+}
+
+macro_rules! compare_64 {
+    {
+        $scalar:ty, $op:tt
+    } => {
+        |a, b| {
+            let a: [$scalar; 2] = core::mem::transmute(a);
+            let b: [$scalar; 2] = core::mem::transmute(b);
+            let lanes = [
+                -((a[0] $op b[0]) as i64),
+                -((a[1] $op b[1]) as i64),
+            ];
+            core::mem::transmute::<[i64; 2], v128>(lanes)
+        }
+    }
+}
+
+compare_ops! {
+    for i8x16 => mask8x16:
+        eq -> i8x16_eq,
+        ne -> i8x16_ne,
+        lt -> i8x16_lt_s,
+        le -> i8x16_le_s,
+        gt -> i8x16_gt_s,
+        ge -> i8x16_ge_s
+}
+
+compare_ops! {
+    for i16x8 => mask16x8:
+        eq -> i16x8_eq,
+        ne -> i16x8_ne,
+        lt -> i16x8_lt_s,
+        le -> i16x8_le_s,
+        gt -> i16x8_gt_s,
+        ge -> i16x8_ge_s
+}
+
+compare_ops! {
+    for i32x4 => mask32x4:
+        eq -> i32x4_eq,
+        ne -> i32x4_ne,
+        lt -> i32x4_lt_s,
+        le -> i32x4_le_s,
+        gt -> i32x4_gt_s,
+        ge -> i32x4_ge_s
+}
+
+compare_ops! {
+    for u8x16 => mask8x16:
+        eq -> i8x16_eq,
+        ne -> i8x16_ne,
+        lt -> i8x16_lt_u,
+        le -> i8x16_le_u,
+        gt -> i8x16_gt_u,
+        ge -> i8x16_ge_u
+}
+
+compare_ops! {
+    for u16x8 => mask16x8:
+        eq -> i16x8_eq,
+        ne -> i16x8_ne,
+        lt -> i16x8_lt_u,
+        le -> i16x8_le_u,
+        gt -> i16x8_gt_u,
+        ge -> i16x8_ge_u
+}
+
+compare_ops! {
+    for u32x4 => mask32x4:
+        eq -> i32x4_eq,
+        ne -> i32x4_ne,
+        lt -> i32x4_lt_u,
+        le -> i32x4_le_u,
+        gt -> i32x4_gt_u,
+        ge -> i32x4_ge_u
+}
+
+// 64-bit comparisons are performed lane-wise.
+compare_ops! {
+    for i64x2 => mask64x2:
+        eq -> compare_64!(i64, ==),
+        ne -> compare_64!(i64, !=),
+        lt -> compare_64!(i64, <),
+        le -> compare_64!(i64, <=),
+        gt -> compare_64!(i64, >),
+        ge -> compare_64!(i64, >=)
+}
+
+compare_ops! {
+    for u64x2 => mask64x2:
+        eq -> compare_64!(u64, ==),
+        ne -> compare_64!(u64, !=),
+        lt -> compare_64!(u64, <),
+        le -> compare_64!(u64, <=),
+        gt -> compare_64!(u64, >),
+        ge -> compare_64!(u64, >=)
+}
+
+select_ops! {
+    for mask8x16 =>
+        i8x16: |mask, a, b| v128_bitselect(a, b, mask),
+        u8x16: |mask, a, b| v128_bitselect(a, b, mask)
+}
+
+select_ops! {
+    for mask16x8 =>
+        i16x8: |mask, a, b| v128_bitselect(a, b, mask),
+        u16x8: |mask, a, b| v128_bitselect(a, b, mask)
+}
+
+select_ops! {
+    for mask32x4 =>
+        i32x4: |mask, a, b| v128_bitselect(a, b, mask),
+        u32x4: |mask, a, b| v128_bitselect(a, b, mask)
+}
+
+select_ops! {
+    for mask64x2 =>
+        i64x2: |mask, a, b| v128_bitselect(a, b, mask),
+        u64x2: |mask, a, b| v128_bitselect(a, b, mask)
+}
+
+// Horizontal reductions of integers are performed lane-wise.
+macro_rules! implement_reduce {
+    {
+        $($vector:ty),*
+    } => {
+        $(
+        reduce_ops! {
+            for $vector:
+                sum -> [wrapping_add],
+                product -> [wrapping_mul]
+        }
+
+        reduce_ops! {
+            for $vector:
+                min -> [min],
+                max -> [max]
+        }
+        )*
+    }
+}
+
+implement_reduce! { i8x16, i16x8, i32x4, i64x2, u8x16, u16x8, u32x4, u64x2 }
@@ -0,0 +1,77 @@
+use crate::{
+    arch::wasm::Simd128,
+    vector::{width, Mask},
+};
+use core::arch::wasm32::*;
+
+/// A simd128 mask of 16 8-bit lanes.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct mask8x16(pub(super) v128);
+
+/// A simd128 mask of 8 16-bit lanes.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct mask16x8(pub(super) v128);
+
+/// A simd128 mask of 4 32-bit lanes.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct mask32x4(pub(super) v128);
+
+/// A simd128 mask of 2 64-bit lanes.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct mask64x2(pub(super) v128);
+
+// Each lane of a mask is either all ones or all zeros, so any lane is set if any byte is set.
+macro_rules! implement {
+    {
+        $mask:ty, $width:ty, $lane:ty, $splat:ident, $splat_type:ty
+    } => {
+        bitwise_ops! {
+            for $mask:
+                and -> (v128_and),
+                or -> (v128_or),
+                xor -> (v128_xor),
+                not -> (v128_not)
+        }
+
+        impl Mask for $mask {
+            type Token = Simd128;
+            type Width = $width;
+
+            #[inline]
+            fn splat(_: Self::Token, value: bool) -> Self {
+                Self(unsafe { $splat(-(value as $splat_type)) })
+            }
+
+            #[inline]
+            fn test(&self, lane: usize) -> bool {
+                assert!(lane < Self::width(), "lane index out of range");
+                let lanes: [$lane; <$width as width::Width>::VALUE] =
+                    unsafe { core::mem::transmute(self.0) };
+                lanes[lane] != 0
+            }
+
+            #[inline]
+            fn any(&self) -> bool {
+                unsafe { i8x16_any_true(self.0) != 0 }
+            }
+
+            #[inline]
+            fn all(&self) -> bool {
+                unsafe { i8x16_all_true(self.0) != 0 }
+            }
+        }
+    }
+}
+
+implement! { mask8x16, width::W16, u8, i8x16_splat, i8 }
+implement! { mask16x8, width::W8, u16, i16x8_splat, i16 }
+implement! { mask32x4, width::W4, u32, i32x4_splat, i32 }
+implement! { mask64x2, width::W2, u64, i64x2_splat, i64 }
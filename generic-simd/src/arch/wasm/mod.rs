@@ -5,6 +5,12 @@ mod complex;
 #[cfg(feature = "complex")]
 pub use complex::*;
 
+mod integer;
+pub use integer::*;
+
+mod mask;
+pub use mask::*;
+
 use crate::{
     arch::{generic, Token},
     scalar::Scalar,
@@ -71,6 +77,43 @@ impl Scalar<Simd128, width::W8> for f64 {
     type Vector = Shim4<f64x2, f64>;
 }
 
+shim_widths! { Simd128, [f32, f64], [W8 -> W16, W16 -> W32, W32 -> W64] }
+
+// simd128 only truncates when converting between floats and integers of the same lane size, so
+// the other conversions are performed lane-wise.
+convert_ops! {
+    for f32x4 => f64 as Shim2<f64x2, f64>:
+        convert -> [|x| x as f64]
+}
+
+convert_ops! {
+    for f64x2 => f32 as <f32 as Scalar<Simd128, width::W2>>::Vector:
+        convert -> [|x| x as f32]
+}
+
+convert_ops! {
+    for f32x4 => i32 as i32x4:
+        convert -> (i32x4_trunc_sat_f32x4_s),
+        round -> [crate::vector::round_to_i32]
+}
+
+convert_ops! {
+    for i32x4 => f32 as f32x4:
+        convert -> (f32x4_convert_i32x4_s)
+}
+
+bits_ops! {
+    for f32x4 => u32x4:
+        to_bits -> (|x| x),
+        from_bits -> (|x| x)
+}
+
+bits_ops! {
+    for f64x2 => u64x2:
+        to_bits -> (|x| x),
+        from_bits -> (|x| x)
+}
+
 as_slice! { f32x4 }
 as_slice! { f64x2 }
 
@@ -89,6 +132,35 @@ unsafe impl Vector for f32x4 {
     fn splat(_: Self::Token, value: Self::Scalar) -> Self {
         Self(unsafe { f32x4_splat(value) })
     }
+
+    #[inline]
+    fn reverse(self) -> Self {
+        Self(unsafe { v32x4_shuffle::<3, 2, 1, 0>(self.0, self.0) })
+    }
+
+    #[inline]
+    fn rotate_lanes_left_impl(self, n: usize) -> Self {
+        Self(unsafe {
+            match n {
+                1 => v32x4_shuffle::<1, 2, 3, 0>(self.0, self.0),
+                2 => v32x4_shuffle::<2, 3, 0, 1>(self.0, self.0),
+                3 => v32x4_shuffle::<3, 0, 1, 2>(self.0, self.0),
+                _ => self.0,
+            }
+        })
+    }
+
+    #[inline]
+    fn broadcast_lane_impl(self, lane: usize) -> Self {
+        Self(unsafe {
+            match lane {
+                0 => v32x4_shuffle::<0, 0, 0, 0>(self.0, self.0),
+                1 => v32x4_shuffle::<1, 1, 1, 1>(self.0, self.0),
+                2 => v32x4_shuffle::<2, 2, 2, 2>(self.0, self.0),
+                _ => v32x4_shuffle::<3, 3, 3, 3>(self.0, self.0),
+            }
+        })
+    }
 }
 
 unsafe impl Vector for f64x2 {
@@ -106,6 +178,31 @@ unsafe impl Vector for f64x2 {
     fn splat(_: Self::Token, value: Self::Scalar) -> Self {
         Self(unsafe { f64x2_splat(value) })
     }
+
+    #[inline]
+    fn reverse(self) -> Self {
+        Self(unsafe { v64x2_shuffle::<1, 0>(self.0, self.0) })
+    }
+
+    #[inline]
+    fn rotate_lanes_left_impl(self, n: usize) -> Self {
+        if n == 1 {
+            self.reverse()
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    fn broadcast_lane_impl(self, lane: usize) -> Self {
+        Self(unsafe {
+            if lane == 0 {
+                v64x2_shuffle::<0, 0>(self.0, self.0)
+            } else {
+                v64x2_shuffle::<1, 1>(self.0, self.0)
+            }
+        })
+    }
 }
 
 arithmetic_ops! {
@@ -117,6 +214,9 @@ arithmetic_ops! {
         div -> (f32x4_div)
 }
 
+mul_add_ops! { for f32x4 }
+gather_ops! { for f32x4 }
+
 arithmetic_ops! {
     feature: Simd128::new_unchecked(),
     for f64x2:
@@ -126,6 +226,121 @@ arithmetic_ops! {
         div -> (f64x2_div)
 }
 
+mul_add_ops! { for f64x2 }
+gather_ops! { for f64x2 }
+
+compare_ops! {
+    for f32x4 => mask32x4:
+        eq -> f32x4_eq,
+        ne -> f32x4_ne,
+        lt -> f32x4_lt,
+        le -> f32x4_le,
+        gt -> f32x4_gt,
+        ge -> f32x4_ge
+}
+
+compare_ops! {
+    for f64x2 => mask64x2:
+        eq -> f64x2_eq,
+        ne -> f64x2_ne,
+        lt -> f64x2_lt,
+        le -> f64x2_le,
+        gt -> f64x2_gt,
+        ge -> f64x2_ge
+}
+
+select_ops! {
+    for mask32x4 => f32x4: |mask, a, b| v128_bitselect(a, b, mask)
+}
+
+select_ops! {
+    for mask64x2 => f64x2: |mask, a, b| v128_bitselect(a, b, mask)
+}
+
+float_ops! {
+    for f32x4:
+        min -> (f32x4_min),
+        max -> (f32x4_max),
+        abs -> (f32x4_abs),
+        copysign -> (|a, b| v128_bitselect(b, a, i32x4_splat(i32::MIN)))
+}
+
+float_ops! {
+    for f64x2:
+        min -> (f64x2_min),
+        max -> (f64x2_max),
+        abs -> (f64x2_abs),
+        copysign -> (|a, b| v128_bitselect(b, a, i64x2_splat(i64::MIN)))
+}
+
+// There are no estimate instructions, so estimates are exact.
+sqrt_ops! {
+    for f32x4:
+        sqrt -> (f32x4_sqrt),
+        recip -> (|a| f32x4_div(f32x4_splat(1.), a)),
+        rsqrt -> (|a| f32x4_div(f32x4_splat(1.), f32x4_sqrt(a)))
+}
+
+sqrt_ops! {
+    for f64x2:
+        sqrt -> (f64x2_sqrt),
+        recip -> (|a| f64x2_div(f64x2_splat(1.), a)),
+        rsqrt -> (|a| f64x2_div(f64x2_splat(1.), f64x2_sqrt(a)))
+}
+
+round_ops! {
+    for f32x4:
+        floor -> (f32x4_floor),
+        ceil -> (f32x4_ceil),
+        round_ties_even -> (f32x4_nearest),
+        trunc -> (f32x4_trunc)
+}
+
+round_ops! {
+    for f64x2:
+        floor -> (f64x2_floor),
+        ceil -> (f64x2_ceil),
+        round_ties_even -> (f64x2_nearest),
+        trunc -> (f64x2_trunc)
+}
+
+#[inline]
+unsafe fn reduce_f32x4(x: v128, op: impl Fn(v128, v128) -> v128) -> f32 {
+    let x = op(x, v32x4_shuffle::<2, 3, 0, 1>(x, x));
+    let x = op(x, v32x4_shuffle::<1, 0, 3, 2>(x, x));
+    f32x4_extract_lane::<0>(x)
+}
+
+#[inline]
+unsafe fn reduce_f64x2(x: v128, op: impl Fn(v128, v128) -> v128) -> f64 {
+    let x = op(x, v64x2_shuffle::<1, 0>(x, x));
+    f64x2_extract_lane::<0>(x)
+}
+
+reduce_ops! {
+    for f32x4:
+        sum -> (|x| reduce_f32x4(x, |a, b| f32x4_add(a, b))),
+        product -> (|x| reduce_f32x4(x, |a, b| f32x4_mul(a, b)))
+}
+
+reduce_ops! {
+    for f32x4:
+        min -> (|x| reduce_f32x4(x, |a, b| f32x4_min(a, b))),
+        max -> (|x| reduce_f32x4(x, |a, b| f32x4_max(a, b)))
+}
+
+reduce_ops! {
+    for f64x2:
+        sum -> (|x| reduce_f64x2(x, |a, b| f64x2_add(a, b))),
+        product -> (|x| reduce_f64x2(x, |a, b| f64x2_mul(a, b)))
+}
+
+reduce_ops! {
+    for f64x2:
+        min -> (|x| reduce_f64x2(x, |a, b| f64x2_min(a, b))),
+        max -> (|x| reduce_f64x2(x, |a, b| f64x2_max(a, b)))
+}
+
 impl core::ops::Neg for f32x4 {
     type Output = Self;
 
@@ -143,3 +358,21 @@ impl core::ops::Neg for f64x2 {
         Self(unsafe { f64x2_neg(self.0) })
     }
 }
+
+bitwise_ops! {
+    for f32x4:
+        and -> (v128_and),
+        or -> (v128_or),
+        xor -> (v128_xor),
+        not -> (v128_not),
+        and_not -> (v128_andnot)
+}
+
+bitwise_ops! {
+    for f64x2:
+        and -> (v128_and),
+        or -> (v128_or),
+        xor -> (v128_xor),
+        not -> (v128_not),
+        and_not -> (v128_andnot)
+}
@@ -0,0 +1,640 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use crate::{
+    arch::{generic, x86::*, Token},
+    scalar::Scalar,
+    shim::{Shim2, ShimToken},
+    vector::{width, Mask, Native, Vector},
+};
+
+#[cfg(feature = "complex")]
+use num_complex::Complex;
+
+/// An AVX-512 vector of `f32`s.
+///
+/// Requires feature `"nightly"`.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct f32x16(__m512);
+
+/// An AVX-512 vector of `f64`s.
+///
+/// Requires feature `"nightly"`.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct f64x8(__m512d);
+
+/// An AVX-512 mask of 16 32-bit lanes.
+///
+/// Requires feature `"nightly"`.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct mask32x16(__mmask16);
+
+/// An AVX-512 mask of 8 64-bit lanes.
+///
+/// Requires feature `"nightly"`.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct mask64x8(__mmask8);
+
+impl Native<Avx512> for f32 {
+    type Width = width::W16;
+}
+
+impl Native<Avx512> for f64 {
+    type Width = width::W8;
+}
+
+impl Scalar<Avx512, width::W1> for f32 {
+    type Vector = ShimToken<generic::f32x1, Self, Avx512>;
+}
+
+impl Scalar<Avx512, width::W2> for f32 {
+    type Vector = ShimToken<Shim2<generic::f32x1, Self>, Self, Avx512>;
+}
+
+impl Scalar<Avx512, width::W4> for f32 {
+    type Vector = ShimToken<f32x4, Self, Avx512>;
+}
+
+impl Scalar<Avx512, width::W8> for f32 {
+    type Vector = ShimToken<f32x8, Self, Avx512>;
+}
+
+impl Scalar<Avx512, width::W16> for f32 {
+    type Vector = f32x16;
+}
+
+impl Scalar<Avx512, width::W1> for f64 {
+    type Vector = ShimToken<generic::f64x1, Self, Avx512>;
+}
+
+impl Scalar<Avx512, width::W2> for f64 {
+    type Vector = ShimToken<f64x2, Self, Avx512>;
+}
+
+impl Scalar<Avx512, width::W4> for f64 {
+    type Vector = ShimToken<f64x4, Self, Avx512>;
+}
+
+impl Scalar<Avx512, width::W8> for f64 {
+    type Vector = f64x8;
+}
+
+shim_widths! { Avx512, [f32], [W16 -> W32, W32 -> W64] }
+shim_widths! { Avx512, [f64], [W8 -> W16, W16 -> W32, W32 -> W64] }
+
+// AVX-512F has no integer or complex vectors yet, so these use the AVX2 vectors.
+macro_rules! implement_shim_scalar {
+    {
+        $scalar:ty, [$($width:ident),*]
+    } => {
+        impl Native<Avx512> for $scalar {
+            type Width = <Self as Native<Avx2>>::Width;
+        }
+
+        $(
+        impl Scalar<Avx512, width::$width> for $scalar {
+            type Vector = ShimToken<<Self as Scalar<Avx2, width::$width>>::Vector, Self, Avx512>;
+        }
+        )*
+    }
+}
+
+implement_shim_scalar! { i8, [W1, W2, W4, W8, W16, W32, W64] }
+implement_shim_scalar! { u8, [W1, W2, W4, W8, W16, W32, W64] }
+implement_shim_scalar! { i16, [W1, W2, W4, W8, W16, W32, W64] }
+implement_shim_scalar! { u16, [W1, W2, W4, W8, W16, W32, W64] }
+implement_shim_scalar! { i32, [W1, W2, W4, W8, W16, W32, W64] }
+implement_shim_scalar! { u32, [W1, W2, W4, W8, W16, W32, W64] }
+implement_shim_scalar! { i64, [W1, W2, W4, W8, W16, W32, W64] }
+implement_shim_scalar! { u64, [W1, W2, W4, W8, W16, W32, W64] }
+
+#[cfg(feature = "complex")]
+implement_shim_scalar! { Complex<f32>, [W1, W2, W4, W8, W16, W32, W64] }
+#[cfg(feature = "complex")]
+implement_shim_scalar! { Complex<f64>, [W1, W2, W4, W8, W16, W32, W64] }
+
+bitwise_ops! {
+    for mask32x16:
+        and -> (core::ops::BitAnd::bitand),
+        or -> (core::ops::BitOr::bitor),
+        xor -> (core::ops::BitXor::bitxor),
+        not -> (core::ops::Not::not)
+}
+
+bitwise_ops! {
+    for mask64x8:
+        and -> (core::ops::BitAnd::bitand),
+        or -> (core::ops::BitOr::bitor),
+        xor -> (core::ops::BitXor::bitxor),
+        not -> (core::ops::Not::not)
+}
+
+// AVX-512 masks are bitmasks, with one bit per lane.
+macro_rules! implement_mask {
+    {
+        $mask:ty, $width:ty
+    } => {
+        impl Mask for $mask {
+            type Token = Avx512;
+            type Width = $width;
+
+            #[inline]
+            fn splat(_: Self::Token, value: bool) -> Self {
+                Self(if value { !0 } else { 0 })
+            }
+
+            #[inline]
+            fn test(&self, lane: usize) -> bool {
+                assert!(lane < Self::width(), "lane index out of range");
+                (self.0 >> lane) & 1 != 0
+            }
+
+            #[inline]
+            fn any(&self) -> bool {
+                self.0 != 0
+            }
+
+            #[inline]
+            fn all(&self) -> bool {
+                self.0 == !0
+            }
+        }
+    }
+}
+
+implement_mask! { mask32x16, width::W16 }
+implement_mask! { mask64x8, width::W8 }
+
+arithmetic_ops! {
+    feature: Avx512::new_unchecked(),
+    for f32x16:
+        add -> (_mm512_add_ps),
+        sub -> (_mm512_sub_ps),
+        mul -> (_mm512_mul_ps),
+        div -> (_mm512_div_ps)
+}
+
+arithmetic_ops! {
+    feature: Avx512::new_unchecked(),
+    for f64x8:
+        add -> (_mm512_add_pd),
+        sub -> (_mm512_sub_pd),
+        mul -> (_mm512_mul_pd),
+        div -> (_mm512_div_pd)
+}
+
+compare_ops! {
+    for f32x16 => mask32x16:
+        eq -> |a, b| _mm512_cmp_ps_mask(a, b, _CMP_EQ_OQ),
+        ne -> |a, b| _mm512_cmp_ps_mask(a, b, _CMP_NEQ_UQ),
+        lt -> |a, b| _mm512_cmp_ps_mask(a, b, _CMP_LT_OQ),
+        le -> |a, b| _mm512_cmp_ps_mask(a, b, _CMP_LE_OQ),
+        gt -> |a, b| _mm512_cmp_ps_mask(a, b, _CMP_GT_OQ),
+        ge -> |a, b| _mm512_cmp_ps_mask(a, b, _CMP_GE_OQ)
+}
+
+compare_ops! {
+    for f64x8 => mask64x8:
+        eq -> |a, b| _mm512_cmp_pd_mask(a, b, _CMP_EQ_OQ),
+        ne -> |a, b| _mm512_cmp_pd_mask(a, b, _CMP_NEQ_UQ),
+        lt -> |a, b| _mm512_cmp_pd_mask(a, b, _CMP_LT_OQ),
+        le -> |a, b| _mm512_cmp_pd_mask(a, b, _CMP_LE_OQ),
+        gt -> |a, b| _mm512_cmp_pd_mask(a, b, _CMP_GT_OQ),
+        ge -> |a, b| _mm512_cmp_pd_mask(a, b, _CMP_GE_OQ)
+}
+
+// `mask_blend` selects the second argument where the mask is set.
+select_ops! {
+    for mask32x16 => f32x16: |mask, a, b| _mm512_mask_blend_ps(mask, b, a)
+}
+
+select_ops! {
+    for mask64x8 => f64x8: |mask, a, b| _mm512_mask_blend_pd(mask, b, a)
+}
+
+reduce_ops! {
+    for f32x16:
+        sum -> (|x| _mm512_reduce_add_ps(x)),
+        product -> (|x| _mm512_reduce_mul_ps(x))
+}
+
+reduce_ops! {
+    for f32x16:
+        min -> (|x| _mm512_reduce_min_ps(x)),
+        max -> (|x| _mm512_reduce_max_ps(x))
+}
+
+reduce_ops! {
+    for f64x8:
+        sum -> (|x| _mm512_reduce_add_pd(x)),
+        product -> (|x| _mm512_reduce_mul_pd(x))
+}
+
+reduce_ops! {
+    for f64x8:
+        min -> (|x| _mm512_reduce_min_pd(x)),
+        max -> (|x| _mm512_reduce_max_pd(x))
+}
+
+// AVX-512F implies FMA, so narrower vectors are fused as well.
+mul_add_ops! {
+    for f32x4 => Avx512:
+        mul_add -> _mm_fmadd_ps,
+        mul_sub -> _mm_fmsub_ps
+}
+
+mul_add_ops! {
+    for f64x2 => Avx512:
+        mul_add -> _mm_fmadd_pd,
+        mul_sub -> _mm_fmsub_pd
+}
+
+mul_add_ops! {
+    for f32x8 => Avx512:
+        mul_add -> _mm256_fmadd_ps,
+        mul_sub -> _mm256_fmsub_ps
+}
+
+mul_add_ops! {
+    for f64x4 => Avx512:
+        mul_add -> _mm256_fmadd_pd,
+        mul_sub -> _mm256_fmsub_pd
+}
+
+mul_add_ops! {
+    for f32x16 => Avx512:
+        mul_add -> _mm512_fmadd_ps,
+        mul_sub -> _mm512_fmsub_ps
+}
+
+mul_add_ops! {
+    for f64x8 => Avx512:
+        mul_add -> _mm512_fmadd_pd,
+        mul_sub -> _mm512_fmsub_pd
+}
+
+gather_ops! {
+    for f32x4 => Avx512:
+        gather -> |from, offsets| _mm_i32gather_ps(from, _mm_loadu_si128(offsets as *const _), 4)
+}
+
+gather_ops! {
+    for f64x2 => Avx512:
+        gather -> |from, offsets| _mm_i32gather_pd(from, _mm_loadu_si128(offsets as *const _), 8)
+}
+
+gather_ops! {
+    for f32x8 => Avx512:
+        gather -> |from, offsets| _mm256_i32gather_ps(from, _mm256_loadu_si256(offsets as *const _), 4)
+}
+
+gather_ops! {
+    for f64x4 => Avx512:
+        gather -> |from, offsets| _mm256_i32gather_pd(from, _mm_loadu_si128(offsets as *const _), 8)
+}
+
+gather_ops! {
+    for f32x16 => Avx512:
+        gather -> |from, offsets| _mm512_i32gather_ps(_mm512_loadu_si512(offsets as *const _), from as *const _, 4)
+}
+
+gather_ops! {
+    for f64x8 => Avx512:
+        gather -> |from, offsets| _mm512_i32gather_pd(_mm256_loadu_si256(offsets as *const _), from as *const _, 8)
+}
+
+// Integer vectors are AVX2 vectors, so 512-bit results are split into 256-bit halves.
+#[inline]
+unsafe fn split_si512(x: __m512i) -> [__m256i; 2] {
+    [_mm512_castsi512_si256(x), _mm512_extracti64x4_epi64(x, 1)]
+}
+
+#[inline]
+unsafe fn join_si512([low, high]: [__m256i; 2]) -> __m512i {
+    _mm512_inserti64x4(_mm512_castsi256_si512(low), high, 1)
+}
+
+convert_ops! {
+    for f32x16 => f64 as Shim2<f64x8, f64>:
+        convert -> (|x| [
+            _mm512_cvtps_pd(_mm512_castps512_ps256(x)),
+            _mm512_cvtps_pd(_mm256_castpd_ps(_mm512_extractf64x4_pd(_mm512_castps_pd(x), 1))),
+        ])
+}
+
+convert_ops! {
+    for f64x8 => f32 as ShimToken<f32x8, f32, Avx512>:
+        convert -> (_mm512_cvtpd_ps)
+}
+
+convert_ops! {
+    for f32x16 => i32 as ShimToken<Shim2<i32x8, i32>, i32, Avx512>:
+        convert -> (|x| split_si512(_mm512_cvttps_epi32(x))),
+        round -> (|x| split_si512(_mm512_cvtps_epi32(x)))
+}
+
+bits_ops! {
+    for f32x16 => ShimToken<Shim2<u32x8, u32>, u32, Avx512>:
+        to_bits -> (|x| split_si512(_mm512_castps_si512(x))),
+        from_bits -> (|x| _mm512_castsi512_ps(join_si512(x)))
+}
+
+bits_ops! {
+    for f64x8 => ShimToken<Shim2<u64x4, u64>, u64, Avx512>:
+        to_bits -> (|x| split_si512(_mm512_castpd_si512(x))),
+        from_bits -> (|x| _mm512_castsi512_pd(join_si512(x)))
+}
+
+// AVX-512F has no floating-point XOR, so the sign bit is flipped with an integer XOR.
+impl core::ops::Neg for f32x16 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(unsafe {
+            _mm512_castsi512_ps(_mm512_xor_si512(
+                _mm512_castps_si512(self.0),
+                _mm512_set1_epi32(i32::MIN),
+            ))
+        })
+    }
+}
+
+impl core::ops::Neg for f64x8 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(unsafe {
+            _mm512_castsi512_pd(_mm512_xor_si512(
+                _mm512_castpd_si512(self.0),
+                _mm512_set1_epi64(i64::MIN),
+            ))
+        })
+    }
+}
+
+// Likewise, the bitwise operations are performed with the integer instructions.
+bitwise_ops! {
+    for f32x16:
+        and -> (|a, b| _mm512_castsi512_ps(_mm512_and_si512(_mm512_castps_si512(a), _mm512_castps_si512(b)))),
+        or -> (|a, b| _mm512_castsi512_ps(_mm512_or_si512(_mm512_castps_si512(a), _mm512_castps_si512(b)))),
+        xor -> (|a, b| _mm512_castsi512_ps(_mm512_xor_si512(_mm512_castps_si512(a), _mm512_castps_si512(b)))),
+        not -> (|a| _mm512_castsi512_ps(_mm512_xor_si512(_mm512_castps_si512(a), _mm512_set1_epi32(-1)))),
+        and_not -> (|a, b| _mm512_castsi512_ps(_mm512_andnot_si512(_mm512_castps_si512(b), _mm512_castps_si512(a))))
+}
+
+bitwise_ops! {
+    for f64x8:
+        and -> (|a, b| _mm512_castsi512_pd(_mm512_and_si512(_mm512_castpd_si512(a), _mm512_castpd_si512(b)))),
+        or -> (|a, b| _mm512_castsi512_pd(_mm512_or_si512(_mm512_castpd_si512(a), _mm512_castpd_si512(b)))),
+        xor -> (|a, b| _mm512_castsi512_pd(_mm512_xor_si512(_mm512_castpd_si512(a), _mm512_castpd_si512(b)))),
+        not -> (|a| _mm512_castsi512_pd(_mm512_xor_si512(_mm512_castpd_si512(a), _mm512_set1_epi32(-1)))),
+        and_not -> (|a, b| _mm512_castsi512_pd(_mm512_andnot_si512(_mm512_castpd_si512(b), _mm512_castpd_si512(a))))
+}
+
+// As with the narrower vectors, NaNs in the first operand of min and max are propagated separately.
+float_ops! {
+    for f32x16:
+        min -> (|a, b| _mm512_mask_mov_ps(_mm512_min_ps(a, b), _mm512_cmp_ps_mask(a, a, _CMP_UNORD_Q), a)),
+        max -> (|a, b| _mm512_mask_mov_ps(_mm512_max_ps(a, b), _mm512_cmp_ps_mask(a, a, _CMP_UNORD_Q), a)),
+        abs -> (_mm512_abs_ps),
+        copysign -> (|a, b| {
+            let sign = _mm512_set1_epi32(i32::MIN);
+            _mm512_castsi512_ps(_mm512_or_si512(
+                _mm512_andnot_si512(sign, _mm512_castps_si512(a)),
+                _mm512_and_si512(sign, _mm512_castps_si512(b)),
+            ))
+        })
+}
+
+float_ops! {
+    for f64x8:
+        min -> (|a, b| _mm512_mask_mov_pd(_mm512_min_pd(a, b), _mm512_cmp_pd_mask(a, a, _CMP_UNORD_Q), a)),
+        max -> (|a, b| _mm512_mask_mov_pd(_mm512_max_pd(a, b), _mm512_cmp_pd_mask(a, a, _CMP_UNORD_Q), a)),
+        abs -> (_mm512_abs_pd),
+        copysign -> (|a, b| {
+            let sign = _mm512_set1_epi64(i64::MIN);
+            _mm512_castsi512_pd(_mm512_or_si512(
+                _mm512_andnot_si512(sign, _mm512_castpd_si512(a)),
+                _mm512_and_si512(sign, _mm512_castpd_si512(b)),
+            ))
+        })
+}
+
+// The AVX-512 estimate instructions have a relative error of at most 2^-14.
+sqrt_ops! {
+    for f32x16:
+        sqrt -> (_mm512_sqrt_ps),
+        recip -> (|a| _mm512_div_ps(_mm512_set1_ps(1.), a)),
+        rsqrt -> (|a| _mm512_div_ps(_mm512_set1_ps(1.), _mm512_sqrt_ps(a))),
+        recip_estimate -> (_mm512_rcp14_ps),
+        rsqrt_estimate -> (_mm512_rsqrt14_ps)
+}
+
+sqrt_ops! {
+    for f64x8:
+        sqrt -> (_mm512_sqrt_pd),
+        recip -> (|a| _mm512_div_pd(_mm512_set1_pd(1.), a)),
+        rsqrt -> (|a| _mm512_div_pd(_mm512_set1_pd(1.), _mm512_sqrt_pd(a))),
+        recip_estimate -> (_mm512_rcp14_pd),
+        rsqrt_estimate -> (_mm512_rsqrt14_pd)
+}
+
+// The rounding mode is encoded in the low bits of the `roundscale` immediate.
+round_ops! {
+    for f32x16:
+        floor -> (|a| _mm512_roundscale_ps(a, _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC)),
+        ceil -> (|a| _mm512_roundscale_ps(a, _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC)),
+        round_ties_even -> (|a| _mm512_roundscale_ps(a, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC)),
+        trunc -> (|a| _mm512_roundscale_ps(a, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC))
+}
+
+round_ops! {
+    for f64x8:
+        floor -> (|a| _mm512_roundscale_pd(a, _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC)),
+        ceil -> (|a| _mm512_roundscale_pd(a, _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC)),
+        round_ties_even -> (|a| _mm512_roundscale_pd(a, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC)),
+        trunc -> (|a| _mm512_roundscale_pd(a, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC))
+}
+
+as_slice! { f32x16 }
+as_slice! { f64x8 }
+
+unsafe impl Vector for f32x16 {
+    type Scalar = f32;
+
+    type Token = Avx512;
+
+    type Width = crate::vector::width::W16;
+
+    type Underlying = __m512;
+
+    #[inline]
+    fn zeroed(_: Self::Token) -> Self {
+        Self(unsafe { _mm512_setzero_ps() })
+    }
+
+    #[inline]
+    fn splat(_: Self::Token, from: Self::Scalar) -> Self {
+        Self(unsafe { _mm512_set1_ps(from) })
+    }
+
+    // Scatters take signed 32-bit offsets, so larger slices are written lane-by-lane.
+    #[inline]
+    unsafe fn scatter_unchecked(self, to: &mut [Self::Scalar], indices: &[usize]) {
+        if to.len() > i32::MAX as usize {
+            for (lane, index) in self.as_slice().iter().zip(indices) {
+                *to.get_unchecked_mut(*index) = *lane;
+            }
+        } else {
+            let mut offsets = [0i32; 16];
+            for (offset, index) in offsets.iter_mut().zip(indices) {
+                *offset = *index as i32;
+            }
+            _mm512_i32scatter_ps(
+                to.as_mut_ptr() as *mut _,
+                _mm512_loadu_si512(offsets.as_ptr() as *const _),
+                self.0,
+                4,
+            );
+        }
+    }
+
+    #[inline]
+    unsafe fn write_strided_unchecked(self, to: &mut [Self::Scalar], stride: usize) {
+        if to.len() > i32::MAX as usize {
+            for (i, lane) in self.as_slice().iter().enumerate() {
+                *to.get_unchecked_mut(i * stride) = *lane;
+            }
+        } else {
+            let mut offsets = [0i32; 16];
+            for (i, offset) in offsets.iter_mut().enumerate() {
+                *offset = (i * stride) as i32;
+            }
+            _mm512_i32scatter_ps(
+                to.as_mut_ptr() as *mut _,
+                _mm512_loadu_si512(offsets.as_ptr() as *const _),
+                self.0,
+                4,
+            );
+        }
+    }
+
+    #[inline]
+    fn split(self) -> (ShimToken<f32x8, f32, Avx512>, ShimToken<f32x8, f32, Avx512>) {
+        let token = self.to_token();
+        unsafe {
+            (
+                Vector::from_underlying(token, _mm512_castps512_ps256(self.0)),
+                Vector::from_underlying(
+                    token,
+                    _mm256_castpd_ps(_mm512_extractf64x4_pd(_mm512_castps_pd(self.0), 1)),
+                ),
+            )
+        }
+    }
+
+    #[inline]
+    fn concat(low: ShimToken<f32x8, f32, Avx512>, high: ShimToken<f32x8, f32, Avx512>) -> Self {
+        Self(unsafe {
+            _mm512_castpd_ps(_mm512_insertf64x4(
+                _mm512_castps_pd(_mm512_castps256_ps512(low.to_underlying())),
+                _mm256_castps_pd(high.to_underlying()),
+                1,
+            ))
+        })
+    }
+}
+
+unsafe impl Vector for f64x8 {
+    type Scalar = f64;
+
+    type Token = Avx512;
+
+    type Width = crate::vector::width::W8;
+
+    type Underlying = __m512d;
+
+    #[inline]
+    fn zeroed(_: Self::Token) -> Self {
+        Self(unsafe { _mm512_setzero_pd() })
+    }
+
+    #[inline]
+    fn splat(_: Self::Token, from: Self::Scalar) -> Self {
+        Self(unsafe { _mm512_set1_pd(from) })
+    }
+
+    #[inline]
+    unsafe fn scatter_unchecked(self, to: &mut [Self::Scalar], indices: &[usize]) {
+        if to.len() > i32::MAX as usize {
+            for (lane, index) in self.as_slice().iter().zip(indices) {
+                *to.get_unchecked_mut(*index) = *lane;
+            }
+        } else {
+            let mut offsets = [0i32; 8];
+            for (offset, index) in offsets.iter_mut().zip(indices) {
+                *offset = *index as i32;
+            }
+            _mm512_i32scatter_pd(
+                to.as_mut_ptr() as *mut _,
+                _mm256_loadu_si256(offsets.as_ptr() as *const _),
+                self.0,
+                8,
+            );
+        }
+    }
+
+    #[inline]
+    unsafe fn write_strided_unchecked(self, to: &mut [Self::Scalar], stride: usize) {
+        if to.len() > i32::MAX as usize {
+            for (i, lane) in self.as_slice().iter().enumerate() {
+                *to.get_unchecked_mut(i * stride) = *lane;
+            }
+        } else {
+            let mut offsets = [0i32; 8];
+            for (i, offset) in offsets.iter_mut().enumerate() {
+                *offset = (i * stride) as i32;
+            }
+            _mm512_i32scatter_pd(
+                to.as_mut_ptr() as *mut _,
+                _mm256_loadu_si256(offsets.as_ptr() as *const _),
+                self.0,
+                8,
+            );
+        }
+    }
+
+    #[inline]
+    fn split(self) -> (ShimToken<f64x4, f64, Avx512>, ShimToken<f64x4, f64, Avx512>) {
+        let token = self.to_token();
+        unsafe {
+            (
+                Vector::from_underlying(token, _mm512_castpd512_pd256(self.0)),
+                Vector::from_underlying(token, _mm512_extractf64x4_pd(self.0, 1)),
+            )
+        }
+    }
+
+    #[inline]
+    fn concat(low: ShimToken<f64x4, f64, Avx512>, high: ShimToken<f64x4, f64, Avx512>) -> Self {
+        Self(unsafe {
+            _mm512_insertf64x4(
+                _mm512_castpd256_pd512(low.to_underlying()),
+                high.to_underlying(),
+                1,
+            )
+        })
+    }
+}
@@ -27,6 +27,14 @@ impl Native<Avx> for Complex<f64> {
     type Width = width::W2;
 }
 
+impl Native<Avx2> for Complex<f32> {
+    type Width = width::W4;
+}
+
+impl Native<Avx2> for Complex<f64> {
+    type Width = width::W2;
+}
+
 /// An SSE vector of `Complex<f32>`s.
 ///
 /// Requires feature `"complex"`.
@@ -123,6 +131,42 @@ impl Scalar<Avx, width::W8> for Complex<f64> {
     type Vector = Shim4<cf64x2, Complex<f64>>;
 }
 
+impl Scalar<Avx2, width::W1> for Complex<f32> {
+    type Vector = ShimToken<generic::cf32x1, Self, Avx2>;
+}
+
+impl Scalar<Avx2, width::W2> for Complex<f32> {
+    type Vector = ShimToken<cf32x2, Self, Avx2>;
+}
+
+impl Scalar<Avx2, width::W4> for Complex<f32> {
+    type Vector = ShimToken<cf32x4, Self, Avx2>;
+}
+
+impl Scalar<Avx2, width::W8> for Complex<f32> {
+    type Vector = ShimToken<Shim2<cf32x4, Complex<f32>>, Self, Avx2>;
+}
+
+impl Scalar<Avx2, width::W1> for Complex<f64> {
+    type Vector = ShimToken<cf64x1, Self, Avx2>;
+}
+
+impl Scalar<Avx2, width::W2> for Complex<f64> {
+    type Vector = ShimToken<cf64x2, Self, Avx2>;
+}
+
+impl Scalar<Avx2, width::W4> for Complex<f64> {
+    type Vector = ShimToken<Shim2<cf64x2, Complex<f64>>, Self, Avx2>;
+}
+
+impl Scalar<Avx2, width::W8> for Complex<f64> {
+    type Vector = ShimToken<Shim4<cf64x2, Complex<f64>>, Self, Avx2>;
+}
+
+shim_widths! { Sse, [Complex<f32>, Complex<f64>], [W8 -> W16, W16 -> W32, W32 -> W64] }
+shim_widths! { Avx, [Complex<f32>, Complex<f64>], [W8 -> W16, W16 -> W32, W32 -> W64] }
+shim_widths! { Avx2, [Complex<f32>, Complex<f64>], [W8 -> W16, W16 -> W32, W32 -> W64] }
+
 arithmetic_ops! {
     feature: Sse::new_unchecked(),
     for cf32x2:
@@ -132,6 +176,15 @@ arithmetic_ops! {
         div -> (div_cf32x2)
 }
 
+reduce_ops! {
+    for cf32x2:
+        sum -> [+],
+        product -> [*]
+}
+
+mul_add_ops! { for cf32x2 }
+gather_ops! { for cf32x2 }
+
 arithmetic_ops! {
     feature: Sse::new_unchecked(),
     for cf64x1:
@@ -141,6 +194,15 @@ arithmetic_ops! {
         div -> (div_cf64x1)
 }
 
+reduce_ops! {
+    for cf64x1:
+        sum -> [+],
+        product -> [*]
+}
+
+mul_add_ops! { for cf64x1 }
+gather_ops! { for cf64x1 }
+
 arithmetic_ops! {
     feature: Avx::new_unchecked(),
     for cf32x4:
@@ -150,6 +212,15 @@ arithmetic_ops! {
         div -> (div_cf32x4)
 }
 
+reduce_ops! {
+    for cf32x4:
+        sum -> [+],
+        product -> [*]
+}
+
+mul_add_ops! { for cf32x4 }
+gather_ops! { for cf32x4 }
+
 arithmetic_ops! {
     feature: Avx::new_unchecked(),
     for cf64x2:
@@ -159,6 +230,15 @@ arithmetic_ops! {
         div -> (div_cf64x2)
 }
 
+reduce_ops! {
+    for cf64x2:
+        sum -> [+],
+        product -> [*]
+}
+
+mul_add_ops! { for cf64x2 }
+gather_ops! { for cf64x2 }
+
 #[target_feature(enable = "sse3")]
 #[inline]
 unsafe fn mul_cf32x2(a: __m128, b: __m128) -> __m128 {
@@ -297,6 +377,42 @@ impl core::ops::Neg for cf64x2 {
     }
 }
 
+bitwise_ops! {
+    for cf32x2:
+        and -> (_mm_and_ps),
+        or -> (_mm_or_ps),
+        xor -> (_mm_xor_ps),
+        not -> (not_ps),
+        and_not -> (|a, b| _mm_andnot_ps(b, a))
+}
+
+bitwise_ops! {
+    for cf64x1:
+        and -> (_mm_and_pd),
+        or -> (_mm_or_pd),
+        xor -> (_mm_xor_pd),
+        not -> (not_pd),
+        and_not -> (|a, b| _mm_andnot_pd(b, a))
+}
+
+bitwise_ops! {
+    for cf32x4:
+        and -> (_mm256_and_ps),
+        or -> (_mm256_or_ps),
+        xor -> (_mm256_xor_ps),
+        not -> (not_ps256),
+        and_not -> (|a, b| _mm256_andnot_ps(b, a))
+}
+
+bitwise_ops! {
+    for cf64x2:
+        and -> (_mm256_and_pd),
+        or -> (_mm256_or_pd),
+        xor -> (_mm256_xor_pd),
+        not -> (not_pd256),
+        and_not -> (|a, b| _mm256_andnot_pd(b, a))
+}
+
 as_slice! { cf32x2 }
 as_slice! { cf32x4 }
 as_slice! { cf64x1 }
@@ -0,0 +1,1063 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use crate::{
+    arch::{generic, x86::*, Token},
+    scalar::Scalar,
+    shim::{Shim2, Shim4, Shim8, ShimToken},
+    vector::{width, Native, Vector},
+};
+
+/// An SSE vector of `i8`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct i8x16(__m128i);
+
+/// An SSE vector of `i16`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct i16x8(__m128i);
+
+/// An SSE vector of `i32`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct i32x4(__m128i);
+
+/// An SSE vector of `i64`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct i64x2(__m128i);
+
+/// An SSE vector of `u8`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u8x16(__m128i);
+
+/// An SSE vector of `u16`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u16x8(__m128i);
+
+/// An SSE vector of `u32`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u32x4(__m128i);
+
+/// An SSE vector of `u64`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u64x2(__m128i);
+
+/// An AVX2 vector of `i8`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct i8x32(__m256i);
+
+/// An AVX2 vector of `i16`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct i16x16(__m256i);
+
+/// An AVX2 vector of `i32`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct i32x8(__m256i);
+
+/// An AVX2 vector of `i64`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct i64x4(__m256i);
+
+/// An AVX2 vector of `u8`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u8x32(__m256i);
+
+/// An AVX2 vector of `u16`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u16x16(__m256i);
+
+/// An AVX2 vector of `u32`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u32x8(__m256i);
+
+/// An AVX2 vector of `u64`s.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct u64x4(__m256i);
+
+macro_rules! implement_scalar {
+    {
+        $scalar:ty, $native:ty,
+        w1 -> $w1:ty,
+        w2 -> $w2:ty,
+        w4 -> $w4:ty,
+        w8 -> $w8:ty
+        $(, w16 -> $w16:ty)?
+    } => {
+        impl Native<Sse> for $scalar {
+            type Width = $native;
+        }
+
+        impl Native<Avx> for $scalar {
+            type Width = $native;
+        }
+
+        impl Scalar<Sse, width::W1> for $scalar {
+            type Vector = $w1;
+        }
+
+        impl Scalar<Sse, width::W2> for $scalar {
+            type Vector = $w2;
+        }
+
+        impl Scalar<Sse, width::W4> for $scalar {
+            type Vector = $w4;
+        }
+
+        impl Scalar<Sse, width::W8> for $scalar {
+            type Vector = $w8;
+        }
+
+        $(
+        impl Scalar<Sse, width::W16> for $scalar {
+            type Vector = $w16;
+        }
+
+        impl Scalar<Avx, width::W16> for $scalar {
+            type Vector = ShimToken<$w16, Self, Avx>;
+        }
+        )?
+
+        impl Scalar<Avx, width::W1> for $scalar {
+            type Vector = ShimToken<$w1, Self, Avx>;
+        }
+
+        impl Scalar<Avx, width::W2> for $scalar {
+            type Vector = ShimToken<$w2, Self, Avx>;
+        }
+
+        impl Scalar<Avx, width::W4> for $scalar {
+            type Vector = ShimToken<$w4, Self, Avx>;
+        }
+
+        impl Scalar<Avx, width::W8> for $scalar {
+            type Vector = ShimToken<$w8, Self, Avx>;
+        }
+    }
+}
+
+implement_scalar! {
+    i8, width::W16,
+    w1 -> ShimToken<generic::i8x1, Self, Sse>,
+    w2 -> ShimToken<Shim2<generic::i8x1, Self>, Self, Sse>,
+    w4 -> ShimToken<Shim4<generic::i8x1, Self>, Self, Sse>,
+    w8 -> ShimToken<Shim8<generic::i8x1, Self>, Self, Sse>,
+    w16 -> i8x16
+}
+
+implement_scalar! {
+    u8, width::W16,
+    w1 -> ShimToken<generic::u8x1, Self, Sse>,
+    w2 -> ShimToken<Shim2<generic::u8x1, Self>, Self, Sse>,
+    w4 -> ShimToken<Shim4<generic::u8x1, Self>, Self, Sse>,
+    w8 -> ShimToken<Shim8<generic::u8x1, Self>, Self, Sse>,
+    w16 -> u8x16
+}
+
+implement_scalar! {
+    i16, width::W8,
+    w1 -> ShimToken<generic::i16x1, Self, Sse>,
+    w2 -> ShimToken<Shim2<generic::i16x1, Self>, Self, Sse>,
+    w4 -> ShimToken<Shim4<generic::i16x1, Self>, Self, Sse>,
+    w8 -> i16x8
+}
+
+implement_scalar! {
+    u16, width::W8,
+    w1 -> ShimToken<generic::u16x1, Self, Sse>,
+    w2 -> ShimToken<Shim2<generic::u16x1, Self>, Self, Sse>,
+    w4 -> ShimToken<Shim4<generic::u16x1, Self>, Self, Sse>,
+    w8 -> u16x8
+}
+
+implement_scalar! {
+    i32, width::W4,
+    w1 -> ShimToken<generic::i32x1, Self, Sse>,
+    w2 -> ShimToken<Shim2<generic::i32x1, Self>, Self, Sse>,
+    w4 -> i32x4,
+    w8 -> Shim2<i32x4, Self>
+}
+
+implement_scalar! {
+    u32, width::W4,
+    w1 -> ShimToken<generic::u32x1, Self, Sse>,
+    w2 -> ShimToken<Shim2<generic::u32x1, Self>, Self, Sse>,
+    w4 -> u32x4,
+    w8 -> Shim2<u32x4, Self>
+}
+
+implement_scalar! {
+    i64, width::W2,
+    w1 -> ShimToken<generic::i64x1, Self, Sse>,
+    w2 -> i64x2,
+    w4 -> Shim2<i64x2, Self>,
+    w8 -> Shim4<i64x2, Self>
+}
+
+implement_scalar! {
+    u64, width::W2,
+    w1 -> ShimToken<generic::u64x1, Self, Sse>,
+    w2 -> u64x2,
+    w4 -> Shim2<u64x2, Self>,
+    w8 -> Shim4<u64x2, Self>
+}
+
+// Widths narrower than a 256-bit register use the SSE vectors.
+macro_rules! implement_avx2_scalar {
+    {
+        $scalar:ty, $native:ty,
+        shim -> [$($shim:ident),*],
+        native -> [$($width:ident -> $vector:ty),*]
+    } => {
+        impl Native<Avx2> for $scalar {
+            type Width = $native;
+        }
+
+        $(
+        impl Scalar<Avx2, width::$shim> for $scalar {
+            type Vector = ShimToken<<Self as Scalar<Sse, width::$shim>>::Vector, Self, Avx2>;
+        }
+        )*
+
+        $(
+        impl Scalar<Avx2, width::$width> for $scalar {
+            type Vector = $vector;
+        }
+        )*
+    }
+}
+
+implement_avx2_scalar! {
+    i8, width::W32,
+    shim -> [W1, W2, W4, W8, W16],
+    native -> [W32 -> i8x32]
+}
+
+implement_avx2_scalar! {
+    u8, width::W32,
+    shim -> [W1, W2, W4, W8, W16],
+    native -> [W32 -> u8x32]
+}
+
+implement_avx2_scalar! {
+    i16, width::W16,
+    shim -> [W1, W2, W4, W8],
+    native -> [W16 -> i16x16]
+}
+
+implement_avx2_scalar! {
+    u16, width::W16,
+    shim -> [W1, W2, W4, W8],
+    native -> [W16 -> u16x16]
+}
+
+implement_avx2_scalar! {
+    i32, width::W8,
+    shim -> [W1, W2, W4],
+    native -> [W8 -> i32x8]
+}
+
+implement_avx2_scalar! {
+    u32, width::W8,
+    shim -> [W1, W2, W4],
+    native -> [W8 -> u32x8]
+}
+
+implement_avx2_scalar! {
+    i64, width::W4,
+    shim -> [W1, W2],
+    native -> [W4 -> i64x4, W8 -> Shim2<i64x4, Self>]
+}
+
+implement_avx2_scalar! {
+    u64, width::W4,
+    shim -> [W1, W2],
+    native -> [W4 -> u64x4, W8 -> Shim2<u64x4, Self>]
+}
+
+shim_widths! { Sse, [i8, u8], [W16 -> W32, W32 -> W64] }
+shim_widths! { Sse, [i16, u16, i32, u32, i64, u64], [W8 -> W16, W16 -> W32, W32 -> W64] }
+shim_widths! { Avx, [i8, u8], [W16 -> W32, W32 -> W64] }
+shim_widths! { Avx, [i16, u16, i32, u32, i64, u64], [W8 -> W16, W16 -> W32, W32 -> W64] }
+shim_widths! { Avx2, [i8, u8], [W32 -> W64] }
+shim_widths! { Avx2, [i16, u16], [W16 -> W32, W32 -> W64] }
+shim_widths! { Avx2, [i32, u32, i64, u64], [W8 -> W16, W16 -> W32, W32 -> W64] }
+
+// Integer vectors wrap on overflow.  Operations without an SSE4.1 or AVX2 instruction are performed
+// lane-wise.  There is no integer division instruction, so `div` is always lane-wise and panics on a
+// zero divisor.
+macro_rules! implement {
+    {
+        $vector:ty, $scalar:ty, $width:ty, $token:ident, $underlying:ty,
+        zeroed -> $setzero:ident,
+        splat -> $set1:ident($set1_type:ty),
+        add -> $add:tt,
+        sub -> $sub:tt,
+        mul -> $mul:tt,
+        div -> $div:tt
+    } => {
+        arithmetic_ops! {
+            feature: $token::new_unchecked(),
+            for $vector:
+                add -> $add,
+                sub -> $sub,
+                mul -> $mul,
+                div -> $div
+        }
+
+        mul_add_ops! { for $vector }
+        gather_ops! { for $vector }
+
+        as_slice! { $vector }
+
+        unsafe impl Vector for $vector {
+            type Scalar = $scalar;
+
+            type Token = $token;
+
+            type Width = $width;
+
+            type Underlying = $underlying;
+
+            #[inline]
+            fn zeroed(_: Self::Token) -> Self {
+                Self(unsafe { $setzero() })
+            }
+
+            #[inline]
+            fn splat(_: Self::Token, from: Self::Scalar) -> Self {
+                Self(unsafe { $set1(from as $set1_type) })
+            }
+        }
+    };
+    {
+        $vector:ty, $scalar:ty, $width:ty, $token:ident, $underlying:ty,
+        zeroed -> $setzero:ident,
+        splat -> $set1:ident($set1_type:ty),
+        add -> $add:tt,
+        sub -> ($sub:path),
+        mul -> $mul:tt,
+        div -> $div:tt,
+        signed
+    } => {
+        implement! {
+            $vector, $scalar, $width, $token, $underlying,
+            zeroed -> $setzero,
+            splat -> $set1($set1_type),
+            add -> $add,
+            sub -> ($sub),
+            mul -> $mul,
+            div -> $div
+        }
+
+        impl core::ops::Neg for $vector {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self(unsafe { $sub($setzero(), self.0) })
+            }
+        }
+    };
+}
+
+implement! {
+    i8x16, i8, width::W16, Sse, __m128i,
+    zeroed -> _mm_setzero_si128,
+    splat -> _mm_set1_epi8(i8),
+    add -> (_mm_add_epi8),
+    sub -> (_mm_sub_epi8),
+    mul -> [wrapping_mul],
+    div -> [wrapping_div],
+    signed
+}
+
+implement! {
+    i16x8, i16, width::W8, Sse, __m128i,
+    zeroed -> _mm_setzero_si128,
+    splat -> _mm_set1_epi16(i16),
+    add -> (_mm_add_epi16),
+    sub -> (_mm_sub_epi16),
+    mul -> (_mm_mullo_epi16),
+    div -> [wrapping_div],
+    signed
+}
+
+implement! {
+    i32x4, i32, width::W4, Sse, __m128i,
+    zeroed -> _mm_setzero_si128,
+    splat -> _mm_set1_epi32(i32),
+    add -> (_mm_add_epi32),
+    sub -> (_mm_sub_epi32),
+    mul -> (_mm_mullo_epi32),
+    div -> [wrapping_div],
+    signed
+}
+
+implement! {
+    i64x2, i64, width::W2, Sse, __m128i,
+    zeroed -> _mm_setzero_si128,
+    splat -> _mm_set1_epi64x(i64),
+    add -> (_mm_add_epi64),
+    sub -> (_mm_sub_epi64),
+    mul -> [wrapping_mul],
+    div -> [wrapping_div],
+    signed
+}
+
+implement! {
+    u8x16, u8, width::W16, Sse, __m128i,
+    zeroed -> _mm_setzero_si128,
+    splat -> _mm_set1_epi8(i8),
+    add -> (_mm_add_epi8),
+    sub -> (_mm_sub_epi8),
+    mul -> [wrapping_mul],
+    div -> [wrapping_div]
+}
+
+implement! {
+    u16x8, u16, width::W8, Sse, __m128i,
+    zeroed -> _mm_setzero_si128,
+    splat -> _mm_set1_epi16(i16),
+    add -> (_mm_add_epi16),
+    sub -> (_mm_sub_epi16),
+    mul -> (_mm_mullo_epi16),
+    div -> [wrapping_div]
+}
+
+implement! {
+    u32x4, u32, width::W4, Sse, __m128i,
+    zeroed -> _mm_setzero_si128,
+    splat -> _mm_set1_epi32(i32),
+    add -> (_mm_add_epi32),
+    sub -> (_mm_sub_epi32),
+    mul -> (_mm_mullo_epi32),
+    div -> [wrapping_div]
+}
+
+implement! {
+    u64x2, u64, width::W2, Sse, __m128i,
+    zeroed -> _mm_setzero_si128,
+    splat -> _mm_set1_epi64x(i64),
+    add -> (_mm_add_epi64),
+    sub -> (_mm_sub_epi64),
+    mul -> [wrapping_mul],
+    div -> [wrapping_div]
+}
+
+implement! {
+    i8x32, i8, width::W32, Avx2, __m256i,
+    zeroed -> _mm256_setzero_si256,
+    splat -> _mm256_set1_epi8(i8),
+    add -> (_mm256_add_epi8),
+    sub -> (_mm256_sub_epi8),
+    mul -> [wrapping_mul],
+    div -> [wrapping_div],
+    signed
+}
+
+implement! {
+    i16x16, i16, width::W16, Avx2, __m256i,
+    zeroed -> _mm256_setzero_si256,
+    splat -> _mm256_set1_epi16(i16),
+    add -> (_mm256_add_epi16),
+    sub -> (_mm256_sub_epi16),
+    mul -> (_mm256_mullo_epi16),
+    div -> [wrapping_div],
+    signed
+}
+
+implement! {
+    i32x8, i32, width::W8, Avx2, __m256i,
+    zeroed -> _mm256_setzero_si256,
+    splat -> _mm256_set1_epi32(i32),
+    add -> (_mm256_add_epi32),
+    sub -> (_mm256_sub_epi32),
+    mul -> (_mm256_mullo_epi32),
+    div -> [wrapping_div],
+    signed
+}
+
+implement! {
+    i64x4, i64, width::W4, Avx2, __m256i,
+    zeroed -> _mm256_setzero_si256,
+    splat -> _mm256_set1_epi64x(i64),
+    add -> (_mm256_add_epi64),
+    sub -> (_mm256_sub_epi64),
+    mul -> [wrapping_mul],
+    div -> [wrapping_div],
+    signed
+}
+
+implement! {
+    u8x32, u8, width::W32, Avx2, __m256i,
+    zeroed -> _mm256_setzero_si256,
+    splat -> _mm256_set1_epi8(i8),
+    add -> (_mm256_add_epi8),
+    sub -> (_mm256_sub_epi8),
+    mul -> [wrapping_mul],
+    div -> [wrapping_div]
+}
+
+implement! {
+    u16x16, u16, width::W16, Avx2, __m256i,
+    zeroed -> _mm256_setzero_si256,
+    splat -> _mm256_set1_epi16(i16),
+    add -> (_mm256_add_epi16),
+    sub -> (_mm256_sub_epi16),
+    mul -> (_mm256_mullo_epi16),
+    div -> [wrapping_div]
+}
+
+implement! {
+    u32x8, u32, width::W8, Avx2, __m256i,
+    zeroed -> _mm256_setzero_si256,
+    splat -> _mm256_set1_epi32(i32),
+    add -> (_mm256_add_epi32),
+    sub -> (_mm256_sub_epi32),
+    mul -> (_mm256_mullo_epi32),
+    div -> [wrapping_div]
+}
+
+implement! {
+    u64x4, u64, width::W4, Avx2, __m256i,
+    zeroed -> _mm256_setzero_si256,
+    splat -> _mm256_set1_epi64x(i64),
+    add -> (_mm256_add_epi64),
+    sub -> (_mm256_sub_epi64),
+    mul -> [wrapping_mul],
+    div -> [wrapping_div]
+}
+
+// Bitwise operations are independent of the lane size.
+macro_rules! implement_bitwise {
+    {
+        $($vector:ty),* => $and:path, $or:path, $xor:path, $not:path, $and_not:path
+    } => {
+        $(
+        bitwise_ops! {
+            for $vector:
+                and -> ($and),
+                or -> ($or),
+                xor -> ($xor),
+                not -> ($not),
+                and_not -> (|a, b| $and_not(b, a))
+        }
+        )*
+    }
+}
+
+implement_bitwise! {
+    i8x16, i16x8, i32x4, i64x2, u8x16, u16x8, u32x4, u64x2 =>
+        _mm_and_si128, _mm_or_si128, _mm_xor_si128, not_si128, _mm_andnot_si128
+}
+
+implement_bitwise! {
+    i8x32, i16x16, i32x8, i64x4, u8x32, u16x16, u32x8, u64x4 =>
+        _mm256_and_si256, _mm256_or_si256, _mm256_xor_si256, not_si256, _mm256_andnot_si256
+}
+
+// The shift instructions take the count in the low 64 bits of a vector.
+macro_rules! implement_shift {
+    {
+        $($vector:ty => $shift_left:path, $shift_right:path);*
+    } => {
+        $(
+        shift_ops! {
+            for $vector:
+                shift_left -> (|a, count: u32| $shift_left(a, _mm_cvtsi32_si128(count as i32))),
+                shift_right -> (|a, count: u32| $shift_right(a, _mm_cvtsi32_si128(count as i32)))
+        }
+        )*
+    }
+}
+
+implement_shift! {
+    u32x4 => _mm_sll_epi32, _mm_srl_epi32;
+    u64x2 => _mm_sll_epi64, _mm_srl_epi64;
+    u32x8 => _mm256_sll_epi32, _mm256_srl_epi32;
+    u64x4 => _mm256_sll_epi64, _mm256_srl_epi64
+}
+
+#[inline]
+unsafe fn compare_i64(a: __m128i, b: __m128i, f: impl Fn(i64, i64) -> bool) -> __m128i {
+    let a: [i64; 2] = core::mem::transmute(a);
+    let b: [i64; 2] = core::mem::transmute(b);
+    _mm_set_epi64x(-(f(a[1], b[1]) as i64), -(f(a[0], b[0]) as i64))
+}
+
+#[inline]
+unsafe fn compare_u64(a: __m128i, b: __m128i, f: impl Fn(u64, u64) -> bool) -> __m128i {
+    let a: [u64; 2] = core::mem::transmute(a);
+    let b: [u64; 2] = core::mem::transmute(b);
+    _mm_set_epi64x(-(f(a[1], b[1]) as i64), -(f(a[0], b[0]) as i64))
+}
+
+// SSE4.1 only provides equality and signed ordering for 8-, 16-, and 32-bit lanes.  Unsigned
+// ordering is derived from min/max, and 64-bit ordering is performed lane-wise.  AVX2 also
+// provides signed 64-bit ordering, which is used for unsigned 64-bit ordering by flipping the
+// sign bits.
+macro_rules! implement_compare {
+    {
+        $vector:ty => $mask:ident $(<$token:ty>)?, $cast:path,
+        eq -> $eq:path,
+        lt -> $lt:expr,
+        gt -> $gt:expr
+    } => {
+        implement_compare! {
+            $vector => $mask $(<$token>)?, $cast, not_si128,
+            eq -> $eq,
+            lt -> $lt,
+            gt -> $gt
+        }
+    };
+    {
+        $vector:ty => $mask:ident $(<$token:ty>)?, $cast:path, $not:path,
+        eq -> $eq:path,
+        lt -> $lt:expr,
+        gt -> $gt:expr
+    } => {
+        compare_ops! {
+            for $vector => $mask $(<$token>)?:
+                eq -> |a, b| $cast($eq(a, b)),
+                ne -> |a, b| $cast($not($eq(a, b))),
+                lt -> |a, b| $cast(($lt)(a, b)),
+                le -> |a, b| $cast($not(($gt)(a, b))),
+                gt -> |a, b| $cast(($gt)(a, b)),
+                ge -> |a, b| $cast($not(($lt)(a, b)))
+        }
+    };
+}
+
+implement_compare! {
+    i8x16 => mask8x16, core::convert::identity,
+    eq -> _mm_cmpeq_epi8,
+    lt -> _mm_cmplt_epi8,
+    gt -> _mm_cmpgt_epi8
+}
+
+implement_compare! {
+    i16x8 => mask16x8, core::convert::identity,
+    eq -> _mm_cmpeq_epi16,
+    lt -> _mm_cmplt_epi16,
+    gt -> _mm_cmpgt_epi16
+}
+
+implement_compare! {
+    i32x4 => mask32x4, _mm_castsi128_ps,
+    eq -> _mm_cmpeq_epi32,
+    lt -> _mm_cmplt_epi32,
+    gt -> _mm_cmpgt_epi32
+}
+
+implement_compare! {
+    i64x2 => mask64x2, _mm_castsi128_pd,
+    eq -> _mm_cmpeq_epi64,
+    lt -> |a, b| compare_i64(a, b, |x, y| x < y),
+    gt -> |a, b| compare_i64(a, b, |x, y| x > y)
+}
+
+implement_compare! {
+    u8x16 => mask8x16, core::convert::identity,
+    eq -> _mm_cmpeq_epi8,
+    lt -> |a, b| not_si128(_mm_cmpeq_epi8(_mm_max_epu8(a, b), a)),
+    gt -> |a, b| not_si128(_mm_cmpeq_epi8(_mm_min_epu8(a, b), a))
+}
+
+implement_compare! {
+    u16x8 => mask16x8, core::convert::identity,
+    eq -> _mm_cmpeq_epi16,
+    lt -> |a, b| not_si128(_mm_cmpeq_epi16(_mm_max_epu16(a, b), a)),
+    gt -> |a, b| not_si128(_mm_cmpeq_epi16(_mm_min_epu16(a, b), a))
+}
+
+implement_compare! {
+    u32x4 => mask32x4, _mm_castsi128_ps,
+    eq -> _mm_cmpeq_epi32,
+    lt -> |a, b| not_si128(_mm_cmpeq_epi32(_mm_max_epu32(a, b), a)),
+    gt -> |a, b| not_si128(_mm_cmpeq_epi32(_mm_min_epu32(a, b), a))
+}
+
+implement_compare! {
+    u64x2 => mask64x2, _mm_castsi128_pd,
+    eq -> _mm_cmpeq_epi64,
+    lt -> |a, b| compare_u64(a, b, |x, y| x < y),
+    gt -> |a, b| compare_u64(a, b, |x, y| x > y)
+}
+
+#[inline]
+unsafe fn flip_sign_epi64(a: __m256i) -> __m256i {
+    _mm256_xor_si256(a, _mm256_set1_epi64x(i64::MIN))
+}
+
+implement_compare! {
+    i8x32 => mask8x32, core::convert::identity, not_si256,
+    eq -> _mm256_cmpeq_epi8,
+    lt -> |a, b| _mm256_cmpgt_epi8(b, a),
+    gt -> _mm256_cmpgt_epi8
+}
+
+implement_compare! {
+    i16x16 => mask16x16, core::convert::identity, not_si256,
+    eq -> _mm256_cmpeq_epi16,
+    lt -> |a, b| _mm256_cmpgt_epi16(b, a),
+    gt -> _mm256_cmpgt_epi16
+}
+
+implement_compare! {
+    i32x8 => mask32x8<Avx2>, _mm256_castsi256_ps, not_si256,
+    eq -> _mm256_cmpeq_epi32,
+    lt -> |a, b| _mm256_cmpgt_epi32(b, a),
+    gt -> _mm256_cmpgt_epi32
+}
+
+implement_compare! {
+    i64x4 => mask64x4<Avx2>, _mm256_castsi256_pd, not_si256,
+    eq -> _mm256_cmpeq_epi64,
+    lt -> |a, b| _mm256_cmpgt_epi64(b, a),
+    gt -> _mm256_cmpgt_epi64
+}
+
+implement_compare! {
+    u8x32 => mask8x32, core::convert::identity, not_si256,
+    eq -> _mm256_cmpeq_epi8,
+    lt -> |a, b| not_si256(_mm256_cmpeq_epi8(_mm256_max_epu8(a, b), a)),
+    gt -> |a, b| not_si256(_mm256_cmpeq_epi8(_mm256_min_epu8(a, b), a))
+}
+
+implement_compare! {
+    u16x16 => mask16x16, core::convert::identity, not_si256,
+    eq -> _mm256_cmpeq_epi16,
+    lt -> |a, b| not_si256(_mm256_cmpeq_epi16(_mm256_max_epu16(a, b), a)),
+    gt -> |a, b| not_si256(_mm256_cmpeq_epi16(_mm256_min_epu16(a, b), a))
+}
+
+implement_compare! {
+    u32x8 => mask32x8<Avx2>, _mm256_castsi256_ps, not_si256,
+    eq -> _mm256_cmpeq_epi32,
+    lt -> |a, b| not_si256(_mm256_cmpeq_epi32(_mm256_max_epu32(a, b), a)),
+    gt -> |a, b| not_si256(_mm256_cmpeq_epi32(_mm256_min_epu32(a, b), a))
+}
+
+implement_compare! {
+    u64x4 => mask64x4<Avx2>, _mm256_castsi256_pd, not_si256,
+    eq -> _mm256_cmpeq_epi64,
+    lt -> |a, b| _mm256_cmpgt_epi64(flip_sign_epi64(b), flip_sign_epi64(a)),
+    gt -> |a, b| _mm256_cmpgt_epi64(flip_sign_epi64(a), flip_sign_epi64(b))
+}
+
+// Mask lanes are all ones or all zeros, so a bytewise blend selects entire lanes.
+select_ops! {
+    for mask8x16 =>
+        i8x16: |mask, a, b| _mm_blendv_epi8(b, a, mask),
+        u8x16: |mask, a, b| _mm_blendv_epi8(b, a, mask)
+}
+
+select_ops! {
+    for mask16x8 =>
+        i16x8: |mask, a, b| _mm_blendv_epi8(b, a, mask),
+        u16x8: |mask, a, b| _mm_blendv_epi8(b, a, mask)
+}
+
+select_ops! {
+    for mask32x4 =>
+        i32x4: |mask, a, b| _mm_blendv_epi8(b, a, _mm_castps_si128(mask)),
+        u32x4: |mask, a, b| _mm_blendv_epi8(b, a, _mm_castps_si128(mask))
+}
+
+select_ops! {
+    for mask64x2 =>
+        i64x2: |mask, a, b| _mm_blendv_epi8(b, a, _mm_castpd_si128(mask)),
+        u64x2: |mask, a, b| _mm_blendv_epi8(b, a, _mm_castpd_si128(mask))
+}
+
+select_ops! {
+    for mask8x32 =>
+        i8x32: |mask, a, b| _mm256_blendv_epi8(b, a, mask),
+        u8x32: |mask, a, b| _mm256_blendv_epi8(b, a, mask)
+}
+
+select_ops! {
+    for mask16x16 =>
+        i16x16: |mask, a, b| _mm256_blendv_epi8(b, a, mask),
+        u16x16: |mask, a, b| _mm256_blendv_epi8(b, a, mask)
+}
+
+select_ops! {
+    for mask32x8<Avx2> =>
+        i32x8: |mask, a, b| _mm256_blendv_epi8(b, a, _mm256_castps_si256(mask)),
+        u32x8: |mask, a, b| _mm256_blendv_epi8(b, a, _mm256_castps_si256(mask))
+}
+
+select_ops! {
+    for mask64x4<Avx2> =>
+        i64x4: |mask, a, b| _mm256_blendv_epi8(b, a, _mm256_castpd_si256(mask)),
+        u64x4: |mask, a, b| _mm256_blendv_epi8(b, a, _mm256_castpd_si256(mask))
+}
+
+// The high lanes are moved down with byte shifts, so `bytes` is the size of a lane.
+#[inline]
+unsafe fn reduce_epi(
+    x: __m128i,
+    bytes: usize,
+    op: impl Fn(__m128i, __m128i) -> __m128i,
+) -> __m128i {
+    let mut x = op(x, _mm_srli_si128(x, 8));
+    if bytes <= 4 {
+        x = op(x, _mm_srli_si128(x, 4));
+    }
+    if bytes <= 2 {
+        x = op(x, _mm_srli_si128(x, 2));
+    }
+    if bytes <= 1 {
+        x = op(x, _mm_srli_si128(x, 1));
+    }
+    x
+}
+
+macro_rules! reduce_epi {
+    {
+        $scalar:ty, $op:path
+    } => {
+        |x| {
+            const BYTES: usize = core::mem::size_of::<$scalar>();
+            let x = reduce_epi(x, BYTES, |a, b| $op(a, b));
+            core::mem::transmute::<__m128i, [$scalar; 16 / BYTES]>(x)[0]
+        }
+    }
+}
+
+// 256-bit vectors are first reduced to 128 bits by combining the upper and lower halves.
+macro_rules! reduce_epi256 {
+    {
+        $scalar:ty, $op:path
+    } => {
+        |x| {
+            let x = $op(_mm256_castsi256_si128(x), _mm256_extracti128_si256(x, 1));
+            (reduce_epi!($scalar, $op))(x)
+        }
+    }
+}
+
+reduce_ops! {
+    for i8x16:
+        sum -> (reduce_epi!(i8, _mm_add_epi8)),
+        product -> [wrapping_mul]
+}
+
+reduce_ops! {
+    for i8x16:
+        min -> (reduce_epi!(i8, _mm_min_epi8)),
+        max -> (reduce_epi!(i8, _mm_max_epi8))
+}
+
+reduce_ops! {
+    for i16x8:
+        sum -> (reduce_epi!(i16, _mm_add_epi16)),
+        product -> (reduce_epi!(i16, _mm_mullo_epi16))
+}
+
+reduce_ops! {
+    for i16x8:
+        min -> (reduce_epi!(i16, _mm_min_epi16)),
+        max -> (reduce_epi!(i16, _mm_max_epi16))
+}
+
+reduce_ops! {
+    for i32x4:
+        sum -> (reduce_epi!(i32, _mm_add_epi32)),
+        product -> (reduce_epi!(i32, _mm_mullo_epi32))
+}
+
+reduce_ops! {
+    for i32x4:
+        min -> (reduce_epi!(i32, _mm_min_epi32)),
+        max -> (reduce_epi!(i32, _mm_max_epi32))
+}
+
+reduce_ops! {
+    for i64x2:
+        sum -> (reduce_epi!(i64, _mm_add_epi64)),
+        product -> [wrapping_mul]
+}
+
+reduce_ops! {
+    for i64x2:
+        min -> [min],
+        max -> [max]
+}
+
+reduce_ops! {
+    for u8x16:
+        sum -> (reduce_epi!(u8, _mm_add_epi8)),
+        product -> [wrapping_mul]
+}
+
+reduce_ops! {
+    for u8x16:
+        min -> (reduce_epi!(u8, _mm_min_epu8)),
+        max -> (reduce_epi!(u8, _mm_max_epu8))
+}
+
+reduce_ops! {
+    for u16x8:
+        sum -> (reduce_epi!(u16, _mm_add_epi16)),
+        product -> (reduce_epi!(u16, _mm_mullo_epi16))
+}
+
+reduce_ops! {
+    for u16x8:
+        min -> (reduce_epi!(u16, _mm_min_epu16)),
+        max -> (reduce_epi!(u16, _mm_max_epu16))
+}
+
+reduce_ops! {
+    for u32x4:
+        sum -> (reduce_epi!(u32, _mm_add_epi32)),
+        product -> (reduce_epi!(u32, _mm_mullo_epi32))
+}
+
+reduce_ops! {
+    for u32x4:
+        min -> (reduce_epi!(u32, _mm_min_epu32)),
+        max -> (reduce_epi!(u32, _mm_max_epu32))
+}
+
+reduce_ops! {
+    for u64x2:
+        sum -> (reduce_epi!(u64, _mm_add_epi64)),
+        product -> [wrapping_mul]
+}
+
+reduce_ops! {
+    for u64x2:
+        min -> [min],
+        max -> [max]
+}
+
+reduce_ops! {
+    for i8x32:
+        sum -> (reduce_epi256!(i8, _mm_add_epi8)),
+        product -> [wrapping_mul]
+}
+
+reduce_ops! {
+    for i8x32:
+        min -> (reduce_epi256!(i8, _mm_min_epi8)),
+        max -> (reduce_epi256!(i8, _mm_max_epi8))
+}
+
+reduce_ops! {
+    for i16x16:
+        sum -> (reduce_epi256!(i16, _mm_add_epi16)),
+        product -> (reduce_epi256!(i16, _mm_mullo_epi16))
+}
+
+reduce_ops! {
+    for i16x16:
+        min -> (reduce_epi256!(i16, _mm_min_epi16)),
+        max -> (reduce_epi256!(i16, _mm_max_epi16))
+}
+
+reduce_ops! {
+    for i32x8:
+        sum -> (reduce_epi256!(i32, _mm_add_epi32)),
+        product -> (reduce_epi256!(i32, _mm_mullo_epi32))
+}
+
+reduce_ops! {
+    for i32x8:
+        min -> (reduce_epi256!(i32, _mm_min_epi32)),
+        max -> (reduce_epi256!(i32, _mm_max_epi32))
+}
+
+reduce_ops! {
+    for i64x4:
+        sum -> (reduce_epi256!(i64, _mm_add_epi64)),
+        product -> [wrapping_mul]
+}
+
+reduce_ops! {
+    for i64x4:
+        min -> [min],
+        max -> [max]
+}
+
+reduce_ops! {
+    for u8x32:
+        sum -> (reduce_epi256!(u8, _mm_add_epi8)),
+        product -> [wrapping_mul]
+}
+
+reduce_ops! {
+    for u8x32:
+        min -> (reduce_epi256!(u8, _mm_min_epu8)),
+        max -> (reduce_epi256!(u8, _mm_max_epu8))
+}
+
+reduce_ops! {
+    for u16x16:
+        sum -> (reduce_epi256!(u16, _mm_add_epi16)),
+        product -> (reduce_epi256!(u16, _mm_mullo_epi16))
+}
+
+reduce_ops! {
+    for u16x16:
+        min -> (reduce_epi256!(u16, _mm_min_epu16)),
+        max -> (reduce_epi256!(u16, _mm_max_epu16))
+}
+
+reduce_ops! {
+    for u32x8:
+        sum -> (reduce_epi256!(u32, _mm_add_epi32)),
+        product -> (reduce_epi256!(u32, _mm_mullo_epi32))
+}
+
+reduce_ops! {
+    for u32x8:
+        min -> (reduce_epi256!(u32, _mm_min_epu32)),
+        max -> (reduce_epi256!(u32, _mm_max_epu32))
+}
+
+reduce_ops! {
+    for u64x4:
+        sum -> (reduce_epi256!(u64, _mm_add_epi64)),
+        product -> [wrapping_mul]
+}
+
+reduce_ops! {
+    for u64x4:
+        min -> [min],
+        max -> [max]
+}
@@ -0,0 +1,272 @@
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+use crate::{
+    arch::{
+        x86::{Avx, Avx2, Sse},
+        Token,
+    },
+    vector::{width, Mask},
+};
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for super::Avx {}
+    impl Sealed for super::Avx2 {}
+}
+
+/// A token for the 256-bit masks, which are shared by the AVX float vectors and the AVX2 integer
+/// vectors.
+///
+/// This trait is sealed and implemented only for [`Avx`](struct.Avx.html) and
+/// [`Avx2`](struct.Avx2.html).
+pub trait AvxToken: Token + private::Sealed {
+    #[doc(hidden)]
+    type Ps: Copy + core::fmt::Debug;
+
+    #[doc(hidden)]
+    type Pd: Copy + core::fmt::Debug;
+}
+
+impl AvxToken for Avx {
+    type Ps = __m256;
+    type Pd = __m256d;
+}
+
+impl AvxToken for Avx2 {
+    type Ps = __m256;
+    type Pd = __m256d;
+}
+
+/// An SSE mask of 16 8-bit lanes.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct mask8x16(pub(super) __m128i);
+
+/// An SSE mask of 8 16-bit lanes.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct mask16x8(pub(super) __m128i);
+
+/// An SSE mask of 4 32-bit lanes.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct mask32x4(pub(super) __m128);
+
+/// An SSE mask of 2 64-bit lanes.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct mask64x2(pub(super) __m128d);
+
+/// An AVX mask of 8 32-bit lanes.
+///
+/// `mask32x8<Avx2>` is the mask of the AVX2 integer vectors.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct mask32x8<T: AvxToken = Avx>(pub(super) T::Ps);
+
+/// An AVX mask of 4 64-bit lanes.
+///
+/// `mask64x4<Avx2>` is the mask of the AVX2 integer vectors.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct mask64x4<T: AvxToken = Avx>(pub(super) T::Pd);
+
+/// An AVX2 mask of 32 8-bit lanes.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct mask8x32(pub(super) __m256i);
+
+/// An AVX2 mask of 16 16-bit lanes.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+#[allow(non_camel_case_types)]
+pub struct mask16x16(pub(super) __m256i);
+
+#[inline]
+pub(super) unsafe fn not_si128(a: __m128i) -> __m128i {
+    _mm_xor_si128(a, _mm_set1_epi32(-1))
+}
+
+#[inline]
+pub(super) unsafe fn not_si256(a: __m256i) -> __m256i {
+    _mm256_xor_si256(a, _mm256_set1_epi32(-1))
+}
+
+#[inline]
+pub(super) unsafe fn not_ps(a: __m128) -> __m128 {
+    _mm_xor_ps(a, _mm_castsi128_ps(_mm_set1_epi32(-1)))
+}
+
+#[inline]
+pub(super) unsafe fn not_pd(a: __m128d) -> __m128d {
+    _mm_xor_pd(a, _mm_castsi128_pd(_mm_set1_epi32(-1)))
+}
+
+#[inline]
+pub(super) unsafe fn not_ps256(a: __m256) -> __m256 {
+    _mm256_xor_ps(a, _mm256_castsi256_ps(_mm256_set1_epi32(-1)))
+}
+
+#[inline]
+pub(super) unsafe fn not_pd256(a: __m256d) -> __m256d {
+    _mm256_xor_pd(a, _mm256_castsi256_pd(_mm256_set1_epi32(-1)))
+}
+
+// Each lane of a mask is either all ones or all zeros.  `movemask` extracts `bits` bits per lane.
+macro_rules! implement {
+    {
+        $mask:ty, $token:ty, $width:ty, $bits:literal,
+        splat -> |$value:ident| $splat:expr,
+        movemask -> $movemask:path,
+        and -> $and:path,
+        or -> $or:path,
+        xor -> $xor:path,
+        not -> $not:path
+    } => {
+        bitwise_ops! {
+            for $mask:
+                and -> ($and),
+                or -> ($or),
+                xor -> ($xor),
+                not -> ($not)
+        }
+
+        impl Mask for $mask {
+            type Token = $token;
+            type Width = $width;
+
+            #[inline]
+            fn splat(_: Self::Token, $value: bool) -> Self {
+                Self(unsafe { $splat })
+            }
+
+            #[inline]
+            fn test(&self, lane: usize) -> bool {
+                assert!(lane < Self::width(), "lane index out of range");
+                (unsafe { $movemask(self.0) } >> (lane * $bits)) & 1 != 0
+            }
+
+            #[inline]
+            fn any(&self) -> bool {
+                unsafe { $movemask(self.0) != 0 }
+            }
+
+            #[inline]
+            fn all(&self) -> bool {
+                let movemask = unsafe { $movemask(self.0) } as u32;
+                movemask == !0u32 >> (32 - Self::width() * $bits)
+            }
+        }
+    }
+}
+
+implement! {
+    mask8x16, Sse, width::W16, 1,
+    splat -> |value| _mm_set1_epi8(-(value as i8)),
+    movemask -> _mm_movemask_epi8,
+    and -> _mm_and_si128,
+    or -> _mm_or_si128,
+    xor -> _mm_xor_si128,
+    not -> not_si128
+}
+
+implement! {
+    mask16x8, Sse, width::W8, 2,
+    splat -> |value| _mm_set1_epi16(-(value as i16)),
+    movemask -> _mm_movemask_epi8,
+    and -> _mm_and_si128,
+    or -> _mm_or_si128,
+    xor -> _mm_xor_si128,
+    not -> not_si128
+}
+
+implement! {
+    mask32x4, Sse, width::W4, 1,
+    splat -> |value| _mm_castsi128_ps(_mm_set1_epi32(-(value as i32))),
+    movemask -> _mm_movemask_ps,
+    and -> _mm_and_ps,
+    or -> _mm_or_ps,
+    xor -> _mm_xor_ps,
+    not -> not_ps
+}
+
+implement! {
+    mask64x2, Sse, width::W2, 1,
+    splat -> |value| _mm_castsi128_pd(_mm_set1_epi64x(-(value as i64))),
+    movemask -> _mm_movemask_pd,
+    and -> _mm_and_pd,
+    or -> _mm_or_pd,
+    xor -> _mm_xor_pd,
+    not -> not_pd
+}
+
+implement! {
+    mask32x8, Avx, width::W8, 1,
+    splat -> |value| _mm256_castsi256_ps(_mm256_set1_epi32(-(value as i32))),
+    movemask -> _mm256_movemask_ps,
+    and -> _mm256_and_ps,
+    or -> _mm256_or_ps,
+    xor -> _mm256_xor_ps,
+    not -> not_ps256
+}
+
+implement! {
+    mask64x4, Avx, width::W4, 1,
+    splat -> |value| _mm256_castsi256_pd(_mm256_set1_epi64x(-(value as i64))),
+    movemask -> _mm256_movemask_pd,
+    and -> _mm256_and_pd,
+    or -> _mm256_or_pd,
+    xor -> _mm256_xor_pd,
+    not -> not_pd256
+}
+
+implement! {
+    mask8x32, Avx2, width::W32, 1,
+    splat -> |value| _mm256_set1_epi8(-(value as i8)),
+    movemask -> _mm256_movemask_epi8,
+    and -> _mm256_and_si256,
+    or -> _mm256_or_si256,
+    xor -> _mm256_xor_si256,
+    not -> not_si256
+}
+
+implement! {
+    mask16x16, Avx2, width::W16, 2,
+    splat -> |value| _mm256_set1_epi16(-(value as i16)),
+    movemask -> _mm256_movemask_epi8,
+    and -> _mm256_and_si256,
+    or -> _mm256_or_si256,
+    xor -> _mm256_xor_si256,
+    not -> not_si256
+}
+
+implement! {
+    mask32x8<Avx2>, Avx2, width::W8, 1,
+    splat -> |value| _mm256_castsi256_ps(_mm256_set1_epi32(-(value as i32))),
+    movemask -> _mm256_movemask_ps,
+    and -> _mm256_and_ps,
+    or -> _mm256_or_ps,
+    xor -> _mm256_xor_ps,
+    not -> not_ps256
+}
+
+implement! {
+    mask64x4<Avx2>, Avx2, width::W4, 1,
+    splat -> |value| _mm256_castsi256_pd(_mm256_set1_epi64x(-(value as i64))),
+    movemask -> _mm256_movemask_pd,
+    and -> _mm256_and_pd,
+    or -> _mm256_or_pd,
+    xor -> _mm256_xor_pd,
+    not -> not_pd256
+}
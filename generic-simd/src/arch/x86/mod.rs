@@ -5,6 +5,17 @@ mod complex;
 #[cfg(feature = "complex")]
 pub use complex::*;
 
+mod integer;
+pub use integer::*;
+
+mod mask;
+pub use mask::*;
+
+#[cfg(feature = "nightly")]
+mod avx512;
+#[cfg(feature = "nightly")]
+pub use avx512::*;
+
 use crate::{
     arch::{generic, Token},
     scalar::Scalar,
@@ -25,8 +36,22 @@ pub struct Sse(());
 #[derive(Copy, Clone, Debug)]
 pub struct Avx(());
 
+/// AVX2 and FMA instruction set token.
+#[derive(Copy, Clone, Debug)]
+pub struct Avx2(());
+
+/// AVX-512F instruction set token.
+///
+/// Requires feature `"nightly"`.
+#[cfg(feature = "nightly")]
+#[derive(Copy, Clone, Debug)]
+pub struct Avx512(());
+
 impl_token! { Sse => "sse4.1" }
 impl_token! { Avx => "avx" }
+impl_token! { Avx2 => "avx2", "fma" }
+#[cfg(feature = "nightly")]
+impl_token! { Avx512 => "avx512f" }
 
 impl core::convert::From<Avx> for Sse {
     #[inline]
@@ -35,6 +60,44 @@ impl core::convert::From<Avx> for Sse {
     }
 }
 
+impl core::convert::From<Avx2> for Sse {
+    #[inline]
+    fn from(_: Avx2) -> Sse {
+        unsafe { Sse::new_unchecked() }
+    }
+}
+
+impl core::convert::From<Avx2> for Avx {
+    #[inline]
+    fn from(_: Avx2) -> Avx {
+        unsafe { Avx::new_unchecked() }
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl core::convert::From<Avx512> for Sse {
+    #[inline]
+    fn from(_: Avx512) -> Sse {
+        unsafe { Sse::new_unchecked() }
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl core::convert::From<Avx512> for Avx {
+    #[inline]
+    fn from(_: Avx512) -> Avx {
+        unsafe { Avx::new_unchecked() }
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl core::convert::From<Avx512> for Avx2 {
+    #[inline]
+    fn from(_: Avx512) -> Avx2 {
+        unsafe { Avx2::new_unchecked() }
+    }
+}
+
 impl Native<Sse> for f32 {
     type Width = width::W4;
 }
@@ -51,6 +114,14 @@ impl Native<Avx> for f64 {
     type Width = width::W4;
 }
 
+impl Native<Avx2> for f32 {
+    type Width = width::W8;
+}
+
+impl Native<Avx2> for f64 {
+    type Width = width::W4;
+}
+
 /// An SSE vector of `f32`s.
 #[derive(Clone, Copy, Debug)]
 #[repr(transparent)]
@@ -139,6 +210,42 @@ impl Scalar<Avx, width::W8> for f64 {
     type Vector = Shim2<f64x4, f64>;
 }
 
+impl Scalar<Avx2, width::W1> for f32 {
+    type Vector = ShimToken<generic::f32x1, Self, Avx2>;
+}
+
+impl Scalar<Avx2, width::W2> for f32 {
+    type Vector = ShimToken<Shim2<generic::f32x1, Self>, Self, Avx2>;
+}
+
+impl Scalar<Avx2, width::W4> for f32 {
+    type Vector = ShimToken<f32x4, Self, Avx2>;
+}
+
+impl Scalar<Avx2, width::W8> for f32 {
+    type Vector = ShimToken<f32x8, Self, Avx2>;
+}
+
+impl Scalar<Avx2, width::W1> for f64 {
+    type Vector = ShimToken<generic::f64x1, Self, Avx2>;
+}
+
+impl Scalar<Avx2, width::W2> for f64 {
+    type Vector = ShimToken<f64x2, Self, Avx2>;
+}
+
+impl Scalar<Avx2, width::W4> for f64 {
+    type Vector = ShimToken<f64x4, Self, Avx2>;
+}
+
+impl Scalar<Avx2, width::W8> for f64 {
+    type Vector = ShimToken<Shim2<f64x4, f64>, Self, Avx2>;
+}
+
+shim_widths! { Sse, [f32, f64], [W8 -> W16, W16 -> W32, W32 -> W64] }
+shim_widths! { Avx, [f32, f64], [W8 -> W16, W16 -> W32, W32 -> W64] }
+shim_widths! { Avx2, [f32, f64], [W8 -> W16, W16 -> W32, W32 -> W64] }
+
 arithmetic_ops! {
     feature: Sse::new_unchecked(),
     for f32x4:
@@ -175,6 +282,195 @@ arithmetic_ops! {
         div -> (_mm256_div_pd)
 }
 
+compare_ops! {
+    for f32x4 => mask32x4:
+        eq -> _mm_cmpeq_ps,
+        ne -> _mm_cmpneq_ps,
+        lt -> _mm_cmplt_ps,
+        le -> _mm_cmple_ps,
+        gt -> _mm_cmpgt_ps,
+        ge -> _mm_cmpge_ps
+}
+
+compare_ops! {
+    for f64x2 => mask64x2:
+        eq -> _mm_cmpeq_pd,
+        ne -> _mm_cmpneq_pd,
+        lt -> _mm_cmplt_pd,
+        le -> _mm_cmple_pd,
+        gt -> _mm_cmpgt_pd,
+        ge -> _mm_cmpge_pd
+}
+
+compare_ops! {
+    for f32x8 => mask32x8:
+        eq -> |a, b| _mm256_cmp_ps(a, b, _CMP_EQ_OQ),
+        ne -> |a, b| _mm256_cmp_ps(a, b, _CMP_NEQ_UQ),
+        lt -> |a, b| _mm256_cmp_ps(a, b, _CMP_LT_OQ),
+        le -> |a, b| _mm256_cmp_ps(a, b, _CMP_LE_OQ),
+        gt -> |a, b| _mm256_cmp_ps(a, b, _CMP_GT_OQ),
+        ge -> |a, b| _mm256_cmp_ps(a, b, _CMP_GE_OQ)
+}
+
+compare_ops! {
+    for f64x4 => mask64x4:
+        eq -> |a, b| _mm256_cmp_pd(a, b, _CMP_EQ_OQ),
+        ne -> |a, b| _mm256_cmp_pd(a, b, _CMP_NEQ_UQ),
+        lt -> |a, b| _mm256_cmp_pd(a, b, _CMP_LT_OQ),
+        le -> |a, b| _mm256_cmp_pd(a, b, _CMP_LE_OQ),
+        gt -> |a, b| _mm256_cmp_pd(a, b, _CMP_GT_OQ),
+        ge -> |a, b| _mm256_cmp_pd(a, b, _CMP_GE_OQ)
+}
+
+select_ops! {
+    for mask32x4 => f32x4: |mask, a, b| _mm_blendv_ps(b, a, mask)
+}
+
+select_ops! {
+    for mask64x2 => f64x2: |mask, a, b| _mm_blendv_pd(b, a, mask)
+}
+
+select_ops! {
+    for mask32x8 => f32x8: |mask, a, b| _mm256_blendv_ps(b, a, mask)
+}
+
+select_ops! {
+    for mask64x4 => f64x4: |mask, a, b| _mm256_blendv_pd(b, a, mask)
+}
+
+// The high lanes are moved down with `movehl` and `shuffle` and combined with the low lanes.
+#[inline]
+unsafe fn reduce_ps(x: __m128, op: impl Fn(__m128, __m128) -> __m128 + Copy) -> f32 {
+    let x = op(x, _mm_movehl_ps(x, x));
+    let x = op(x, _mm_shuffle_ps(x, x, 1));
+    _mm_cvtss_f32(x)
+}
+
+#[inline]
+unsafe fn reduce_pd(x: __m128d, op: impl Fn(__m128d, __m128d) -> __m128d + Copy) -> f64 {
+    _mm_cvtsd_f64(op(x, _mm_unpackhi_pd(x, x)))
+}
+
+#[inline]
+unsafe fn reduce_ps256(x: __m256, op: impl Fn(__m128, __m128) -> __m128 + Copy) -> f32 {
+    reduce_ps(
+        op(_mm256_castps256_ps128(x), _mm256_extractf128_ps(x, 1)),
+        op,
+    )
+}
+
+#[inline]
+unsafe fn reduce_pd256(x: __m256d, op: impl Fn(__m128d, __m128d) -> __m128d + Copy) -> f64 {
+    reduce_pd(
+        op(_mm256_castpd256_pd128(x), _mm256_extractf128_pd(x, 1)),
+        op,
+    )
+}
+
+reduce_ops! {
+    for f32x4:
+        sum -> (|x| reduce_ps(x, |a, b| _mm_add_ps(a, b))),
+        product -> (|x| reduce_ps(x, |a, b| _mm_mul_ps(a, b)))
+}
+
+reduce_ops! {
+    for f32x4:
+        min -> (|x| reduce_ps(x, |a, b| _mm_min_ps(a, b))),
+        max -> (|x| reduce_ps(x, |a, b| _mm_max_ps(a, b)))
+}
+
+reduce_ops! {
+    for f64x2:
+        sum -> (|x| reduce_pd(x, |a, b| _mm_add_pd(a, b))),
+        product -> (|x| reduce_pd(x, |a, b| _mm_mul_pd(a, b)))
+}
+
+reduce_ops! {
+    for f64x2:
+        min -> (|x| reduce_pd(x, |a, b| _mm_min_pd(a, b))),
+        max -> (|x| reduce_pd(x, |a, b| _mm_max_pd(a, b)))
+}
+
+reduce_ops! {
+    for f32x8:
+        sum -> (|x| reduce_ps256(x, |a, b| _mm_add_ps(a, b))),
+        product -> (|x| reduce_ps256(x, |a, b| _mm_mul_ps(a, b)))
+}
+
+reduce_ops! {
+    for f32x8:
+        min -> (|x| reduce_ps256(x, |a, b| _mm_min_ps(a, b))),
+        max -> (|x| reduce_ps256(x, |a, b| _mm_max_ps(a, b)))
+}
+
+reduce_ops! {
+    for f64x4:
+        sum -> (|x| reduce_pd256(x, |a, b| _mm_add_pd(a, b))),
+        product -> (|x| reduce_pd256(x, |a, b| _mm_mul_pd(a, b)))
+}
+
+reduce_ops! {
+    for f64x4:
+        min -> (|x| reduce_pd256(x, |a, b| _mm_min_pd(a, b))),
+        max -> (|x| reduce_pd256(x, |a, b| _mm_max_pd(a, b)))
+}
+
+// Multiply-add is only fused when FMA is available.
+mul_add_ops! { for f32x4: Sse, Avx }
+mul_add_ops! { for f64x2: Sse, Avx }
+mul_add_ops! { for f32x8: Avx }
+mul_add_ops! { for f64x4: Avx }
+
+mul_add_ops! {
+    for f32x4 => Avx2:
+        mul_add -> _mm_fmadd_ps,
+        mul_sub -> _mm_fmsub_ps
+}
+
+mul_add_ops! {
+    for f64x2 => Avx2:
+        mul_add -> _mm_fmadd_pd,
+        mul_sub -> _mm_fmsub_pd
+}
+
+mul_add_ops! {
+    for f32x8 => Avx2:
+        mul_add -> _mm256_fmadd_ps,
+        mul_sub -> _mm256_fmsub_ps
+}
+
+mul_add_ops! {
+    for f64x4 => Avx2:
+        mul_add -> _mm256_fmadd_pd,
+        mul_sub -> _mm256_fmsub_pd
+}
+
+// Hardware gathers are only available with AVX2.
+gather_ops! { for f32x4: Sse, Avx }
+gather_ops! { for f64x2: Sse, Avx }
+gather_ops! { for f32x8: Avx }
+gather_ops! { for f64x4: Avx }
+
+gather_ops! {
+    for f32x4 => Avx2:
+        gather -> |from, offsets| _mm_i32gather_ps(from, _mm_loadu_si128(offsets as *const _), 4)
+}
+
+gather_ops! {
+    for f64x2 => Avx2:
+        gather -> |from, offsets| _mm_i32gather_pd(from, _mm_loadu_si128(offsets as *const _), 8)
+}
+
+gather_ops! {
+    for f32x8 => Avx2:
+        gather -> |from, offsets| _mm256_i32gather_ps(from, _mm256_loadu_si256(offsets as *const _), 4)
+}
+
+gather_ops! {
+    for f64x4 => Avx2:
+        gather -> |from, offsets| _mm256_i32gather_pd(from, _mm_loadu_si128(offsets as *const _), 8)
+}
+
 impl core::ops::Neg for f32x4 {
     type Output = Self;
 
@@ -211,11 +507,558 @@ impl core::ops::Neg for f64x4 {
     }
 }
 
+bitwise_ops! {
+    for f32x4:
+        and -> (_mm_and_ps),
+        or -> (_mm_or_ps),
+        xor -> (_mm_xor_ps),
+        not -> (not_ps),
+        and_not -> (|a, b| _mm_andnot_ps(b, a))
+}
+
+bitwise_ops! {
+    for f64x2:
+        and -> (_mm_and_pd),
+        or -> (_mm_or_pd),
+        xor -> (_mm_xor_pd),
+        not -> (not_pd),
+        and_not -> (|a, b| _mm_andnot_pd(b, a))
+}
+
+bitwise_ops! {
+    for f32x8:
+        and -> (_mm256_and_ps),
+        or -> (_mm256_or_ps),
+        xor -> (_mm256_xor_ps),
+        not -> (not_ps256),
+        and_not -> (|a, b| _mm256_andnot_ps(b, a))
+}
+
+bitwise_ops! {
+    for f64x4:
+        and -> (_mm256_and_pd),
+        or -> (_mm256_or_pd),
+        xor -> (_mm256_xor_pd),
+        not -> (not_pd256),
+        and_not -> (|a, b| _mm256_andnot_pd(b, a))
+}
+
+// The min and max instructions return the second operand when either operand is NaN, so NaNs in
+// the first operand are propagated separately.
+float_ops! {
+    for f32x4:
+        min -> (|a, b| _mm_blendv_ps(_mm_min_ps(a, b), a, _mm_cmpunord_ps(a, a))),
+        max -> (|a, b| _mm_blendv_ps(_mm_max_ps(a, b), a, _mm_cmpunord_ps(a, a))),
+        abs -> (|a| _mm_andnot_ps(_mm_set1_ps(-0.), a)),
+        copysign -> (|a, b| {
+            let sign = _mm_set1_ps(-0.);
+            _mm_or_ps(_mm_andnot_ps(sign, a), _mm_and_ps(sign, b))
+        })
+}
+
+float_ops! {
+    for f64x2:
+        min -> (|a, b| _mm_blendv_pd(_mm_min_pd(a, b), a, _mm_cmpunord_pd(a, a))),
+        max -> (|a, b| _mm_blendv_pd(_mm_max_pd(a, b), a, _mm_cmpunord_pd(a, a))),
+        abs -> (|a| _mm_andnot_pd(_mm_set1_pd(-0.), a)),
+        copysign -> (|a, b| {
+            let sign = _mm_set1_pd(-0.);
+            _mm_or_pd(_mm_andnot_pd(sign, a), _mm_and_pd(sign, b))
+        })
+}
+
+float_ops! {
+    for f32x8:
+        min -> (|a, b| _mm256_blendv_ps(_mm256_min_ps(a, b), a, _mm256_cmp_ps(a, a, _CMP_UNORD_Q))),
+        max -> (|a, b| _mm256_blendv_ps(_mm256_max_ps(a, b), a, _mm256_cmp_ps(a, a, _CMP_UNORD_Q))),
+        abs -> (|a| _mm256_andnot_ps(_mm256_set1_ps(-0.), a)),
+        copysign -> (|a, b| {
+            let sign = _mm256_set1_ps(-0.);
+            _mm256_or_ps(_mm256_andnot_ps(sign, a), _mm256_and_ps(sign, b))
+        })
+}
+
+float_ops! {
+    for f64x4:
+        min -> (|a, b| _mm256_blendv_pd(_mm256_min_pd(a, b), a, _mm256_cmp_pd(a, a, _CMP_UNORD_Q))),
+        max -> (|a, b| _mm256_blendv_pd(_mm256_max_pd(a, b), a, _mm256_cmp_pd(a, a, _CMP_UNORD_Q))),
+        abs -> (|a| _mm256_andnot_pd(_mm256_set1_pd(-0.), a)),
+        copysign -> (|a, b| {
+            let sign = _mm256_set1_pd(-0.);
+            _mm256_or_pd(_mm256_andnot_pd(sign, a), _mm256_and_pd(sign, b))
+        })
+}
+
+// Estimate instructions are only available for `f32`, with a relative error of at most
+// 1.5 * 2^-12.
+sqrt_ops! {
+    for f32x4:
+        sqrt -> (_mm_sqrt_ps),
+        recip -> (|a| _mm_div_ps(_mm_set1_ps(1.), a)),
+        rsqrt -> (|a| _mm_div_ps(_mm_set1_ps(1.), _mm_sqrt_ps(a))),
+        recip_estimate -> (_mm_rcp_ps),
+        rsqrt_estimate -> (_mm_rsqrt_ps)
+}
+
+sqrt_ops! {
+    for f64x2:
+        sqrt -> (_mm_sqrt_pd),
+        recip -> (|a| _mm_div_pd(_mm_set1_pd(1.), a)),
+        rsqrt -> (|a| _mm_div_pd(_mm_set1_pd(1.), _mm_sqrt_pd(a)))
+}
+
+sqrt_ops! {
+    for f32x8:
+        sqrt -> (_mm256_sqrt_ps),
+        recip -> (|a| _mm256_div_ps(_mm256_set1_ps(1.), a)),
+        rsqrt -> (|a| _mm256_div_ps(_mm256_set1_ps(1.), _mm256_sqrt_ps(a))),
+        recip_estimate -> (_mm256_rcp_ps),
+        rsqrt_estimate -> (_mm256_rsqrt_ps)
+}
+
+sqrt_ops! {
+    for f64x4:
+        sqrt -> (_mm256_sqrt_pd),
+        recip -> (|a| _mm256_div_pd(_mm256_set1_pd(1.), a)),
+        rsqrt -> (|a| _mm256_div_pd(_mm256_set1_pd(1.), _mm256_sqrt_pd(a)))
+}
+
+round_ops! {
+    for f32x4:
+        floor -> (_mm_floor_ps),
+        ceil -> (_mm_ceil_ps),
+        round_ties_even -> (|a| _mm_round_ps(a, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC)),
+        trunc -> (|a| _mm_round_ps(a, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC))
+}
+
+round_ops! {
+    for f64x2:
+        floor -> (_mm_floor_pd),
+        ceil -> (_mm_ceil_pd),
+        round_ties_even -> (|a| _mm_round_pd(a, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC)),
+        trunc -> (|a| _mm_round_pd(a, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC))
+}
+
+round_ops! {
+    for f32x8:
+        floor -> (_mm256_floor_ps),
+        ceil -> (_mm256_ceil_ps),
+        round_ties_even -> (|a| _mm256_round_ps(a, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC)),
+        trunc -> (|a| _mm256_round_ps(a, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC))
+}
+
+round_ops! {
+    for f64x4:
+        floor -> (_mm256_floor_pd),
+        ceil -> (_mm256_ceil_pd),
+        round_ties_even -> (|a| _mm256_round_pd(a, _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC)),
+        trunc -> (|a| _mm256_round_pd(a, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC))
+}
+
+// Conversions that change the lane size produce vectors of a different register width, so halves
+// of 256-bit registers are used where necessary.
+#[inline]
+unsafe fn split_si256(x: __m256i) -> [__m128i; 2] {
+    [_mm256_castsi256_si128(x), _mm256_extractf128_si256(x, 1)]
+}
+
+convert_ops! {
+    for f32x4 => f64 as Shim2<f64x2, f64>:
+        convert -> (|x| [_mm_cvtps_pd(x), _mm_cvtps_pd(_mm_movehl_ps(x, x))])
+}
+
+// The narrowed vector holds only two lanes, which are not stored in an SSE register.
+convert_ops! {
+    for f64x2 => f32 as <f32 as Scalar<Sse, width::W2>>::Vector:
+        convert -> [|x| x as f32]
+}
+
+convert_ops! {
+    for f32x4 => i32 as i32x4:
+        convert -> (_mm_cvttps_epi32),
+        round -> (_mm_cvtps_epi32)
+}
+
+convert_ops! {
+    for i32x4 => f32 as f32x4:
+        convert -> (_mm_cvtepi32_ps)
+}
+
+convert_ops! {
+    for f32x8 => f64 as Shim2<f64x4, f64>:
+        convert -> (|x| [
+            _mm256_cvtps_pd(_mm256_castps256_ps128(x)),
+            _mm256_cvtps_pd(_mm256_extractf128_ps(x, 1)),
+        ])
+}
+
+convert_ops! {
+    for f64x4 => f32 as ShimToken<f32x4, f32, Avx>:
+        convert -> (_mm256_cvtpd_ps)
+}
+
+convert_ops! {
+    for f32x8 => i32 as ShimToken<Shim2<i32x4, i32>, i32, Avx>:
+        convert -> (|x| split_si256(_mm256_cvttps_epi32(x))),
+        round -> (|x| split_si256(_mm256_cvtps_epi32(x)))
+}
+
+convert_ops! {
+    for i32x8 => f32 as ShimToken<f32x8, f32, Avx2>:
+        convert -> (_mm256_cvtepi32_ps)
+}
+
+bits_ops! {
+    for f32x4 => u32x4:
+        to_bits -> (_mm_castps_si128),
+        from_bits -> (_mm_castsi128_ps)
+}
+
+bits_ops! {
+    for f64x2 => u64x2:
+        to_bits -> (_mm_castpd_si128),
+        from_bits -> (_mm_castsi128_pd)
+}
+
+bits_ops! {
+    for f32x8 => ShimToken<Shim2<u32x4, u32>, u32, Avx>:
+        to_bits -> (|x| split_si256(_mm256_castps_si256(x))),
+        from_bits -> (|[low, high]: [__m128i; 2]| _mm256_castsi256_ps(_mm256_set_m128i(high, low)))
+}
+
+bits_ops! {
+    for f64x4 => ShimToken<Shim2<u64x2, u64>, u64, Avx>:
+        to_bits -> (|x| split_si256(_mm256_castpd_si256(x))),
+        from_bits -> (|[low, high]: [__m128i; 2]| _mm256_castsi256_pd(_mm256_set_m128i(high, low)))
+}
+
 as_slice! { f32x4 }
 as_slice! { f32x8 }
 as_slice! { f64x2 }
 as_slice! { f64x4 }
 
+// Interleaved data is loaded and stored in 128-bit chunks.  Shuffle selectors list the source
+// lanes from highest to lowest.  The halves of 256-bit vectors hold consecutive halves of the
+// interleaved data, so the same in-lane shuffles apply to both widths.
+macro_rules! interleave_ps {
+    { $load:ident, $store:ident, $shuffle:ident, $unpacklo:ident, $unpackhi:ident } => {
+        #[inline]
+        unsafe fn deinterleave2_unchecked(
+            _: Self::Token,
+            from: &[Self::Scalar],
+        ) -> (Self, Self) {
+            let from = from.as_ptr();
+            let (v0, v1) = ($load(from, 2, 0), $load(from, 2, 1));
+            (
+                Self($shuffle(v0, v1, 0b10_00_10_00)),
+                Self($shuffle(v0, v1, 0b11_01_11_01)),
+            )
+        }
+
+        #[inline]
+        unsafe fn deinterleave3_unchecked(
+            _: Self::Token,
+            from: &[Self::Scalar],
+        ) -> (Self, Self, Self) {
+            let from = from.as_ptr();
+            let (v0, v1, v2) = ($load(from, 3, 0), $load(from, 3, 1), $load(from, 3, 2));
+            let a01 = $shuffle(v0, v0, 0b11_11_00_00);
+            let a23 = $shuffle(v1, v2, 0b01_01_10_10);
+            let b01 = $shuffle(v0, v1, 0b00_00_01_01);
+            let b23 = $shuffle(v1, v2, 0b10_10_11_11);
+            let c01 = $shuffle(v0, v1, 0b01_01_10_10);
+            let c23 = $shuffle(v2, v2, 0b11_11_00_00);
+            (
+                Self($shuffle(a01, a23, 0b10_00_10_00)),
+                Self($shuffle(b01, b23, 0b10_00_10_00)),
+                Self($shuffle(c01, c23, 0b10_00_10_00)),
+            )
+        }
+
+        #[inline]
+        unsafe fn deinterleave4_unchecked(
+            _: Self::Token,
+            from: &[Self::Scalar],
+        ) -> (Self, Self, Self, Self) {
+            let from = from.as_ptr();
+            let (a, b, c, d) = transpose_ps!(
+                $shuffle,
+                $unpacklo,
+                $unpackhi,
+                $load(from, 4, 0),
+                $load(from, 4, 1),
+                $load(from, 4, 2),
+                $load(from, 4, 3)
+            );
+            (Self(a), Self(b), Self(c), Self(d))
+        }
+
+        #[inline]
+        unsafe fn interleave2_unchecked(self, b: Self, to: &mut [Self::Scalar]) {
+            let to = to.as_mut_ptr();
+            $store(to, 2, 0, $unpacklo(self.0, b.0));
+            $store(to, 2, 1, $unpackhi(self.0, b.0));
+        }
+
+        #[inline]
+        unsafe fn interleave3_unchecked(self, b: Self, c: Self, to: &mut [Self::Scalar]) {
+            let to = to.as_mut_ptr();
+            let (a, b, c) = (self.0, b.0, c.0);
+            let ab0 = $shuffle(a, b, 0b00_00_00_00);
+            let ca0 = $shuffle(c, a, 0b01_01_00_00);
+            let bc1 = $shuffle(b, c, 0b01_01_01_01);
+            let ab2 = $shuffle(a, b, 0b10_10_10_10);
+            let ca2 = $shuffle(c, a, 0b11_11_10_10);
+            let bc3 = $shuffle(b, c, 0b11_11_11_11);
+            $store(to, 3, 0, $shuffle(ab0, ca0, 0b10_00_10_00));
+            $store(to, 3, 1, $shuffle(bc1, ab2, 0b10_00_10_00));
+            $store(to, 3, 2, $shuffle(ca2, bc3, 0b10_00_10_00));
+        }
+
+        #[inline]
+        unsafe fn interleave4_unchecked(self, b: Self, c: Self, d: Self, to: &mut [Self::Scalar]) {
+            let to = to.as_mut_ptr();
+            let (v0, v1, v2, v3) =
+                transpose_ps!($shuffle, $unpacklo, $unpackhi, self.0, b.0, c.0, d.0);
+            $store(to, 4, 0, v0);
+            $store(to, 4, 1, v1);
+            $store(to, 4, 2, v2);
+            $store(to, 4, 3, v3);
+        }
+    }
+}
+
+// Transposes the 4x4 matrices in each 128-bit lane.
+macro_rules! transpose_ps {
+    { $shuffle:ident, $unpacklo:ident, $unpackhi:ident, $r0:expr, $r1:expr, $r2:expr, $r3:expr } => {
+        {
+            let (r0, r1, r2, r3) = ($r0, $r1, $r2, $r3);
+            let t0 = $unpacklo(r0, r1);
+            let t1 = $unpacklo(r2, r3);
+            let t2 = $unpackhi(r0, r1);
+            let t3 = $unpackhi(r2, r3);
+            (
+                $shuffle(t0, t1, 0b01_00_01_00),
+                $shuffle(t0, t1, 0b11_10_11_10),
+                $shuffle(t2, t3, 0b01_00_01_00),
+                $shuffle(t2, t3, 0b11_10_11_10),
+            )
+        }
+    }
+}
+
+macro_rules! interleave_pd {
+    { $load:ident, $store:ident, $unpacklo:ident, $unpackhi:ident } => {
+        #[inline]
+        unsafe fn deinterleave2_unchecked(
+            _: Self::Token,
+            from: &[Self::Scalar],
+        ) -> (Self, Self) {
+            let from = from.as_ptr();
+            let (v0, v1) = ($load(from, 2, 0), $load(from, 2, 1));
+            (Self($unpacklo(v0, v1)), Self($unpackhi(v0, v1)))
+        }
+
+        #[inline]
+        unsafe fn deinterleave3_unchecked(
+            _: Self::Token,
+            from: &[Self::Scalar],
+        ) -> (Self, Self, Self) {
+            let from = from.as_ptr();
+            let (v0, v1, v2) = ($load(from, 3, 0), $load(from, 3, 1), $load(from, 3, 2));
+            (
+                Self($unpacklo(v0, $unpackhi(v1, v1))),
+                Self($unpackhi(v0, $unpacklo(v2, v2))),
+                Self($unpacklo(v1, $unpackhi(v2, v2))),
+            )
+        }
+
+        #[inline]
+        unsafe fn deinterleave4_unchecked(
+            _: Self::Token,
+            from: &[Self::Scalar],
+        ) -> (Self, Self, Self, Self) {
+            let from = from.as_ptr();
+            let (v0, v1, v2, v3) = (
+                $load(from, 4, 0),
+                $load(from, 4, 1),
+                $load(from, 4, 2),
+                $load(from, 4, 3),
+            );
+            (
+                Self($unpacklo(v0, v2)),
+                Self($unpackhi(v0, v2)),
+                Self($unpacklo(v1, v3)),
+                Self($unpackhi(v1, v3)),
+            )
+        }
+
+        #[inline]
+        unsafe fn interleave2_unchecked(self, b: Self, to: &mut [Self::Scalar]) {
+            let to = to.as_mut_ptr();
+            $store(to, 2, 0, $unpacklo(self.0, b.0));
+            $store(to, 2, 1, $unpackhi(self.0, b.0));
+        }
+
+        #[inline]
+        unsafe fn interleave3_unchecked(self, b: Self, c: Self, to: &mut [Self::Scalar]) {
+            let to = to.as_mut_ptr();
+            $store(to, 3, 0, $unpacklo(self.0, b.0));
+            $store(to, 3, 1, $unpacklo(c.0, $unpackhi(self.0, self.0)));
+            $store(to, 3, 2, $unpackhi(b.0, c.0));
+        }
+
+        #[inline]
+        unsafe fn interleave4_unchecked(self, b: Self, c: Self, d: Self, to: &mut [Self::Scalar]) {
+            let to = to.as_mut_ptr();
+            $store(to, 4, 0, $unpacklo(self.0, b.0));
+            $store(to, 4, 1, $unpacklo(c.0, d.0));
+            $store(to, 4, 2, $unpackhi(self.0, b.0));
+            $store(to, 4, 3, $unpackhi(c.0, d.0));
+        }
+    }
+}
+
+#[inline]
+unsafe fn load_ps(from: *const f32, _streams: usize, chunk: usize) -> __m128 {
+    _mm_loadu_ps(from.add(4 * chunk))
+}
+
+#[inline]
+unsafe fn store_ps(to: *mut f32, _streams: usize, chunk: usize, value: __m128) {
+    _mm_storeu_ps(to.add(4 * chunk), value)
+}
+
+#[inline]
+unsafe fn load_pd(from: *const f64, _streams: usize, chunk: usize) -> __m128d {
+    _mm_loadu_pd(from.add(2 * chunk))
+}
+
+#[inline]
+unsafe fn store_pd(to: *mut f64, _streams: usize, chunk: usize, value: __m128d) {
+    _mm_storeu_pd(to.add(2 * chunk), value)
+}
+
+#[inline]
+unsafe fn load_ps256(from: *const f32, streams: usize, chunk: usize) -> __m256 {
+    _mm256_loadu2_m128(from.add(4 * (streams + chunk)), from.add(4 * chunk))
+}
+
+#[inline]
+unsafe fn store_ps256(to: *mut f32, streams: usize, chunk: usize, value: __m256) {
+    _mm256_storeu2_m128(to.add(4 * (streams + chunk)), to.add(4 * chunk), value)
+}
+
+#[inline]
+unsafe fn load_pd256(from: *const f64, streams: usize, chunk: usize) -> __m256d {
+    _mm256_loadu2_m128d(from.add(2 * (streams + chunk)), from.add(2 * chunk))
+}
+
+#[inline]
+unsafe fn store_pd256(to: *mut f64, streams: usize, chunk: usize, value: __m256d) {
+    _mm256_storeu2_m128d(to.add(2 * (streams + chunk)), to.add(2 * chunk), value)
+}
+
+// Lane permutations select a constant immediate for each possible lane count.
+#[inline]
+unsafe fn rotate_ps(x: __m128, n: usize) -> __m128 {
+    match n {
+        1 => _mm_shuffle_ps(x, x, 0b00_11_10_01),
+        2 => _mm_shuffle_ps(x, x, 0b01_00_11_10),
+        3 => _mm_shuffle_ps(x, x, 0b10_01_00_11),
+        _ => x,
+    }
+}
+
+#[inline]
+unsafe fn broadcast_ps(x: __m128, lane: usize) -> __m128 {
+    match lane {
+        0 => _mm_shuffle_ps(x, x, 0b00_00_00_00),
+        1 => _mm_shuffle_ps(x, x, 0b01_01_01_01),
+        2 => _mm_shuffle_ps(x, x, 0b10_10_10_10),
+        _ => _mm_shuffle_ps(x, x, 0b11_11_11_11),
+    }
+}
+
+#[inline]
+unsafe fn broadcast_pd(x: __m128d, lane: usize) -> __m128d {
+    if lane == 0 {
+        _mm_unpacklo_pd(x, x)
+    } else {
+        _mm_unpackhi_pd(x, x)
+    }
+}
+
+// 256-bit rotations rotate within each half, then blend in the lanes that wrapped around from
+// the other half.
+#[inline]
+unsafe fn rotate_ps256(x: __m256, n: usize) -> __m256 {
+    let swapped = _mm256_permute2f128_ps(x, x, 0x01);
+    let (x, y) = if n < 4 { (x, swapped) } else { (swapped, x) };
+    match n % 4 {
+        1 => _mm256_blend_ps(
+            _mm256_permute_ps(x, 0b00_11_10_01),
+            _mm256_permute_ps(y, 0b00_11_10_01),
+            0b1000_1000,
+        ),
+        2 => _mm256_blend_ps(
+            _mm256_permute_ps(x, 0b01_00_11_10),
+            _mm256_permute_ps(y, 0b01_00_11_10),
+            0b1100_1100,
+        ),
+        3 => _mm256_blend_ps(
+            _mm256_permute_ps(x, 0b10_01_00_11),
+            _mm256_permute_ps(y, 0b10_01_00_11),
+            0b1110_1110,
+        ),
+        _ => x,
+    }
+}
+
+#[inline]
+unsafe fn rotate_pd256(x: __m256d, n: usize) -> __m256d {
+    let swapped = _mm256_permute2f128_pd(x, x, 0x01);
+    let (x, y) = if n < 2 { (x, swapped) } else { (swapped, x) };
+    if n % 2 == 1 {
+        _mm256_blend_pd(
+            _mm256_permute_pd(x, 0b0101),
+            _mm256_permute_pd(y, 0b0101),
+            0b1010,
+        )
+    } else {
+        x
+    }
+}
+
+#[inline]
+unsafe fn broadcast_ps256(x: __m256, lane: usize) -> __m256 {
+    let half = if lane < 4 {
+        _mm256_permute2f128_ps(x, x, 0x00)
+    } else {
+        _mm256_permute2f128_ps(x, x, 0x11)
+    };
+    match lane % 4 {
+        0 => _mm256_permute_ps(half, 0b00_00_00_00),
+        1 => _mm256_permute_ps(half, 0b01_01_01_01),
+        2 => _mm256_permute_ps(half, 0b10_10_10_10),
+        _ => _mm256_permute_ps(half, 0b11_11_11_11),
+    }
+}
+
+#[inline]
+unsafe fn broadcast_pd256(x: __m256d, lane: usize) -> __m256d {
+    let half = if lane < 2 {
+        _mm256_permute2f128_pd(x, x, 0x00)
+    } else {
+        _mm256_permute2f128_pd(x, x, 0x11)
+    };
+    if lane % 2 == 0 {
+        _mm256_permute_pd(half, 0b0000)
+    } else {
+        _mm256_permute_pd(half, 0b1111)
+    }
+}
+
 unsafe impl Vector for f32x4 {
     type Scalar = f32;
 
@@ -234,6 +1077,23 @@ unsafe impl Vector for f32x4 {
     fn splat(_: Self::Token, from: Self::Scalar) -> Self {
         Self(unsafe { _mm_set1_ps(from) })
     }
+
+    interleave_ps! { load_ps, store_ps, _mm_shuffle_ps, _mm_unpacklo_ps, _mm_unpackhi_ps }
+
+    #[inline]
+    fn reverse(self) -> Self {
+        Self(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_01_10_11) })
+    }
+
+    #[inline]
+    fn rotate_lanes_left_impl(self, n: usize) -> Self {
+        Self(unsafe { rotate_ps(self.0, n) })
+    }
+
+    #[inline]
+    fn broadcast_lane_impl(self, lane: usize) -> Self {
+        Self(unsafe { broadcast_ps(self.0, lane) })
+    }
 }
 
 unsafe impl Vector for f64x2 {
@@ -254,6 +1114,40 @@ unsafe impl Vector for f64x2 {
     fn splat(_: Self::Token, from: Self::Scalar) -> Self {
         Self(unsafe { _mm_set1_pd(from) })
     }
+
+    interleave_pd! { load_pd, store_pd, _mm_unpacklo_pd, _mm_unpackhi_pd }
+
+    #[inline]
+    fn reverse(self) -> Self {
+        Self(unsafe { _mm_shuffle_pd(self.0, self.0, 0b01) })
+    }
+
+    #[inline]
+    fn rotate_lanes_left_impl(self, n: usize) -> Self {
+        if n == 1 {
+            self.reverse()
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    fn broadcast_lane_impl(self, lane: usize) -> Self {
+        Self(unsafe { broadcast_pd(self.0, lane) })
+    }
+}
+
+// Masks for partial loads and stores, selecting the first `len` lanes.
+#[inline]
+unsafe fn partial_mask_epi32(len: usize) -> __m256i {
+    static MASKS: [i32; 16] = [-1, -1, -1, -1, -1, -1, -1, -1, 0, 0, 0, 0, 0, 0, 0, 0];
+    _mm256_loadu_si256(MASKS.as_ptr().add(8 - len) as *const __m256i)
+}
+
+#[inline]
+unsafe fn partial_mask_epi64(len: usize) -> __m256i {
+    static MASKS: [i64; 8] = [-1, -1, -1, -1, 0, 0, 0, 0];
+    _mm256_loadu_si256(MASKS.as_ptr().add(4 - len) as *const __m256i)
 }
 
 unsafe impl Vector for f32x8 {
@@ -274,6 +1168,67 @@ unsafe impl Vector for f32x8 {
     fn splat(_: Self::Token, from: Self::Scalar) -> Self {
         Self(unsafe { _mm256_set1_ps(from) })
     }
+
+    #[inline]
+    fn read_partial(token: Self::Token, from: &[Self::Scalar], fill: Self::Scalar) -> Self {
+        if from.len() >= Self::width() {
+            unsafe { Self::read_unchecked(token, from) }
+        } else {
+            unsafe {
+                let mask = partial_mask_epi32(from.len());
+                let loaded = _mm256_maskload_ps(from.as_ptr(), mask);
+                Self(_mm256_blendv_ps(
+                    _mm256_set1_ps(fill),
+                    loaded,
+                    _mm256_castsi256_ps(mask),
+                ))
+            }
+        }
+    }
+
+    #[inline]
+    fn write_partial(self, to: &mut [Self::Scalar]) {
+        if to.len() >= Self::width() {
+            unsafe { self.write_unchecked(to) };
+        } else {
+            unsafe { _mm256_maskstore_ps(to.as_mut_ptr(), partial_mask_epi32(to.len()), self.0) };
+        }
+    }
+
+    interleave_ps! { load_ps256, store_ps256, _mm256_shuffle_ps, _mm256_unpacklo_ps, _mm256_unpackhi_ps }
+
+    #[inline]
+    fn reverse(self) -> Self {
+        Self(unsafe {
+            _mm256_permute_ps(_mm256_permute2f128_ps(self.0, self.0, 0x01), 0b00_01_10_11)
+        })
+    }
+
+    #[inline]
+    fn rotate_lanes_left_impl(self, n: usize) -> Self {
+        Self(unsafe { rotate_ps256(self.0, n) })
+    }
+
+    #[inline]
+    fn broadcast_lane_impl(self, lane: usize) -> Self {
+        Self(unsafe { broadcast_ps256(self.0, lane) })
+    }
+
+    #[inline]
+    fn split(self) -> (ShimToken<f32x4, f32, Avx>, ShimToken<f32x4, f32, Avx>) {
+        let token = self.to_token();
+        unsafe {
+            (
+                Vector::from_underlying(token, _mm256_castps256_ps128(self.0)),
+                Vector::from_underlying(token, _mm256_extractf128_ps(self.0, 1)),
+            )
+        }
+    }
+
+    #[inline]
+    fn concat(low: ShimToken<f32x4, f32, Avx>, high: ShimToken<f32x4, f32, Avx>) -> Self {
+        Self(unsafe { _mm256_set_m128(high.to_underlying(), low.to_underlying()) })
+    }
 }
 
 unsafe impl Vector for f64x4 {
@@ -294,4 +1249,63 @@ unsafe impl Vector for f64x4 {
     fn splat(_: Self::Token, from: Self::Scalar) -> Self {
         Self(unsafe { _mm256_set1_pd(from) })
     }
+
+    #[inline]
+    fn read_partial(token: Self::Token, from: &[Self::Scalar], fill: Self::Scalar) -> Self {
+        if from.len() >= Self::width() {
+            unsafe { Self::read_unchecked(token, from) }
+        } else {
+            unsafe {
+                let mask = partial_mask_epi64(from.len());
+                let loaded = _mm256_maskload_pd(from.as_ptr(), mask);
+                Self(_mm256_blendv_pd(
+                    _mm256_set1_pd(fill),
+                    loaded,
+                    _mm256_castsi256_pd(mask),
+                ))
+            }
+        }
+    }
+
+    #[inline]
+    fn write_partial(self, to: &mut [Self::Scalar]) {
+        if to.len() >= Self::width() {
+            unsafe { self.write_unchecked(to) };
+        } else {
+            unsafe { _mm256_maskstore_pd(to.as_mut_ptr(), partial_mask_epi64(to.len()), self.0) };
+        }
+    }
+
+    interleave_pd! { load_pd256, store_pd256, _mm256_unpacklo_pd, _mm256_unpackhi_pd }
+
+    #[inline]
+    fn reverse(self) -> Self {
+        Self(unsafe { _mm256_permute_pd(_mm256_permute2f128_pd(self.0, self.0, 0x01), 0b0101) })
+    }
+
+    #[inline]
+    fn rotate_lanes_left_impl(self, n: usize) -> Self {
+        Self(unsafe { rotate_pd256(self.0, n) })
+    }
+
+    #[inline]
+    fn broadcast_lane_impl(self, lane: usize) -> Self {
+        Self(unsafe { broadcast_pd256(self.0, lane) })
+    }
+
+    #[inline]
+    fn split(self) -> (ShimToken<f64x2, f64, Avx>, ShimToken<f64x2, f64, Avx>) {
+        let token = self.to_token();
+        unsafe {
+            (
+                Vector::from_underlying(token, _mm256_castpd256_pd128(self.0)),
+                Vector::from_underlying(token, _mm256_extractf128_pd(self.0, 1)),
+            )
+        }
+    }
+
+    #[inline]
+    fn concat(low: ShimToken<f64x2, f64, Avx>, high: ShimToken<f64x2, f64, Avx>) -> Self {
+        Self(unsafe { _mm256_set_m128d(high.to_underlying(), low.to_underlying()) })
+    }
 }
@@ -47,6 +47,52 @@ macro_rules! arithmetic_ops {
             }
         }
     };
+    {
+        @new $type:ty, $feature:expr, $trait:ident, $func:ident, [$method:ident]
+    } => {
+        impl core::ops::$trait<$type> for $type {
+            type Output = Self;
+            #[inline]
+            fn $func(mut self, rhs: Self) -> Self {
+                for (a, b) in self.iter_mut().zip(rhs.iter()) {
+                    *a = a.$method(*b);
+                }
+                self
+            }
+        }
+
+        impl core::ops::$trait<<$type as $crate::vector::Vector>::Scalar> for $type {
+            type Output = Self;
+            #[inline]
+            fn $func(mut self, rhs: <$type as $crate::vector::Vector>::Scalar) -> Self {
+                for a in self.iter_mut() {
+                    *a = a.$method(rhs);
+                }
+                self
+            }
+        }
+    };
+    {
+        @assign $type:ty, $feature:expr, $trait:ident, $func:ident, [$method:ident]
+    } => {
+        impl core::ops::$trait<$type> for $type {
+            #[inline]
+            fn $func(&mut self, rhs: Self) {
+                for (a, b) in self.iter_mut().zip(rhs.iter()) {
+                    *a = a.$method(*b);
+                }
+            }
+        }
+
+        impl core::ops::$trait<<$type as $crate::vector::Vector>::Scalar> for $type {
+            #[inline]
+            fn $func(&mut self, rhs: <$type as $crate::vector::Vector>::Scalar) {
+                for a in self.iter_mut() {
+                    *a = a.$method(rhs);
+                }
+            }
+        }
+    };
     {
         @new $type:ty, $feature:expr, $trait:ident, $func:ident, ($op:path)
     } => {
@@ -117,7 +163,7 @@ macro_rules! arithmetic_ops {
                 I: Iterator<Item = $type>,
             {
                 if let Some(sums) = iter.sum::<Option<$type>>() {
-                    sums.iter().sum()
+                    $crate::vector::Reduce::reduce_sum(sums)
                 } else {
                     Default::default()
                 }
@@ -147,8 +193,8 @@ macro_rules! arithmetic_ops {
             where
                 I: Iterator<Item = $type>,
             {
-                if let Some(sums) = iter.sum::<Option<$type>>() {
-                    sums.iter().product()
+                if let Some(products) = iter.product::<Option<$type>>() {
+                    $crate::vector::Reduce::reduce_product(products)
                 } else {
                     Default::default()
                 }
@@ -166,6 +212,675 @@ macro_rules! arithmetic_ops {
     };
 }
 
+// Float lanes are operated on through their bit representation.  NEON has no float bitwise
+// instructions, so its float lanes are reinterpreted as unsigned integers.
+macro_rules! bitwise_ops {
+    {
+        for $type:ty:
+            and -> ($and:path),
+            or -> ($or:path),
+            xor -> ($xor:path),
+            not -> ($not:path)
+    } => {
+        impl core::ops::BitAnd<$type> for $type {
+            type Output = Self;
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn bitand(self, rhs: Self) -> Self {
+                Self(unsafe { $and(self.0, rhs.0) })
+            }
+        }
+
+        impl core::ops::BitOr<$type> for $type {
+            type Output = Self;
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn bitor(self, rhs: Self) -> Self {
+                Self(unsafe { $or(self.0, rhs.0) })
+            }
+        }
+
+        impl core::ops::BitXor<$type> for $type {
+            type Output = Self;
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn bitxor(self, rhs: Self) -> Self {
+                Self(unsafe { $xor(self.0, rhs.0) })
+            }
+        }
+
+        impl core::ops::Not for $type {
+            type Output = Self;
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn not(self) -> Self {
+                Self(unsafe { $not(self.0) })
+            }
+        }
+    };
+    {
+        for $type:ty:
+            and -> $and:expr,
+            or -> $or:expr,
+            xor -> $xor:expr,
+            not -> $not:expr,
+            and_not -> $and_not:expr
+    } => {
+        impl core::ops::BitAnd<$type> for $type {
+            type Output = Self;
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn bitand(self, rhs: Self) -> Self {
+                Self(unsafe { ($and)(self.0, rhs.0) })
+            }
+        }
+
+        impl core::ops::BitOr<$type> for $type {
+            type Output = Self;
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn bitor(self, rhs: Self) -> Self {
+                Self(unsafe { ($or)(self.0, rhs.0) })
+            }
+        }
+
+        impl core::ops::BitXor<$type> for $type {
+            type Output = Self;
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn bitxor(self, rhs: Self) -> Self {
+                Self(unsafe { ($xor)(self.0, rhs.0) })
+            }
+        }
+
+        impl core::ops::Not for $type {
+            type Output = Self;
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn not(self) -> Self {
+                Self(unsafe { ($not)(self.0) })
+            }
+        }
+
+        // `and_not` computes `self & !other`.  The x86 andnot instructions complement their first
+        // operand, so they are given the operands in reverse order, while NEON `vbic` and simd128
+        // `v128_andnot` complement their second operand and are used directly.
+        impl $crate::vector::Bitwise for $type {
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn and_not(self, other: Self) -> Self {
+                Self(unsafe { ($and_not)(self.0, other.0) })
+            }
+        }
+    };
+}
+
+macro_rules! shift_ops {
+    {
+        for $type:ty:
+            shift_left -> $shift_left:expr,
+            shift_right -> $shift_right:expr
+    } => {
+        impl $crate::math::Shift for $type {
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn shift_left(self, count: u32) -> Self {
+                Self(unsafe { ($shift_left)(self.0, count) })
+            }
+
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn shift_right(self, count: u32) -> Self {
+                Self(unsafe { ($shift_right)(self.0, count) })
+            }
+        }
+    };
+}
+
+macro_rules! compare_ops {
+    {
+        for $type:ty => $mask:ident $(<$token:ty>)?:
+            eq -> $eq:expr,
+            ne -> $ne:expr,
+            lt -> $lt:expr,
+            le -> $le:expr,
+            gt -> $gt:expr,
+            ge -> $ge:expr
+    } => {
+        impl $crate::vector::Compare for $type {
+            type Mask = $mask $(<$token>)?;
+
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn lanes_eq(self, other: Self) -> Self::Mask {
+                $mask(unsafe { ($eq)(self.0, other.0) })
+            }
+
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn lanes_ne(self, other: Self) -> Self::Mask {
+                $mask(unsafe { ($ne)(self.0, other.0) })
+            }
+
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn lanes_lt(self, other: Self) -> Self::Mask {
+                $mask(unsafe { ($lt)(self.0, other.0) })
+            }
+
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn lanes_le(self, other: Self) -> Self::Mask {
+                $mask(unsafe { ($le)(self.0, other.0) })
+            }
+
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn lanes_gt(self, other: Self) -> Self::Mask {
+                $mask(unsafe { ($gt)(self.0, other.0) })
+            }
+
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn lanes_ge(self, other: Self) -> Self::Mask {
+                $mask(unsafe { ($ge)(self.0, other.0) })
+            }
+        }
+    }
+}
+
+macro_rules! select_ops {
+    {
+        for $mask:ty => $($vector:ident: $select:expr),*
+    } => {
+        $(
+        impl $crate::vector::Select<$vector> for $mask {
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn select(self, a: $vector, b: $vector) -> $vector {
+                $vector(unsafe { ($select)(self.0, a.0, b.0) })
+            }
+        }
+        )*
+    }
+}
+
+// Implements wider vectors of scalars by doubling the narrower vectors.
+macro_rules! shim_widths {
+    {
+        $token:ty, [$($scalar:ty),*], $widths:tt
+    } => {
+        $(
+        shim_widths! { @impl $token, $scalar, $widths }
+        )*
+    };
+    {
+        @impl $token:ty, $scalar:ty, [$($width:ident -> $doubled:ident),*]
+    } => {
+        $(
+        impl $crate::scalar::Scalar<$token, $crate::vector::width::$doubled> for $scalar {
+            type Vector = $crate::shim::Shim2<
+                <Self as $crate::scalar::Scalar<$token, $crate::vector::width::$width>>::Vector,
+                Self,
+            >;
+        }
+        )*
+    };
+}
+
+macro_rules! mul_add_ops {
+    {
+        for $type:ty
+    } => {
+        impl<Token> $crate::vector::MulAddImpl<Token> for $type {
+            #[inline]
+            fn mul_add_impl(self, a: Self, b: Self) -> Self {
+                self * a + b
+            }
+
+            #[inline]
+            fn mul_sub_impl(self, a: Self, b: Self) -> Self {
+                self * a - b
+            }
+        }
+    };
+    {
+        for $type:ty: $($token:ty),+
+    } => {
+        $(
+        impl $crate::vector::MulAddImpl<$token> for $type {
+            #[inline]
+            fn mul_add_impl(self, a: Self, b: Self) -> Self {
+                self * a + b
+            }
+
+            #[inline]
+            fn mul_sub_impl(self, a: Self, b: Self) -> Self {
+                self * a - b
+            }
+        }
+        )*
+    };
+    {
+        for $type:ty => $token:ty:
+            mul_add -> $mul_add:expr,
+            mul_sub -> $mul_sub:expr
+    } => {
+        impl $crate::vector::MulAddImpl<$token> for $type {
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn mul_add_impl(self, a: Self, b: Self) -> Self {
+                Self(unsafe { ($mul_add)(self.0, a.0, b.0) })
+            }
+
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn mul_sub_impl(self, a: Self, b: Self) -> Self {
+                Self(unsafe { ($mul_sub)(self.0, a.0, b.0) })
+            }
+        }
+    };
+}
+
+macro_rules! gather_ops {
+    {
+        for $type:ty
+    } => {
+        impl<Token> $crate::vector::GatherImpl<Token> for $type {}
+    };
+    {
+        for $type:ty: $($token:ty),+
+    } => {
+        $(
+        impl $crate::vector::GatherImpl<$token> for $type {}
+        )*
+    };
+    {
+        for $type:ty => $token:ty:
+            gather -> |$from:ident, $offsets:ident| $gather:expr
+    } => {
+        // Hardware gathers take signed 32-bit offsets, so larger slices are loaded lane-by-lane.
+        impl $crate::vector::GatherImpl<$token> for $type {
+            #[inline]
+            unsafe fn gather_impl(
+                token: Self::Token,
+                from: &[Self::Scalar],
+                indices: &[usize],
+            ) -> Self {
+                if from.len() > i32::MAX as usize {
+                    return $crate::vector::gather_lanes(token, from, indices);
+                }
+                let mut offsets = [0i32; 16];
+                for (offset, index) in offsets.iter_mut().zip(&indices[..Self::width()]) {
+                    *offset = *index as i32;
+                }
+                let $from = from.as_ptr();
+                let $offsets = offsets.as_ptr();
+                Self($gather)
+            }
+
+            #[inline]
+            unsafe fn read_strided_impl(
+                token: Self::Token,
+                from: &[Self::Scalar],
+                stride: usize,
+            ) -> Self {
+                if from.len() > i32::MAX as usize {
+                    return $crate::vector::read_strided_lanes(token, from, stride);
+                }
+                let mut offsets = [0i32; 16];
+                for (i, offset) in offsets.iter_mut().take(Self::width()).enumerate() {
+                    *offset = (i * stride) as i32;
+                }
+                let $from = from.as_ptr();
+                let $offsets = offsets.as_ptr();
+                Self($gather)
+            }
+        }
+    };
+}
+
+macro_rules! reduce_ops {
+    {
+        @reduce $self:ident, [$method:ident]
+    } => {
+        {
+            let mut lanes = $self.iter().copied();
+            let first = lanes.next().unwrap();
+            lanes.fold(first, |a, b| a.$method(b))
+        }
+    };
+    {
+        @reduce $self:ident, [$op:tt]
+    } => {
+        {
+            let mut lanes = $self.iter().copied();
+            let first = lanes.next().unwrap();
+            lanes.fold(first, |a, b| a $op b)
+        }
+    };
+    {
+        @reduce $self:ident, ($reduce:expr)
+    } => {
+        // Native reductions combine the upper and lower halves of the vector until one lane
+        // remains.
+        unsafe { ($reduce)($self.0) }
+    };
+    {
+        for $type:ty:
+            sum -> $sum:tt,
+            product -> $product:tt
+    } => {
+        impl $crate::vector::Reduce for $type {
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn reduce_sum(self) -> Self::Scalar {
+                reduce_ops!(@reduce self, $sum)
+            }
+
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn reduce_product(self) -> Self::Scalar {
+                reduce_ops!(@reduce self, $product)
+            }
+        }
+    };
+    {
+        for $type:ty:
+            min -> $min:tt,
+            max -> $max:tt
+    } => {
+        impl $crate::vector::ReduceOrd for $type {
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn reduce_min(self) -> Self::Scalar {
+                reduce_ops!(@reduce self, $min)
+            }
+
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn reduce_max(self) -> Self::Scalar {
+                reduce_ops!(@reduce self, $max)
+            }
+        }
+    };
+}
+
+macro_rules! convert_ops {
+    {
+        @convert $self:ident, $output:ty, [$($convert:tt)*]
+    } => {
+        $crate::vector::convert_lanes($self, $($convert)*)
+    };
+    {
+        @convert $self:ident, $output:ty, ($convert:expr)
+    } => {
+        <$output as $crate::vector::Vector>::from_underlying(
+            $crate::vector::Vector::to_token($self),
+            unsafe { ($convert)($crate::vector::Vector::to_underlying($self)) },
+        )
+    };
+    {
+        for $type:ty => $to:ty as $output:ty:
+            convert -> $convert:tt
+    } => {
+        impl $crate::vector::Convert<$to> for $type {
+            type Output = $output;
+
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn convert(self) -> Self::Output {
+                convert_ops!(@convert self, $output, $convert)
+            }
+        }
+    };
+    {
+        for $type:ty => $to:ty as $output:ty:
+            convert -> $convert:tt,
+            round -> $round:tt
+    } => {
+        convert_ops! {
+            for $type => $to as $output:
+                convert -> $convert
+        }
+
+        impl $crate::vector::ConvertRound<$to> for $type {
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn convert_round(self) -> Self::Output {
+                convert_ops!(@convert self, $output, $round)
+            }
+        }
+    };
+}
+
+macro_rules! bits_ops {
+    {
+        for $type:ty => $bits:ty:
+            to_bits -> $to_bits:tt,
+            from_bits -> $from_bits:tt
+    } => {
+        impl $crate::vector::Bits for $type {
+            type Bits = $bits;
+
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn to_bits(self) -> $bits {
+                convert_ops!(@convert self, $bits, $to_bits)
+            }
+
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn from_bits(bits: $bits) -> Self {
+                convert_ops!(@convert bits, Self, $from_bits)
+            }
+        }
+    };
+}
+
+macro_rules! float_ops {
+    {
+        for $type:ty:
+            min -> $min:expr,
+            max -> $max:expr,
+            abs -> $abs:expr,
+            copysign -> $copysign:expr
+    } => {
+        impl $crate::vector::Float for $type {
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn min(self, other: Self) -> Self {
+                Self(unsafe { ($min)(self.0, other.0) })
+            }
+
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn max(self, other: Self) -> Self {
+                Self(unsafe { ($max)(self.0, other.0) })
+            }
+
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn abs(self) -> Self {
+                Self(unsafe { ($abs)(self.0) })
+            }
+
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn copysign(self, sign: Self) -> Self {
+                Self(unsafe { ($copysign)(self.0, sign.0) })
+            }
+        }
+    };
+}
+
+macro_rules! sqrt_ops {
+    {
+        for $type:ty:
+            sqrt -> $sqrt:expr,
+            recip -> $recip:expr,
+            rsqrt -> $rsqrt:expr
+    } => {
+        impl $crate::vector::Sqrt for $type {
+            sqrt_ops! { @exact $sqrt, $recip, $rsqrt }
+
+            #[inline]
+            fn recip_estimate(self) -> Self {
+                <Self as $crate::vector::Sqrt>::recip(self)
+            }
+
+            #[inline]
+            fn rsqrt_estimate(self) -> Self {
+                <Self as $crate::vector::Sqrt>::rsqrt(self)
+            }
+
+            #[inline]
+            fn recip_refined(self) -> Self {
+                <Self as $crate::vector::Sqrt>::recip(self)
+            }
+
+            #[inline]
+            fn rsqrt_refined(self) -> Self {
+                <Self as $crate::vector::Sqrt>::rsqrt(self)
+            }
+        }
+    };
+    {
+        for $type:ty:
+            sqrt -> $sqrt:expr,
+            recip -> $recip:expr,
+            rsqrt -> $rsqrt:expr,
+            recip_estimate -> $recip_estimate:expr,
+            rsqrt_estimate -> $rsqrt_estimate:expr
+    } => {
+        impl $crate::vector::Sqrt for $type {
+            sqrt_ops! { @exact $sqrt, $recip, $rsqrt }
+
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn recip_estimate(self) -> Self {
+                Self(unsafe { ($recip_estimate)(self.0) })
+            }
+
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn rsqrt_estimate(self) -> Self {
+                Self(unsafe { ($rsqrt_estimate)(self.0) })
+            }
+
+            // y' = y (2 - x y)
+            #[inline]
+            fn recip_refined(self) -> Self {
+                let token = <Self as $crate::vector::Vector>::to_token(self);
+                let y = <Self as $crate::vector::Sqrt>::recip_estimate(self);
+                y * (<Self as $crate::vector::Vector>::splat(token, 2.) - self * y)
+            }
+
+            // y' = y (3 - x y^2) / 2
+            #[inline]
+            fn rsqrt_refined(self) -> Self {
+                let token = <Self as $crate::vector::Vector>::to_token(self);
+                let y = <Self as $crate::vector::Sqrt>::rsqrt_estimate(self);
+                y * (<Self as $crate::vector::Vector>::splat(token, 1.5) - self * y * y * 0.5)
+            }
+        }
+    };
+    {
+        @exact $sqrt:expr, $recip:expr, $rsqrt:expr
+    } => {
+        #[allow(unused_unsafe)]
+        #[inline]
+        fn sqrt(self) -> Self {
+            Self(unsafe { ($sqrt)(self.0) })
+        }
+
+        #[allow(unused_unsafe)]
+        #[inline]
+        fn recip(self) -> Self {
+            Self(unsafe { ($recip)(self.0) })
+        }
+
+        #[allow(unused_unsafe)]
+        #[inline]
+        fn rsqrt(self) -> Self {
+            Self(unsafe { ($rsqrt)(self.0) })
+        }
+    };
+}
+
+macro_rules! round_ops {
+    {
+        for $type:ty:
+            floor -> $floor:expr,
+            ceil -> $ceil:expr,
+            round_ties_even -> $round_ties_even:expr,
+            trunc -> $trunc:expr
+    } => {
+        impl $crate::vector::Round for $type {
+            round_ops! { @impl $floor, $ceil, $round_ties_even, $trunc }
+
+            // Adding the largest value less than one half before truncating rounds ties away from
+            // zero, without rounding up values just below one half.
+            #[inline]
+            fn round(self) -> Self {
+                let token = <Self as $crate::vector::Vector>::to_token(self);
+                let half = <Self as $crate::vector::Vector>::splat(
+                    token,
+                    0.5 - <<Self as $crate::vector::Vector>::Scalar>::EPSILON / 4.,
+                );
+                <Self as $crate::vector::Round>::trunc(
+                    self + <Self as $crate::vector::Float>::copysign(half, self),
+                )
+            }
+        }
+    };
+    {
+        for $type:ty:
+            floor -> $floor:expr,
+            ceil -> $ceil:expr,
+            round -> $round:expr,
+            round_ties_even -> $round_ties_even:expr,
+            trunc -> $trunc:expr
+    } => {
+        impl $crate::vector::Round for $type {
+            round_ops! { @impl $floor, $ceil, $round_ties_even, $trunc }
+
+            #[allow(unused_unsafe)]
+            #[inline]
+            fn round(self) -> Self {
+                Self(unsafe { ($round)(self.0) })
+            }
+        }
+    };
+    {
+        @impl $floor:expr, $ceil:expr, $round_ties_even:expr, $trunc:expr
+    } => {
+        #[allow(unused_unsafe)]
+        #[inline]
+        fn floor(self) -> Self {
+            Self(unsafe { ($floor)(self.0) })
+        }
+
+        #[allow(unused_unsafe)]
+        #[inline]
+        fn ceil(self) -> Self {
+            Self(unsafe { ($ceil)(self.0) })
+        }
+
+        #[allow(unused_unsafe)]
+        #[inline]
+        fn round_ties_even(self) -> Self {
+            Self(unsafe { ($round_ties_even)(self.0) })
+        }
+
+        #[allow(unused_unsafe)]
+        #[inline]
+        fn trunc(self) -> Self {
+            Self(unsafe { ($trunc)(self.0) })
+        }
+    };
+}
+
 macro_rules! as_slice {
     {
         $type:ty
@@ -7,6 +7,10 @@
     all(feature = "nightly", target_arch = "aarch64"),
     feature(stdsimd, aarch64_target_feature)
 )]
+#![cfg_attr(
+    all(feature = "nightly", any(target_arch = "x86", target_arch = "x86_64")),
+    feature(stdsimd, avx512_target_feature)
+)]
 //! `generic-simd` provides safe and idiomatic zero-cost abstractions for writing explicit
 //! cross-platform SIMD operations.
 //!
@@ -15,6 +19,8 @@
 //! also supported:
 //! * SSE4.1 (x86/x86-64)
 //! * AVX (x86/x86-64)
+//! * AVX2 and FMA (x86/x86-64)
+//! * AVX-512F (x86/x86-64, with `nightly` cargo feature)
 //! * NEON (aarch64, with `nightly` cargo feature)
 //! * SIMD128 (wasm32, with `nightly` cargo feature and `simd128` target feature)
 //!
@@ -73,7 +79,7 @@
 //!     dispatch,
 //!     scalar::Scalar,
 //!     slice::Slice,
-//!     vector::{Signed, Vector, width},
+//!     vector::{width, Signed, Vector, VectorOf},
 //! };
 //!
 //! // Equivalent to an array of 4 2-dimensional coordinates,
@@ -88,7 +94,7 @@
 //! where
 //!     T: Token,
 //!     f64: Scalar<T, width::W4>,
-//!     <f64 as Scalar<T, width::W4>>::Vector: Signed,
+//!     VectorOf<f64, 4, T>: Signed,
 //! {
 //!     let mut xsum = f64::zeroed(token);
 //!     let mut ysum = f64::zeroed(token);
@@ -162,6 +168,7 @@ mod implementation;
 
 pub mod alignment;
 pub mod arch;
+pub mod math;
 pub mod pointer;
 pub mod scalar;
 pub mod shim;
@@ -0,0 +1,900 @@
+//! Vectorized transcendental functions.
+//!
+//! The functions in this module are evaluated with polynomial approximations built from lane-wise
+//! vector operations, so a single implementation serves every token and width.  They accept any
+//! `f32` or `f64` vector implementing [`Transcendental`](trait.Transcendental.html).
+//!
+//! Each function documents its maximum error in units in the last place (ULP) of the exact
+//! result, over all finite arguments.  The bounds hold whether or not
+//! [`mul_add`](../vector/trait.MulAdd.html) is fused.  The trigonometric functions reduce very
+//! large arguments lane-wise with `libm`.
+//! Special values (zeros, infinities and NaN) are handled like the corresponding functions in
+//! `std`.
+
+use crate::vector::{Bits, Compare, Mask, Ops, Round, Select, Vector};
+
+mod private {
+    /// Constants, polynomial coefficients and scalar fallbacks for a scalar type.
+    ///
+    /// Polynomial coefficients are in order of increasing degree.
+    pub trait Constants: Copy + 'static {
+        type Bits: Copy;
+
+        /// The number of explicit mantissa bits, which precede the exponent.
+        const MANTISSA: u32;
+        const BIAS: Self::Bits;
+        const EXPONENT_BIAS: Self;
+        /// Adding and subtracting this value rounds to an integer, which is then stored in the
+        /// low bits of the result.
+        const SHIFT: Self;
+
+        const ONE: Self;
+        const HALF: Self;
+        const TWO: Self;
+        const INFINITY: Self;
+        const NAN: Self;
+        const MIN_POSITIVE: Self;
+        const SUBNORMAL_SCALE: Self;
+        const SUBNORMAL_EXPONENT: Self;
+        /// Multiplying by this value splits a float into halves for exact products.
+        const SPLIT: Self;
+
+        const SQRT_2: Self;
+        const LOG2_E: Self;
+        const LOG2_E_HI: Self;
+        const LOG2_E_LO: Self;
+        const LOG10_E_HI: Self;
+        const LOG10_E_LO: Self;
+        const LN2_HI: Self;
+        const LN2_LO: Self;
+        const LOG10_2_HI: Self;
+        const LOG10_2_LO: Self;
+        const EXP_MIN: Self;
+        const EXP_MAX: Self;
+        const EXP2_MIN: Self;
+        const EXP2_MAX: Self;
+        const EXP: &'static [Self];
+        const EXP2: &'static [Self];
+        const LN: &'static [Self];
+        /// The rounding error of the constant term of `LN`.
+        const LN_LO: Self;
+
+        const FRAC_2_PI: Self;
+        const PI_2_A: Self;
+        const PI_2_B: Self;
+        const PI_2_C: Self;
+        const PI_2_D: Self;
+        /// The largest magnitude reduced exactly by `reduce_pi_2`.
+        const REDUCE_MAX: Self;
+        const SIN: &'static [Self];
+        const COS: &'static [Self];
+
+        const TAN_PI_8: Self;
+        const TAN_3PI_8: Self;
+        const FRAC_PI_4_HI: Self;
+        const FRAC_PI_4_LO: Self;
+        const FRAC_PI_2_HI: Self;
+        const FRAC_PI_2_LO: Self;
+        const PI_HI: Self;
+        const PI_LO: Self;
+        const ATAN: &'static [Self];
+
+        const TANH_SMALL: Self;
+        const TANH: &'static [Self];
+
+        const ERF_MAX: Self;
+        const ERF: &'static [Self];
+        const ERFC: &'static [Self];
+
+        /// Scalar functions for lanes too large for `reduce_pi_2`.
+        fn sin(self) -> Self;
+        fn cos(self) -> Self;
+        fn tan(self) -> Self;
+    }
+
+    /// Logical shifts of the unsigned lanes holding the bits of a float vector.
+    ///
+    /// The count is always less than the number of bits in a lane.
+    pub trait Shift: Copy {
+        fn shift_left(self, count: u32) -> Self;
+        fn shift_right(self, count: u32) -> Self;
+    }
+}
+
+use private::Constants;
+pub(crate) use private::Shift;
+
+impl Constants for f32 {
+    type Bits = u32;
+
+    const MANTISSA: u32 = 23;
+    const BIAS: u32 = 127;
+    const EXPONENT_BIAS: f32 = 127.;
+    const SHIFT: f32 = 12_582_912.;
+
+    const ONE: f32 = 1.;
+    const HALF: f32 = 0.5;
+    const TWO: f32 = 2.;
+    const INFINITY: f32 = f32::INFINITY;
+    const NAN: f32 = f32::NAN;
+    const MIN_POSITIVE: f32 = f32::MIN_POSITIVE;
+    const SUBNORMAL_SCALE: f32 = 16_777_216.;
+    const SUBNORMAL_EXPONENT: f32 = 24.;
+    const SPLIT: f32 = 4097.;
+
+    const SQRT_2: f32 = core::f32::consts::SQRT_2;
+    const LOG2_E: f32 = core::f32::consts::LOG2_E;
+    const LOG2_E_HI: f32 = 1.442_871_1;
+    const LOG2_E_LO: f32 = -1.760_528_5e-4;
+    const LOG10_E_HI: f32 = 0.434_326_17;
+    const LOG10_E_LO: f32 = -3.168_997e-5;
+    const LN2_HI: f32 = 0.693_359_4;
+    const LN2_LO: f32 = -2.121_944_4e-4;
+    const LOG10_2_HI: f32 = 0.301_025_4;
+    const LOG10_2_LO: f32 = 4.605_039e-6;
+    const EXP_MIN: f32 = -104.;
+    const EXP_MAX: f32 = 89.;
+    const EXP2_MIN: f32 = -151.;
+    const EXP2_MAX: f32 = 129.;
+    const EXP: &'static [f32] = &[
+        0.499_999_94,
+        0.166_665_21,
+        0.041_668_39,
+        0.008_368_71,
+        0.001_381_461_3,
+    ];
+    const EXP2: &'static [f32] = &[
+        0.693_147_24,
+        0.240_226_52,
+        0.055_503_104,
+        0.009_617_693,
+        0.001_340_664_3,
+        1.559_467_8e-4,
+    ];
+    const LN: &'static [f32] = &[
+        0.666_666_6,
+        0.400_003_34,
+        0.285_373_1,
+        0.235_821_52,
+    ];
+    const LN_LO: f32 = 2.952_432e-8;
+
+    const FRAC_2_PI: f32 = core::f32::consts::FRAC_2_PI;
+    const PI_2_A: f32 = 1.570_800_8;
+    const PI_2_B: f32 = -4.453_584_6e-6;
+    const PI_2_C: f32 = -8.706_138e-10;
+    const PI_2_D: f32 = 6.223_372e-14;
+    const REDUCE_MAX: f32 = 6000.;
+    const SIN: &'static [f32] = &[
+        -0.166_666_55,
+        0.008_332_16,
+        -1.951_528_2e-4,
+    ];
+    const COS: &'static [f32] = &[
+        0.041_666_646,
+        -0.001_388_731_6,
+        2.443_315_7e-5,
+    ];
+
+    const TAN_PI_8: f32 = 0.414_213_57;
+    const TAN_3PI_8: f32 = 2.414_213_7;
+    const FRAC_PI_4_HI: f32 = core::f32::consts::FRAC_PI_4;
+    const FRAC_PI_4_LO: f32 = -2.185_569_4e-8;
+    const FRAC_PI_2_HI: f32 = core::f32::consts::FRAC_PI_2;
+    const FRAC_PI_2_LO: f32 = -4.371_139e-8;
+    const PI_HI: f32 = core::f32::consts::PI;
+    const PI_LO: f32 = -8.742_278e-8;
+    const ATAN: &'static [f32] = &[
+        -0.333_333_16,
+        0.199_984_71,
+        -0.142_435_33,
+        0.105_938_14,
+        -0.060_782_213,
+    ];
+
+    const TANH_SMALL: f32 = 0.625;
+    const TANH: &'static [f32] = &[
+        -0.333_332_8,
+        0.133_314_42,
+        -0.053_739_715,
+        0.020_639_088,
+        -0.005_704_987,
+    ];
+
+    const ERF_MAX: f32 = 4.;
+    const ERF: &'static [f32] = &[
+        0.128_379_17,
+        -0.376_126_38,
+        0.112_837_82,
+        -0.026_865_4,
+        0.005_220_945_5,
+        -8.482_829e-4,
+        1.125_694_9e-4,
+        -9.641_52e-6,
+    ];
+    const ERFC: &'static [f32] = &[
+        0.278_917_58,
+        0.312_673_7,
+        0.131_287_89,
+        0.381_334_75,
+        -0.053_726_96,
+        -0.051_006_15,
+    ];
+
+    #[inline]
+    fn sin(self) -> f32 {
+        libm::sinf(self)
+    }
+
+    #[inline]
+    fn cos(self) -> f32 {
+        libm::cosf(self)
+    }
+
+    #[inline]
+    fn tan(self) -> f32 {
+        libm::tanf(self)
+    }
+}
+
+impl Constants for f64 {
+    type Bits = u64;
+
+    const MANTISSA: u32 = 52;
+    const BIAS: u64 = 1023;
+    const EXPONENT_BIAS: f64 = 1023.;
+    const SHIFT: f64 = 6_755_399_441_055_744.;
+
+    const ONE: f64 = 1.;
+    const HALF: f64 = 0.5;
+    const TWO: f64 = 2.;
+    const INFINITY: f64 = f64::INFINITY;
+    const NAN: f64 = f64::NAN;
+    const MIN_POSITIVE: f64 = f64::MIN_POSITIVE;
+    const SUBNORMAL_SCALE: f64 = 18_014_398_509_481_984.;
+    const SUBNORMAL_EXPONENT: f64 = 54.;
+    const SPLIT: f64 = 134_217_729.;
+
+    const SQRT_2: f64 = core::f64::consts::SQRT_2;
+    const LOG2_E: f64 = core::f64::consts::LOG2_E;
+    const LOG2_E_HI: f64 = 1.442_695_036_530_494_7;
+    const LOG2_E_LO: f64 = 4.358_468_717_418_518_4e-9;
+    const LOG10_E_HI: f64 = 0.434_294_480_830_431;
+    const LOG10_E_LO: f64 = 1.072_820_843_154_058_5e-9;
+    const LN2_HI: f64 = 0.693_147_180_369_123_8;
+    const LN2_LO: f64 = 1.908_214_929_270_587_7e-10;
+    const LOG10_2_HI: f64 = 0.301_029_995_665_885_5;
+    const LOG10_2_LO: f64 = -1.904_312_846_716_427_4e-12;
+    const EXP_MIN: f64 = -746.;
+    const EXP_MAX: f64 = 710.;
+    const EXP2_MIN: f64 = -1076.;
+    const EXP2_MAX: f64 = 1025.;
+    const EXP: &'static [f64] = &[
+        0.500_000_000_000_001_1,
+        0.166_666_666_666_664_13,
+        0.041_666_666_666_530_267,
+        0.008_333_333_333_494_333,
+        0.001_388_888_894_359_769,
+        0.000_198_412_695_067_796_64,
+        2.480_149_313_619_725_4e-5,
+        2.755_758_626_640_848_6e-6,
+        2.763_023_391_495_863_6e-7,
+        2.500_007_229_215_124_4e-8,
+    ];
+    const EXP2: &'static [f64] = &[
+        0.693_147_180_559_945_7,
+        0.240_226_506_959_100_97,
+        0.055_504_108_664_790_39,
+        0.009_618_129_107_607_003,
+        0.001_333_355_815_343_996_6,
+        0.000_154_035_304_415_721_3,
+        1.525_272_738_519_386_8e-5,
+        1.321_544_267_532_982_3e-6,
+        1.018_064_765_923_413_9e-7,
+        7.072_570_412_814_423e-9,
+        4.078_071_929_588_337e-10,
+    ];
+    const LN: &'static [f64] = &[
+        0.666_666_666_666_673_4,
+        0.399_999_999_994_146_76,
+        0.285_714_287_423_876_2,
+        0.222_221_985_731_845_23,
+        0.181_835_643_261_196_75,
+        0.153_140_505_521_127_12,
+        0.147_959_497_005_828_58,
+    ];
+    const LN_LO: f64 = 3.920_298_005_516_351e-17;
+
+    const FRAC_2_PI: f64 = core::f64::consts::FRAC_2_PI;
+    const PI_2_A: f64 = 1.570_796_326_734_125_6;
+    const PI_2_B: f64 = 6.077_100_506_303_966e-11;
+    const PI_2_C: f64 = 2.022_266_248_711_166_5e-21;
+    const PI_2_D: f64 = 8.478_427_660_368_9e-32;
+    const REDUCE_MAX: f64 = 1.6e6;
+    const SIN: &'static [f64] = &[
+        -0.166_666_666_666_666_3,
+        0.008_333_333_333_322_118,
+        -0.000_198_412_698_295_895_42,
+        2.755_731_362_138_634e-6,
+        -2.505_074_776_294_622_2e-8,
+        1.589_623_016_236_066e-10,
+    ];
+    const COS: &'static [f64] = &[
+        0.041_666_666_666_666_595,
+        -0.001_388_888_888_887_305_6,
+        2.480_158_728_885_17e-5,
+        -2.755_731_417_929_608e-7,
+        2.087_570_084_189_222_7e-9,
+        -1.135_853_651_741_480_3e-11,
+    ];
+
+    const TAN_PI_8: f64 = 0.414_213_562_373_095_03;
+    const TAN_3PI_8: f64 = 2.414_213_562_373_095;
+    const FRAC_PI_4_HI: f64 = core::f64::consts::FRAC_PI_4;
+    const FRAC_PI_4_LO: f64 = 3.061_616_997_868_383e-17;
+    const FRAC_PI_2_HI: f64 = core::f64::consts::FRAC_PI_2;
+    const FRAC_PI_2_LO: f64 = 6.123_233_995_736_766e-17;
+    const PI_HI: f64 = core::f64::consts::PI;
+    const PI_LO: f64 = 1.224_646_799_147_353_2e-16;
+    const ATAN: &'static [f64] = &[
+        -0.333_333_333_333_332,
+        0.199_999_999_999_532_47,
+        -0.142_857_142_801_665_87,
+        0.111_111_107_821_562_22,
+        -0.090_908_977_252_848_44,
+        0.076_920_597_179_934_7,
+        -0.066_630_992_099_308_78,
+        0.058_478_593_166_527_99,
+        -0.050_391_906_154_030_13,
+        0.038_062_144_583_190_16,
+        -0.017_905_044_512_109_59,
+    ];
+
+    const TANH_SMALL: f64 = 0.625;
+    const TANH: &'static [f64] = &[
+        -0.333_333_333_333_328_54,
+        0.133_333_333_332_620_9,
+        -0.053_968_253_931_267_73,
+        0.021_869_487_576_095_383,
+        -0.008_863_221_002_641_683,
+        0.003_591_989_173_907_908,
+        -0.001_454_958_878_471_695_7,
+        0.000_586_316_541_935_519_4,
+        -0.000_228_564_214_356_768_18,
+        7.714_437_032_708_599e-5,
+        -1.607_257_147_365_447e-5,
+    ];
+
+    const ERF_MAX: f64 = 6.;
+    const ERF: &'static [f64] = &[
+        0.128_379_167_095_512_56,
+        -0.376_126_389_031_835_2,
+        0.112_837_916_709_441_85,
+        -0.026_866_170_643_111_448,
+        0.005_223_977_606_118_33,
+        -0.000_854_832_592_930_851_5,
+        0.000_120_552_935_767_416_7,
+        -1.492_471_229_925_613e-5,
+        1.644_713_154_029_769_4e-6,
+        -1.620_631_354_017_391_5e-7,
+        1.371_097_952_250_381_2e-8,
+        -7.779_466_963_980_758e-10,
+    ];
+    const ERFC: &'static [f64] = &[
+        0.282_117_753_689_728_8,
+        0.281_550_909_690_189_7,
+        0.252_717_181_828_629_96,
+        0.137_928_814_865_324_82,
+        0.251_306_957_149_267_26,
+        -0.517_351_817_309_637_8,
+        1.060_819_024_058_768_5,
+        -1.765_846_313_740_729_6,
+        1.754_947_346_396_658_3,
+        -1.022_373_759_429_391_8,
+        0.330_716_089_619_443_54,
+        -0.046_532_483_281_331_2,
+    ];
+
+    #[inline]
+    fn sin(self) -> f64 {
+        libm::sin(self)
+    }
+
+    #[inline]
+    fn cos(self) -> f64 {
+        libm::cos(self)
+    }
+
+    #[inline]
+    fn tan(self) -> f64 {
+        libm::tan(self)
+    }
+}
+
+/// A float vector supported by the functions in this module.
+///
+/// This trait is implemented for every vector of `f32` or `f64`.
+pub trait Transcendental:
+    Round
+    + Compare
+    + Bits<Bits = <Self as Transcendental>::Int>
+    + Vector<Scalar = <Self as Transcendental>::Real>
+{
+    #[doc(hidden)]
+    type Real: Constants;
+
+    #[doc(hidden)]
+    type Int: Ops<Scalar = <<Self as Transcendental>::Real as Constants>::Bits> + Shift;
+}
+
+impl<V> Transcendental for V
+where
+    V: Round + Compare + Bits,
+    V::Scalar: Constants,
+    <V as Bits>::Bits: Ops<Scalar = <V::Scalar as Constants>::Bits> + Shift,
+{
+    type Real = V::Scalar;
+    type Int = <V as Bits>::Bits;
+}
+
+#[inline]
+fn splat<V: Vector>(like: V, value: V::Scalar) -> V {
+    V::splat(like.to_token(), value)
+}
+
+/// Evaluates a polynomial with Horner's method.
+#[inline]
+fn polynomial<V: Ops>(x: V, coefficients: &[V::Scalar]) -> V {
+    let (last, rest) = coefficients.split_last().unwrap();
+    rest.iter()
+        .rev()
+        .fold(splat(x, *last), |p, c| p.mul_add(x, splat(x, *c)))
+}
+
+/// Returns `(s, e)`, where `s = a + b` rounded and `s + e = a + b` exactly.
+#[inline]
+fn two_sum<V: Ops>(a: V, b: V) -> (V, V) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/// Returns `(hi, lo)`, where `hi` holds the upper half of the significand of `x` and
+/// `hi + lo = x` exactly.
+#[inline]
+fn split<V: Transcendental>(x: V) -> (V, V) {
+    let c = x * V::Real::SPLIT;
+    let hi = c - (c - x);
+    (hi, x - hi)
+}
+
+/// Returns `(p, e)`, where `p = a * b` rounded and `p + e = a * b` exactly.
+///
+/// The product is computed without `mul_add`, which may not be fused.
+#[inline]
+fn two_product<V: Transcendental>(a: V, b: V) -> (V, V) {
+    let p = a * b;
+    let (a_hi, a_lo) = split(a);
+    let (b_hi, b_lo) = split(b);
+    let e = ((a_hi * b_hi - p) + a_hi * b_lo + a_lo * b_hi) + a_lo * b_lo;
+    (p, e)
+}
+
+/// Returns `(q, e)`, where `q + e` is the quotient `(a + a_lo) / (b + b_lo)` to about twice the
+/// working precision.
+#[inline]
+fn divide<V: Transcendental>(a: V, a_lo: V, b: V, b_lo: V) -> (V, V) {
+    let q = a / b;
+    let (p, p_lo) = two_product(q, b);
+    (q, ((((a - p) - p_lo) + a_lo) - q * b_lo) / b)
+}
+
+/// Returns `2^n` for integral `n` in the range of normal exponents.
+#[inline]
+fn pow2i<V: Transcendental>(n: V) -> V {
+    let shift = splat(n, V::Real::SHIFT);
+    let exponent = ((n + shift).to_bits() + V::Real::BIAS) - shift.to_bits();
+    V::from_bits(exponent.shift_left(V::Real::MANTISSA))
+}
+
+/// Returns `x * 2^n` for integral `n`.
+///
+/// The scale is applied in two steps, so results may be subnormal or overflow.
+#[inline]
+fn ldexp<V: Transcendental>(x: V, n: V) -> V {
+    let half = (n * V::Real::HALF).floor();
+    x * pow2i(half) * pow2i(n - half)
+}
+
+/// Returns `(e, f)` such that positive `x = 2^e * (1 + f)`, with `1 + f` in
+/// `[sqrt(2) / 2, sqrt(2)]`.
+#[inline]
+fn frexp<V: Transcendental>(x: V) -> (V, V) {
+    let subnormal = x.lanes_lt(splat(x, V::Real::MIN_POSITIVE));
+    let x = subnormal.select(x * V::Real::SUBNORMAL_SCALE, x);
+
+    // The biased exponent is the bits shifted right by the width of the mantissa.
+    let bits = x.to_bits();
+    let exponent = bits.shift_right(V::Real::MANTISSA);
+    let one = splat(x, V::Real::ONE).to_bits();
+    let m = V::from_bits((bits - exponent.shift_left(V::Real::MANTISSA)) + one);
+
+    let shift = splat(x, V::Real::SHIFT);
+    let e = V::from_bits(exponent + shift.to_bits()) - shift - V::Real::EXPONENT_BIAS;
+    let e = subnormal.select(e - V::Real::SUBNORMAL_EXPONENT, e);
+
+    let large = m.lanes_gt(splat(x, V::Real::SQRT_2));
+    let m = large.select(m * V::Real::HALF, m);
+    let e = large.select(e + V::Real::ONE, e);
+    (e, m - V::Real::ONE)
+}
+
+/// Returns `(e, f, hfsq, t)` such that positive `ln(x) = e * ln(2) + f - (hfsq - t)`, where
+/// `hfsq = f^2 / 2` and `t` is small.
+#[inline]
+fn ln_parts<V: Transcendental>(x: V) -> (V, V, V, V) {
+    // ln(1 + f) = 2 atanh(s) = f - f^2 / 2 + s * (f^2 / 2 + R(s^2)), with s = f / (2 + f)
+    let (e, f) = frexp(x);
+    let s = f / (f + V::Real::TWO);
+    let z = s * s;
+    let r = z * polynomial(z, V::Real::LN);
+    let hfsq = f * f * V::Real::HALF;
+    (e, f, hfsq, s * (hfsq + r))
+}
+
+/// Returns `(hi, lo)` such that `f - (hfsq - t) = hi + lo`, where `hi` holds half of the
+/// significand bits so that it can be scaled exactly.
+#[inline]
+fn ln_split<V: Transcendental>(f: V, hfsq: V, t: V) -> (V, V) {
+    let hi = split(f - hfsq).0;
+    (hi, ((f - hi) - hfsq) + t)
+}
+
+/// Handles logarithms of zero, negative, infinite and NaN lanes.
+#[inline]
+fn log_special<V: Transcendental>(x: V, y: V) -> V {
+    let zero = V::zeroed(x.to_token());
+    let infinity = splat(x, V::Real::INFINITY);
+    let y = x.lanes_eq(infinity).select(infinity, y);
+    let y = x.lanes_eq(zero).select(-infinity, y);
+    x.lanes_ge(zero).select(y, splat(x, V::Real::NAN))
+}
+
+/// Returns `e^(x + lo)`, where `lo` is much smaller than `x`.
+#[inline]
+fn exp_tail<V: Transcendental>(x: V, lo: V) -> V {
+    // e^x = 2^n * e^r, with r in [-ln(2) / 2, ln(2) / 2]
+    let x = x.clamp(splat(x, V::Real::EXP_MIN), splat(x, V::Real::EXP_MAX));
+    let n = (x * V::Real::LOG2_E).round_ties_even();
+    let r = n.mul_add(-splat(x, V::Real::LN2_HI), x);
+    let r = n.mul_add(-splat(x, V::Real::LN2_LO), r) + lo;
+    let p = (r * r).mul_add(polynomial(r, V::Real::EXP), r) + V::Real::ONE;
+    ldexp(p, n)
+}
+
+/// Returns `(r, lo, q)` such that `|x| = r + lo + q * pi / 2`, with `r` in `[-pi / 4, pi / 4]`.
+///
+/// The first three parts of `pi / 2` are short enough that their products with `q` are exact
+/// while `q` is below `2^12` for `f32` or `2^20` for `f64`, which holds for `|x|` up to
+/// `REDUCE_MAX`.  Larger lanes must be replaced with `reduce_fallback`.
+#[inline]
+fn reduce_pi_2<V: Transcendental>(x: V) -> (V, V, V) {
+    let a = x.abs();
+    let q = (a * V::Real::FRAC_2_PI).round_ties_even();
+    let r = q.mul_add(-splat(x, V::Real::PI_2_A), a);
+    let (r, lo) = two_sum(r, -(q * V::Real::PI_2_B));
+    let (r, lo2) = two_sum(r, -(q * V::Real::PI_2_C));
+    let lo = q.mul_add(-splat(x, V::Real::PI_2_D), lo + lo2);
+    let (r, lo) = two_sum(r, lo);
+    (r, lo, q)
+}
+
+/// Replaces the lanes of `y` where `|x|` exceeds `REDUCE_MAX` with `f(x)`.
+///
+/// These lanes are rare, so they are evaluated lane-wise rather than with a vectorized multiple
+/// precision reduction.
+#[inline]
+fn reduce_fallback<V: Transcendental>(x: V, y: V, f: impl Fn(V::Real) -> V::Real) -> V {
+    let large = x.abs().lanes_gt(splat(x, V::Real::REDUCE_MAX));
+    if !large.any() {
+        return y;
+    }
+    let mut y = y;
+    for (lane, (y, x)) in y.as_slice_mut().iter_mut().zip(x.as_slice()).enumerate() {
+        if large.test(lane) {
+            *y = f(*x);
+        }
+    }
+    y
+}
+
+/// Returns `(sin(r + lo), cos(r + lo))` for `r` in `[-pi / 4, pi / 4]` and `lo` much smaller
+/// than `r`.
+#[inline]
+fn sincos_reduced<V: Transcendental>(r: V, lo: V) -> (V, V) {
+    let one = splat(r, V::Real::ONE);
+    let z = r * r;
+    let hz = z * V::Real::HALF;
+    let sin = (r * z).mul_add(polynomial(z, V::Real::SIN), lo - hz * lo) + r;
+
+    // cos(r) = 1 - z / 2 + z^2 C(z), with the rounding error of 1 - z / 2 recovered
+    let w = one - hz;
+    let tail = (z * z).mul_add(polynomial(z, V::Real::COS), -(r * lo));
+    let cos = w + (((one - w) - hz) + tail);
+    (sin, cos)
+}
+
+/// Returns whether each lane of an integral `q` is odd.
+#[inline]
+fn is_odd<V: Transcendental>(q: V) -> V::Mask {
+    let half = q * V::Real::HALF;
+    half.floor().lanes_ne(half)
+}
+
+/// Returns `atan(a)` for `a` that is positive or NaN.
+#[inline]
+fn atan_positive<V: Transcendental>(a: V) -> V {
+    let zero = V::zeroed(a.to_token());
+    let one = splat(a, V::Real::ONE);
+
+    // Reduce to |t| <= tan(pi / 8), using atan(a) = pi / 4 + atan((a - 1) / (a + 1)) and
+    // atan(a) = pi / 2 - atan(1 / a).
+    let mid = a.lanes_gt(splat(a, V::Real::TAN_PI_8));
+    let large = a.lanes_gt(splat(a, V::Real::TAN_3PI_8));
+    let (num, num_lo) = two_sum(a, -one);
+    let (den, den_lo) = two_sum(a, one);
+    let (q, q_lo) = divide(num, num_lo, den, den_lo);
+    let t = mid.select(q, a);
+    let t = large.select(-(one / a), t);
+    let t_lo = mid.select(q_lo, zero);
+    let t_lo = large.select(zero, t_lo);
+    let hi = mid.select(splat(a, V::Real::FRAC_PI_4_HI), zero);
+    let hi = large.select(splat(a, V::Real::FRAC_PI_2_HI), hi);
+    let lo = mid.select(splat(a, V::Real::FRAC_PI_4_LO), zero);
+    let lo = large.select(splat(a, V::Real::FRAC_PI_2_LO), lo);
+
+    let z = t * t;
+    hi + ((t * z).mul_add(polynomial(z, V::Real::ATAN), lo + t_lo) + t)
+}
+
+/// Returns `e^x` for each lane.
+///
+/// The maximum error is 1.5 ULP for `f32` and 1 ULP for `f64`.
+#[inline]
+pub fn exp<V: Transcendental>(x: V) -> V {
+    exp_tail(x, V::zeroed(x.to_token()))
+}
+
+/// Returns `2^x` for each lane.
+///
+/// The maximum error is 1.5 ULP.
+#[inline]
+pub fn exp2<V: Transcendental>(x: V) -> V {
+    // 2^x = 2^n * 2^r, with r in [-1 / 2, 1 / 2]
+    let x = x.clamp(splat(x, V::Real::EXP2_MIN), splat(x, V::Real::EXP2_MAX));
+    let n = x.round_ties_even();
+    let r = x - n;
+    ldexp(r.mul_add(polynomial(r, V::Real::EXP2), splat(x, V::Real::ONE)), n)
+}
+
+/// Returns the natural logarithm of each lane.
+///
+/// The maximum error is 1 ULP.
+#[inline]
+pub fn ln<V: Transcendental>(x: V) -> V {
+    let (e, f, hfsq, t) = ln_parts(x);
+    let y = e.mul_add(splat(x, V::Real::LN2_LO), f - (hfsq - t));
+    log_special(x, e.mul_add(splat(x, V::Real::LN2_HI), y))
+}
+
+/// Returns the base 2 logarithm of each lane.
+///
+/// The maximum error is 1 ULP.
+#[inline]
+pub fn log2<V: Transcendental>(x: V) -> V {
+    // Scale the logarithm by log2(e) in two parts, with the leading product exact.
+    let (e, f, hfsq, t) = ln_parts(x);
+    let (hi, lo) = ln_split(f, hfsq, t);
+    let y_hi = hi * V::Real::LOG2_E_HI;
+    let y_lo = (lo + hi).mul_add(splat(x, V::Real::LOG2_E_LO), lo * V::Real::LOG2_E_HI);
+    let w = e + y_hi;
+    let y_lo = y_lo + ((e - w) + y_hi);
+    log_special(x, y_lo + w)
+}
+
+/// Returns the base 10 logarithm of each lane.
+///
+/// The maximum error is 1 ULP.
+#[inline]
+pub fn log10<V: Transcendental>(x: V) -> V {
+    // Scale the logarithm by log10(e) in two parts, with the leading products exact.
+    let (e, f, hfsq, t) = ln_parts(x);
+    let (hi, lo) = ln_split(f, hfsq, t);
+    let y_hi = hi * V::Real::LOG10_E_HI;
+    let y_lo = (lo + hi).mul_add(splat(x, V::Real::LOG10_E_LO), lo * V::Real::LOG10_E_HI);
+    let y_lo = e.mul_add(splat(x, V::Real::LOG10_2_LO), y_lo);
+    let e_hi = e * V::Real::LOG10_2_HI;
+    let w = e_hi + y_hi;
+    let y_lo = y_lo + ((e_hi - w) + y_hi);
+    log_special(x, y_lo + w)
+}
+
+/// Returns the sine and cosine of each lane.
+///
+/// See [`sin`](fn.sin.html) and [`cos`](fn.cos.html) for the error bounds.
+#[inline]
+pub fn sincos<V: Transcendental>(x: V) -> (V, V) {
+    // sin(r + q * pi / 2) cycles through sin(r), cos(r), -sin(r) and -cos(r)
+    let (r, lo, q) = reduce_pi_2(x);
+    let (s, c) = sincos_reduced(r, lo);
+    let swap = is_odd(q);
+    let sin = swap.select(c, s);
+    let cos = swap.select(s, c);
+    let sin = is_odd((q * V::Real::HALF).floor()).select(-sin, sin);
+    let cos = is_odd(((q + V::Real::ONE) * V::Real::HALF).floor()).select(-cos, cos);
+    let sin = sin * splat(x, V::Real::ONE).copysign(x);
+    (
+        reduce_fallback(x, sin, V::Real::sin),
+        reduce_fallback(x, cos, V::Real::cos),
+    )
+}
+
+/// Returns the sine of each lane.
+///
+/// The maximum error is 1 ULP.
+#[inline]
+pub fn sin<V: Transcendental>(x: V) -> V {
+    sincos(x).0
+}
+
+/// Returns the cosine of each lane.
+///
+/// The maximum error is 1 ULP.
+#[inline]
+pub fn cos<V: Transcendental>(x: V) -> V {
+    sincos(x).1
+}
+
+/// Returns the tangent of each lane.
+///
+/// The maximum error is 2.5 ULP.
+#[inline]
+pub fn tan<V: Transcendental>(x: V) -> V {
+    // tan(r + q * pi / 2) alternates between tan(r) and -1 / tan(r)
+    let (r, lo, q) = reduce_pi_2(x);
+    let (s, c) = sincos_reduced(r, lo);
+    let tan = is_odd(q).select(-c / s, s / c);
+    reduce_fallback(x, tan * splat(x, V::Real::ONE).copysign(x), V::Real::tan)
+}
+
+/// Returns the arctangent of each lane.
+///
+/// The maximum error is 1 ULP.
+#[inline]
+pub fn atan<V: Transcendental>(x: V) -> V {
+    atan_positive(x.abs()).copysign(x)
+}
+
+/// Returns the four quadrant arctangent of `y` and `x` for each lane.
+///
+/// The maximum error is 2 ULP.
+#[inline]
+pub fn atan2<V: Transcendental>(y: V, x: V) -> V {
+    let zero = V::zeroed(x.to_token());
+    let one = splat(x, V::Real::ONE);
+    let infinity = splat(x, V::Real::INFINITY);
+
+    // Compute the angle in [0, pi / 4] from the ratio of the smaller and larger magnitudes.
+    let ax = x.abs();
+    let ay = y.abs();
+    let min = ax.min(ay);
+    let max = ax.max(ay);
+    let t = min / max;
+    let t = max.lanes_eq(zero).select(zero, t);
+    let t = min.lanes_eq(infinity).select(one, t);
+    let a = atan_positive(t);
+
+    let a = ay.lanes_gt(ax).select(
+        (splat(x, V::Real::FRAC_PI_2_HI) - a) + V::Real::FRAC_PI_2_LO,
+        a,
+    );
+    let a = one.copysign(x).lanes_lt(zero).select(
+        (splat(x, V::Real::PI_HI) - a) + V::Real::PI_LO,
+        a,
+    );
+    a.copysign(y)
+}
+
+/// Returns `x` raised to the power `y` for each lane.
+///
+/// The maximum error is 2 ULP.
+#[inline]
+pub fn pow<V: Transcendental>(x: V, y: V) -> V {
+    let zero = V::zeroed(x.to_token());
+    let one = splat(x, V::Real::ONE);
+    let infinity = splat(x, V::Real::INFINITY);
+    let a = x.abs();
+
+    // Compute ln(|x|) = e * ln(2) + 2s + s^3 * c(s^2) as an unevaluated sum hi + lo, carrying
+    // the rounding errors of s, s^3 and the leading coefficient of c.
+    let (e, f) = frexp(a);
+    let u = f + V::Real::TWO;
+    let u_lo = (splat(x, V::Real::TWO) - u) + f;
+    let (s, s_lo) = divide(f, zero, u, u_lo);
+    let (z, z_lo) = two_product(s, s);
+    let (s3, s3_lo) = two_product(s, z);
+    let s3_lo = s.mul_add(z_lo, s3_lo);
+    let w = z * polynomial(z, &V::Real::LN[1..]);
+    let (c, c_lo) = two_sum(splat(x, V::Real::LN[0]), w);
+    let c_lo = c_lo + V::Real::LN_LO;
+    let (q, q_lo) = two_product(s3, c);
+    let q_lo = s3_lo.mul_add(c, s3.mul_add(c_lo, q_lo));
+    let (hi, lo) = two_sum(e * V::Real::LN2_HI, s + s);
+    let (hi, lo2) = two_sum(hi, q);
+    let s_lo = s_lo + s_lo;
+    let lo = lo + (lo2 + (s_lo.mul_add(z, s_lo) + e.mul_add(splat(x, V::Real::LN2_LO), q_lo)));
+    let (hi, lo) = two_sum(hi, lo);
+    let hi = a.lanes_eq(zero).select(-infinity, hi);
+    let hi = a.lanes_eq(infinity).select(infinity, hi);
+
+    // Compute y * ln(|x|), discarding the low part when it doesn't affect the result.
+    let (p, p_lo) = two_product(y, hi);
+    let p_lo = y.mul_add(lo, p_lo);
+    let p_lo = p.abs().lanes_lt(-splat(x, V::Real::EXP_MIN)).select(p_lo, zero);
+    let magnitude = exp_tail(p, p_lo);
+    let magnitude = a.lanes_eq(one).select(one, magnitude);
+
+    // Negative bases are negated by odd integer powers and undefined for finite non-integer
+    // powers.
+    let integer = y.floor().lanes_eq(y);
+    let half = y * V::Real::HALF;
+    let sign = half.floor().lanes_eq(half).select(one, one.copysign(x));
+    let result = magnitude * integer.select(sign, one);
+    let finite = a.lanes_lt(infinity).select(x, zero);
+    let result = integer.select(
+        result,
+        finite.lanes_lt(zero).select(splat(x, V::Real::NAN), result),
+    );
+
+    // NaN bases propagate, except that 1^y and x^0 are always 1.
+    let result = x.lanes_eq(x).select(result, x);
+    let result = x.lanes_eq(one).select(one, result);
+    y.lanes_eq(zero).select(one, result)
+}
+
+/// Returns the hyperbolic tangent of each lane.
+///
+/// The maximum error is 1.5 ULP.
+#[inline]
+pub fn tanh<V: Transcendental>(x: V) -> V {
+    // tanh(x) = 1 - 2 / (e^(2x) + 1) for large x
+    let one = splat(x, V::Real::ONE);
+    let a = x.abs();
+    let z = x * x;
+    let small = (x * z)
+        .mul_add(polynomial(z, V::Real::TANH), x)
+        .copysign(x);
+    let large = (one - splat(x, V::Real::TWO) / (exp(a + a) + one)).copysign(x);
+    a.lanes_lt(splat(x, V::Real::TANH_SMALL))
+        .select(small, large)
+}
+
+/// Returns the error function of each lane.
+///
+/// The maximum error is 1.5 ULP.
+#[inline]
+pub fn erf<V: Transcendental>(x: V) -> V {
+    let one = splat(x, V::Real::ONE);
+    let a = x.abs();
+    let small = x.mul_add(polynomial(x * x, V::Real::ERF), x);
+
+    // erf(x) = 1 - e^(-x^2) * t * P(t) for large x, with t = 1 / (1 + x / 2)
+    let t = splat(x, V::Real::TWO) / (a + V::Real::TWO);
+    let (hi, lo) = two_product(a, a);
+    let large = one - exp_tail(-hi, -lo) * t * polynomial(t, V::Real::ERFC);
+    let large = a
+        .lanes_ge(splat(x, V::Real::ERF_MAX))
+        .select(one, large)
+        .copysign(x);
+    a.lanes_lt(one).select(small, large)
+}
@@ -64,18 +64,78 @@ where
     }
 }
 
+/// A mutable pointer to a vector.
+pub trait PointerMut<Token, Width>: Pointer<Token, Width>
+where
+    Token: crate::arch::Token,
+    Width: width::Width,
+{
+    /// Write a vector to a pointer.
+    ///
+    /// # Safety
+    /// See [`write_ptr`](../vector/trait.Vector.html#method.write_ptr).
+    unsafe fn vector_write(self, token: Token, vector: Self::Vector);
+
+    /// Write a vector to a vector-aligned pointer.
+    ///
+    /// # Safety
+    /// See [`write_aligned_ptr`](../vector/trait.Vector.html#method.write_aligned_ptr).
+    unsafe fn vector_write_aligned(self, token: Token, vector: Self::Vector);
+}
+
+impl<T, Token, Width> PointerMut<Token, Width> for *mut T
+where
+    T: Scalar<Token, Width>,
+    Token: crate::arch::Token,
+    Width: width::Width,
+{
+    #[inline]
+    unsafe fn vector_write(self, #[allow(unused_variables)] token: Token, vector: Self::Vector) {
+        vector.write_ptr(self)
+    }
+
+    #[inline]
+    unsafe fn vector_write_aligned(
+        self,
+        #[allow(unused_variables)] token: Token,
+        vector: Self::Vector,
+    ) {
+        vector.write_aligned_ptr(self)
+    }
+}
+
+impl<T, Token> Native<Token> for *const T
+where
+    T: Native<Token>,
+{
+    type Width = T::Width;
+}
+
+impl<T, Token> Native<Token> for *mut T
+where
+    T: Native<Token>,
+{
+    type Width = T::Width;
+}
+
 macro_rules! pointer_impl {
     {
         $width:literal,
+        [$($generics:tt)*],
         $width_type:ty,
         $read_unaligned:ident,
-        $read_aligned:ident
+        $read_aligned:ident,
+        $write_unaligned:ident,
+        $write_aligned:ident
     } => {
         #[doc = "Read a vector with "]
         #[doc = $width]
         #[doc = " from a pointer.\n\n# Safety\nSee [`read_ptr`](../vector/trait.Vector.html#method.read_ptr)."]
         #[inline]
-        unsafe fn $read_unaligned(self, token: Token) -> <Self as Pointer<Token, $width_type>>::Vector {
+        unsafe fn $read_unaligned $($generics)* (self, token: Token) -> <Self as Pointer<Token, $width_type>>::Vector
+        where
+            Self: Pointer<Token, $width_type>,
+        {
             <Self as Pointer<Token, $width_type>>::vector_read(self, token)
         }
 
@@ -83,9 +143,34 @@ macro_rules! pointer_impl {
         #[doc = $width]
         #[doc = " from a vector-aligned pointer.\n\n# Safety\nSee [`read_aligned_ptr`](../vector/trait.Vector.html#method.read_aligned_ptr)."]
         #[inline]
-        unsafe fn $read_aligned(self, token: Token) -> <Self as Pointer<Token, $width_type>>::Vector {
+        unsafe fn $read_aligned $($generics)* (self, token: Token) -> <Self as Pointer<Token, $width_type>>::Vector
+        where
+            Self: Pointer<Token, $width_type>,
+        {
             <Self as Pointer<Token, $width_type>>::vector_read_aligned(self, token)
         }
+
+        #[doc = "Write a vector with "]
+        #[doc = $width]
+        #[doc = " to a pointer.\n\n# Safety\nSee [`write_ptr`](../vector/trait.Vector.html#method.write_ptr)."]
+        #[inline]
+        unsafe fn $write_unaligned $($generics)* (self, token: Token, vector: <Self as Pointer<Token, $width_type>>::Vector)
+        where
+            Self: PointerMut<Token, $width_type>,
+        {
+            <Self as PointerMut<Token, $width_type>>::vector_write(self, token, vector)
+        }
+
+        #[doc = "Write a vector with "]
+        #[doc = $width]
+        #[doc = " to a vector-aligned pointer.\n\n# Safety\nSee [`write_aligned_ptr`](../vector/trait.Vector.html#method.write_aligned_ptr)."]
+        #[inline]
+        unsafe fn $write_aligned $($generics)* (self, token: Token, vector: <Self as Pointer<Token, $width_type>>::Vector)
+        where
+            Self: PointerMut<Token, $width_type>,
+        {
+            <Self as PointerMut<Token, $width_type>>::vector_write_aligned(self, token, vector)
+        }
     }
 }
 
@@ -96,15 +181,22 @@ pub trait PointerExt<Token>:
     + Pointer<Token, width::W2>
     + Pointer<Token, width::W4>
     + Pointer<Token, width::W8>
+    + Pointer<Token, width::W16>
+    + Pointer<Token, width::W32>
+    + Pointer<Token, width::W64>
     + Pointer<Token, NativeWidth<Self, Token>>
 where
     Token: crate::arch::Token,
 {
-    pointer_impl! { "the native number of lanes", <Self as Native<Token>>::Width, vector_read_native, vector_read_aligned_native }
-    pointer_impl! { "1 lane",  width::W1, vector_read1, vector_read1_aligned }
-    pointer_impl! { "2 lanes", width::W2, vector_read2, vector_read2_aligned }
-    pointer_impl! { "4 lanes", width::W4, vector_read4, vector_read4_aligned }
-    pointer_impl! { "8 lanes", width::W8, vector_read8, vector_read8_aligned }
+    pointer_impl! { "the native number of lanes", [], <Self as Native<Token>>::Width, vector_read_native, vector_read_aligned_native, vector_write_native, vector_write_aligned_native }
+    pointer_impl! { "1 lane",   [], width::W1, vector_read1, vector_read1_aligned, vector_write1, vector_write1_aligned }
+    pointer_impl! { "2 lanes",  [], width::W2, vector_read2, vector_read2_aligned, vector_write2, vector_write2_aligned }
+    pointer_impl! { "4 lanes",  [], width::W4, vector_read4, vector_read4_aligned, vector_write4, vector_write4_aligned }
+    pointer_impl! { "8 lanes",  [], width::W8, vector_read8, vector_read8_aligned, vector_write8, vector_write8_aligned }
+    pointer_impl! { "16 lanes", [], width::W16, vector_read16, vector_read16_aligned, vector_write16, vector_write16_aligned }
+    pointer_impl! { "32 lanes", [], width::W32, vector_read32, vector_read32_aligned, vector_write32, vector_write32_aligned }
+    pointer_impl! { "64 lanes", [], width::W64, vector_read64, vector_read64_aligned, vector_write64, vector_write64_aligned }
+    pointer_impl! { "`N` lanes", [<const N: usize>], width::Lanes<N>, vector_read_lanes, vector_read_lanes_aligned, vector_write_lanes, vector_write_lanes_aligned }
 }
 
 impl<T, Token> PointerExt<Token> for T
@@ -114,6 +206,9 @@ where
         + Pointer<Token, width::W2>
         + Pointer<Token, width::W4>
         + Pointer<Token, width::W8>
+        + Pointer<Token, width::W16>
+        + Pointer<Token, width::W32>
+        + Pointer<Token, width::W64>
         + Pointer<Token, NativeWidth<Self, Token>>,
     Token: crate::arch::Token,
 {
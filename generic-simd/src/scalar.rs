@@ -30,6 +30,7 @@ where
 macro_rules! scalar_impl {
     {
         $width:literal,
+        [$($generics:tt)*],
         $width_type:ty,
         $zeroed:ident,
         $splat:ident
@@ -38,7 +39,10 @@ macro_rules! scalar_impl {
         #[doc = $width]
         #[doc = " set to zero.\n\nSee [`zeroed`](../vector/trait.Vector.html#method.zeroed)."]
         #[inline]
-        fn $zeroed(token: Token) -> <Self as Scalar<Token, $width_type>>::Vector {
+        fn $zeroed $($generics)* (token: Token) -> <Self as Scalar<Token, $width_type>>::Vector
+        where
+            Self: Scalar<Token, $width_type>,
+        {
            <Self as Scalar<Token, $width_type>>::zeroed(token.into())
         }
 
@@ -46,7 +50,10 @@ macro_rules! scalar_impl {
         #[doc = $width]
         #[doc = ".\n\nSee [`splat`](../vector/trait.Vector.html#tymethod.splat)."]
         #[inline]
-        fn $splat(self, token: Token) -> <Self as Scalar<Token, $width_type>>::Vector {
+        fn $splat $($generics)* (self, token: Token) -> <Self as Scalar<Token, $width_type>>::Vector
+        where
+            Self: Scalar<Token, $width_type>,
+        {
             <Self as Scalar<Token, $width_type>>::splat(self, token.into())
         }
     }
@@ -59,15 +66,22 @@ pub trait ScalarExt<Token>:
     + self::Scalar<Token, width::W2>
     + self::Scalar<Token, width::W4>
     + self::Scalar<Token, width::W8>
+    + self::Scalar<Token, width::W16>
+    + self::Scalar<Token, width::W32>
+    + self::Scalar<Token, width::W64>
     + self::Scalar<Token, NativeWidth<Self, Token>>
 where
     Token: crate::arch::Token + From<Token> + Into<Token>,
 {
-    scalar_impl! { "the native number of lanes", <Self as Native<Token>>::Width, zeroed_native, splat_native }
-    scalar_impl! { "1 lane",  width::W1, zeroed1, splat1 }
-    scalar_impl! { "2 lanes", width::W2, zeroed2, splat2 }
-    scalar_impl! { "4 lanes", width::W4, zeroed4, splat4 }
-    scalar_impl! { "8 lanes", width::W8, zeroed8, splat8 }
+    scalar_impl! { "the native number of lanes", [], <Self as Native<Token>>::Width, zeroed_native, splat_native }
+    scalar_impl! { "1 lane",   [], width::W1, zeroed1, splat1 }
+    scalar_impl! { "2 lanes",  [], width::W2, zeroed2, splat2 }
+    scalar_impl! { "4 lanes",  [], width::W4, zeroed4, splat4 }
+    scalar_impl! { "8 lanes",  [], width::W8, zeroed8, splat8 }
+    scalar_impl! { "16 lanes", [], width::W16, zeroed16, splat16 }
+    scalar_impl! { "32 lanes", [], width::W32, zeroed32, splat32 }
+    scalar_impl! { "64 lanes", [], width::W64, zeroed64, splat64 }
+    scalar_impl! { "`N` lanes", [<const N: usize>], width::Lanes<N>, zeroed_lanes, splat_lanes }
 }
 
 impl<Token, Scalar> ScalarExt<Token> for Scalar
@@ -78,6 +92,9 @@ where
         + self::Scalar<Token, width::W2>
         + self::Scalar<Token, width::W4>
         + self::Scalar<Token, width::W8>
+        + self::Scalar<Token, width::W16>
+        + self::Scalar<Token, width::W32>
+        + self::Scalar<Token, width::W64>
         + self::Scalar<Token, NativeWidth<Self, Token>>,
 {
 }
@@ -1,5 +1,9 @@
 use crate::arch;
-use crate::vector::Vector;
+use crate::math::Shift;
+use crate::vector::{
+    Bits, Bitwise, Compare, Convert, ConvertRound, Float, GatherImpl, Mask, MulAddImpl, Reduce,
+    ReduceOrd, Round, Select, Signed, Sqrt, Vector,
+};
 use core::marker::PhantomData;
 
 #[cfg(feature = "complex")]
@@ -30,6 +34,92 @@ where
     fn splat(token: Self::Token, from: Self::Scalar) -> Self {
         Self(Underlying::splat(token.into(), from), PhantomData)
     }
+
+    #[inline]
+    fn read_partial(token: Self::Token, from: &[Self::Scalar], fill: Self::Scalar) -> Self {
+        Self(
+            Underlying::read_partial(token.into(), from, fill),
+            PhantomData,
+        )
+    }
+
+    #[inline]
+    fn write_partial(self, to: &mut [Self::Scalar]) {
+        self.0.write_partial(to)
+    }
+
+    #[inline]
+    unsafe fn scatter_unchecked(self, to: &mut [Self::Scalar], indices: &[usize]) {
+        self.0.scatter_unchecked(to, indices)
+    }
+
+    #[inline]
+    unsafe fn write_strided_unchecked(self, to: &mut [Self::Scalar], stride: usize) {
+        self.0.write_strided_unchecked(to, stride)
+    }
+
+    #[inline]
+    unsafe fn deinterleave2_unchecked(token: Self::Token, from: &[Self::Scalar]) -> (Self, Self) {
+        let (a, b) = Underlying::deinterleave2_unchecked(token.into(), from);
+        (Self(a, PhantomData), Self(b, PhantomData))
+    }
+
+    #[inline]
+    unsafe fn deinterleave3_unchecked(
+        token: Self::Token,
+        from: &[Self::Scalar],
+    ) -> (Self, Self, Self) {
+        let (a, b, c) = Underlying::deinterleave3_unchecked(token.into(), from);
+        (
+            Self(a, PhantomData),
+            Self(b, PhantomData),
+            Self(c, PhantomData),
+        )
+    }
+
+    #[inline]
+    unsafe fn deinterleave4_unchecked(
+        token: Self::Token,
+        from: &[Self::Scalar],
+    ) -> (Self, Self, Self, Self) {
+        let (a, b, c, d) = Underlying::deinterleave4_unchecked(token.into(), from);
+        (
+            Self(a, PhantomData),
+            Self(b, PhantomData),
+            Self(c, PhantomData),
+            Self(d, PhantomData),
+        )
+    }
+
+    #[inline]
+    unsafe fn interleave2_unchecked(self, b: Self, to: &mut [Self::Scalar]) {
+        self.0.interleave2_unchecked(b.0, to)
+    }
+
+    #[inline]
+    unsafe fn interleave3_unchecked(self, b: Self, c: Self, to: &mut [Self::Scalar]) {
+        self.0.interleave3_unchecked(b.0, c.0, to)
+    }
+
+    #[inline]
+    unsafe fn interleave4_unchecked(self, b: Self, c: Self, d: Self, to: &mut [Self::Scalar]) {
+        self.0.interleave4_unchecked(b.0, c.0, d.0, to)
+    }
+
+    #[inline]
+    fn reverse(self) -> Self {
+        Self(self.0.reverse(), PhantomData)
+    }
+
+    #[inline]
+    fn rotate_lanes_left_impl(self, n: usize) -> Self {
+        Self(self.0.rotate_lanes_left_impl(n), PhantomData)
+    }
+
+    #[inline]
+    fn broadcast_lane_impl(self, lane: usize) -> Self {
+        Self(self.0.broadcast_lane_impl(lane), PhantomData)
+    }
 }
 
 impl<Underlying, Scalar, Token> AsRef<[Scalar]> for ShimToken<Underlying, Scalar, Token>
@@ -140,6 +230,138 @@ implement! { @op_assign SubAssign::sub_assign }
 implement! { @op_assign MulAssign::mul_assign }
 implement! { @op_assign DivAssign::div_assign }
 
+macro_rules! implement_bitwise {
+    {
+        $trait:ident :: $func:ident
+    } => {
+        impl<Underlying, Scalar, Token> core::ops::$trait<Self> for ShimToken<Underlying, Scalar, Token>
+        where
+            Underlying: Copy + core::ops::$trait<Underlying, Output=Underlying>,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $func(self, rhs: Self) -> Self {
+                Self((self.0).$func(rhs.0), PhantomData)
+            }
+        }
+    }
+}
+
+implement_bitwise! { BitAnd::bitand }
+implement_bitwise! { BitOr::bitor }
+implement_bitwise! { BitXor::bitxor }
+
+impl<Underlying, Scalar, Token> core::ops::Not for ShimToken<Underlying, Scalar, Token>
+where
+    Underlying: Copy + core::ops::Not<Output = Underlying>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        Self(!self.0, PhantomData)
+    }
+}
+
+impl<Underlying, Scalar, Token> Bitwise for ShimToken<Underlying, Scalar, Token>
+where
+    Underlying: Bitwise<Scalar = Scalar>,
+    Scalar: Copy,
+    Token: arch::Token + Into<<Underlying as Vector>::Token>,
+{
+    #[inline]
+    fn and_not(self, other: Self) -> Self {
+        Self(self.0.and_not(other.0), PhantomData)
+    }
+}
+
+impl<Underlying, Scalar, Token> Shift for ShimToken<Underlying, Scalar, Token>
+where
+    Underlying: Shift,
+    Scalar: Copy,
+    Token: Copy,
+{
+    #[inline]
+    fn shift_left(self, count: u32) -> Self {
+        Self(self.0.shift_left(count), PhantomData)
+    }
+
+    #[inline]
+    fn shift_right(self, count: u32) -> Self {
+        Self(self.0.shift_right(count), PhantomData)
+    }
+}
+
+impl<Underlying, Token> Mask for ShimToken<Underlying, bool, Token>
+where
+    Underlying: Mask,
+    Token: arch::Token + Into<<Underlying as Mask>::Token>,
+{
+    type Token = Token;
+    type Width = <Underlying as Mask>::Width;
+
+    #[inline]
+    fn splat(token: Self::Token, value: bool) -> Self {
+        Self(Underlying::splat(token.into(), value), PhantomData)
+    }
+
+    #[inline]
+    fn test(&self, lane: usize) -> bool {
+        self.0.test(lane)
+    }
+
+    #[inline]
+    fn any(&self) -> bool {
+        self.0.any()
+    }
+
+    #[inline]
+    fn all(&self) -> bool {
+        self.0.all()
+    }
+}
+
+impl<Underlying, Vector, Scalar, Token> Select<ShimToken<Vector, Scalar, Token>>
+    for ShimToken<Underlying, bool, Token>
+where
+    Underlying: Select<Vector>,
+    Token: arch::Token + Into<<Underlying as Mask>::Token>,
+{
+    #[inline]
+    fn select(
+        self,
+        a: ShimToken<Vector, Scalar, Token>,
+        b: ShimToken<Vector, Scalar, Token>,
+    ) -> ShimToken<Vector, Scalar, Token> {
+        ShimToken(self.0.select(a.0, b.0), PhantomData)
+    }
+}
+
+macro_rules! implement_compare {
+    {
+        $($func:ident),*
+    } => {
+        impl<Underlying, Scalar, Token> Compare for ShimToken<Underlying, Scalar, Token>
+        where
+            Underlying: Compare<Scalar = Scalar>,
+            Scalar: Copy,
+            Token: arch::Token + Into<<Underlying as Vector>::Token>,
+        {
+            type Mask = ShimToken<<Underlying as Compare>::Mask, bool, Token>;
+
+            $(
+            #[inline]
+            fn $func(self, other: Self) -> Self::Mask {
+                ShimToken((self.0).$func(other.0), PhantomData)
+            }
+            )*
+        }
+    }
+}
+
+implement_compare! { lanes_eq, lanes_ne, lanes_lt, lanes_le, lanes_gt, lanes_ge }
+
 impl<Underlying, Scalar, Token> core::ops::Neg for ShimToken<Underlying, Scalar, Token>
 where
     Underlying: Copy + core::ops::Neg<Output = Underlying>,
@@ -152,6 +374,233 @@ where
     }
 }
 
+impl<Underlying, Scalar, Token> Float for ShimToken<Underlying, Scalar, Token>
+where
+    Self: Signed,
+    Underlying: Float<Scalar = Scalar>,
+    Scalar: Copy,
+    Token: arch::Token + Into<<Underlying as Vector>::Token>,
+{
+    #[inline]
+    fn min(self, other: Self) -> Self {
+        Self(self.0.min(other.0), PhantomData)
+    }
+
+    #[inline]
+    fn max(self, other: Self) -> Self {
+        Self(self.0.max(other.0), PhantomData)
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        Self(self.0.abs(), PhantomData)
+    }
+
+    #[inline]
+    fn copysign(self, sign: Self) -> Self {
+        Self(self.0.copysign(sign.0), PhantomData)
+    }
+}
+
+impl<Underlying, Scalar, Token> Sqrt for ShimToken<Underlying, Scalar, Token>
+where
+    Self: Float,
+    Underlying: Sqrt<Scalar = Scalar>,
+    Scalar: Copy,
+    Token: arch::Token + Into<<Underlying as Vector>::Token>,
+{
+    #[inline]
+    fn sqrt(self) -> Self {
+        Self(self.0.sqrt(), PhantomData)
+    }
+
+    #[inline]
+    fn recip(self) -> Self {
+        Self(self.0.recip(), PhantomData)
+    }
+
+    #[inline]
+    fn rsqrt(self) -> Self {
+        Self(self.0.rsqrt(), PhantomData)
+    }
+
+    #[inline]
+    fn recip_estimate(self) -> Self {
+        Self(self.0.recip_estimate(), PhantomData)
+    }
+
+    #[inline]
+    fn rsqrt_estimate(self) -> Self {
+        Self(self.0.rsqrt_estimate(), PhantomData)
+    }
+
+    #[inline]
+    fn recip_refined(self) -> Self {
+        Self(self.0.recip_refined(), PhantomData)
+    }
+
+    #[inline]
+    fn rsqrt_refined(self) -> Self {
+        Self(self.0.rsqrt_refined(), PhantomData)
+    }
+}
+
+impl<Underlying, Scalar, Token> Round for ShimToken<Underlying, Scalar, Token>
+where
+    Self: Float,
+    Underlying: Round<Scalar = Scalar>,
+    Scalar: Copy,
+    Token: arch::Token + Into<<Underlying as Vector>::Token>,
+{
+    #[inline]
+    fn floor(self) -> Self {
+        Self(self.0.floor(), PhantomData)
+    }
+
+    #[inline]
+    fn ceil(self) -> Self {
+        Self(self.0.ceil(), PhantomData)
+    }
+
+    #[inline]
+    fn round(self) -> Self {
+        Self(self.0.round(), PhantomData)
+    }
+
+    #[inline]
+    fn round_ties_even(self) -> Self {
+        Self(self.0.round_ties_even(), PhantomData)
+    }
+
+    #[inline]
+    fn trunc(self) -> Self {
+        Self(self.0.trunc(), PhantomData)
+    }
+}
+
+// The underlying vector may use the wrapping token's features, such as fused multiply-add.
+impl<Underlying, Scalar, Token, MulAddToken> MulAddImpl<MulAddToken>
+    for ShimToken<Underlying, Scalar, Token>
+where
+    Underlying: MulAddImpl<MulAddToken>,
+{
+    #[inline]
+    fn mul_add_impl(self, a: Self, b: Self) -> Self {
+        Self(self.0.mul_add_impl(a.0, b.0), PhantomData)
+    }
+
+    #[inline]
+    fn mul_sub_impl(self, a: Self, b: Self) -> Self {
+        Self(self.0.mul_sub_impl(a.0, b.0), PhantomData)
+    }
+}
+
+// The underlying vector may use the wrapping token's features, such as hardware gathers.
+impl<Underlying, Scalar, Token, GatherToken> GatherImpl<GatherToken>
+    for ShimToken<Underlying, Scalar, Token>
+where
+    Underlying: Vector<Scalar = Scalar> + GatherImpl<GatherToken>,
+    Scalar: Copy,
+    Token: arch::Token + Into<<Underlying as Vector>::Token>,
+{
+    #[inline]
+    unsafe fn gather_impl(token: Self::Token, from: &[Self::Scalar], indices: &[usize]) -> Self {
+        Self(
+            Underlying::gather_impl(token.into(), from, indices),
+            PhantomData,
+        )
+    }
+
+    #[inline]
+    unsafe fn read_strided_impl(token: Self::Token, from: &[Self::Scalar], stride: usize) -> Self {
+        Self(
+            Underlying::read_strided_impl(token.into(), from, stride),
+            PhantomData,
+        )
+    }
+}
+
+impl<Underlying, Scalar, Token> Reduce for ShimToken<Underlying, Scalar, Token>
+where
+    Underlying: Reduce<Scalar = Scalar>,
+    Scalar: Copy,
+    Token: arch::Token + Into<<Underlying as Vector>::Token>,
+{
+    #[inline]
+    fn reduce_sum(self) -> Scalar {
+        self.0.reduce_sum()
+    }
+
+    #[inline]
+    fn reduce_product(self) -> Scalar {
+        self.0.reduce_product()
+    }
+}
+
+impl<Underlying, Scalar, Token> ReduceOrd for ShimToken<Underlying, Scalar, Token>
+where
+    Underlying: ReduceOrd<Scalar = Scalar>,
+    Scalar: Copy,
+    Token: arch::Token + Into<<Underlying as Vector>::Token>,
+{
+    #[inline]
+    fn reduce_min(self) -> Scalar {
+        self.0.reduce_min()
+    }
+
+    #[inline]
+    fn reduce_max(self) -> Scalar {
+        self.0.reduce_max()
+    }
+}
+
+impl<Underlying, Scalar, Token, To> Convert<To> for ShimToken<Underlying, Scalar, Token>
+where
+    Underlying: Convert<To, Scalar = Scalar>,
+    Scalar: Copy,
+    Token: arch::Token + Into<<Underlying as Vector>::Token>,
+    To: Copy,
+{
+    type Output = ShimToken<<Underlying as Convert<To>>::Output, To, Token>;
+
+    #[inline]
+    fn convert(self) -> Self::Output {
+        ShimToken(self.0.convert(), PhantomData)
+    }
+}
+
+impl<Underlying, Scalar, Token, To> ConvertRound<To> for ShimToken<Underlying, Scalar, Token>
+where
+    Underlying: ConvertRound<To, Scalar = Scalar>,
+    Scalar: Copy,
+    Token: arch::Token + Into<<Underlying as Vector>::Token>,
+    To: Copy,
+{
+    #[inline]
+    fn convert_round(self) -> Self::Output {
+        ShimToken(self.0.convert_round(), PhantomData)
+    }
+}
+
+impl<Underlying, Scalar, Token> Bits for ShimToken<Underlying, Scalar, Token>
+where
+    Underlying: Bits<Scalar = Scalar>,
+    Scalar: Copy,
+    Token: arch::Token + Into<<Underlying as Vector>::Token>,
+{
+    type Bits = ShimToken<Underlying::Bits, <Underlying::Bits as Vector>::Scalar, Token>;
+
+    #[inline]
+    fn to_bits(self) -> Self::Bits {
+        ShimToken(self.0.to_bits(), PhantomData)
+    }
+
+    #[inline]
+    fn from_bits(bits: Self::Bits) -> Self {
+        Self(Underlying::from_bits(bits.0), PhantomData)
+    }
+}
+
 impl<Underlying, Scalar, Token> core::iter::Sum<ShimToken<Underlying, Scalar, Token>>
     for Option<ShimToken<Underlying, Scalar, Token>>
 where
@@ -249,7 +698,10 @@ where
 #[cfg(feature = "complex")]
 impl<Underlying, Real, Token> Complex for ShimToken<Underlying, num_complex::Complex<Real>, Token>
 where
-    Underlying: Vector<Scalar = num_complex::Complex<Real>> + Complex<RealScalar = Real>,
+    Underlying: Vector<Scalar = num_complex::Complex<Real>>
+        + Complex<RealScalar = Real>
+        + MulAddImpl<Token>
+        + GatherImpl<Token>,
     Real: Copy,
     Token: arch::Token,
     Underlying::Token: From<Token>,
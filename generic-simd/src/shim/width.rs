@@ -1,4 +1,8 @@
-use crate::vector::{width, Vector};
+use crate::math::Shift;
+use crate::vector::{
+    width, Bits, Bitwise, Compare, Convert, ConvertRound, Float, GatherImpl, HalfVector, Mask,
+    MulAddImpl, Reduce, ReduceOrd, Round, Select, Signed, Sqrt, Vector,
+};
 use core::marker::PhantomData;
 
 #[cfg(feature = "complex")]
@@ -6,7 +10,7 @@ use crate::vector::Complex;
 
 /// Determines the doubled width of this vector.
 pub trait Double {
-    type Doubled: width::Width;
+    type Doubled: width::Width + Half<Halved = Self>;
 }
 
 impl Double for width::W1 {
@@ -21,6 +25,47 @@ impl Double for width::W4 {
     type Doubled = width::W8;
 }
 
+impl Double for width::W8 {
+    type Doubled = width::W16;
+}
+
+impl Double for width::W16 {
+    type Doubled = width::W32;
+}
+
+impl Double for width::W32 {
+    type Doubled = width::W64;
+}
+
+/// Determines the halved width of this vector.
+pub trait Half {
+    type Halved: width::Width;
+}
+
+impl Half for width::W2 {
+    type Halved = width::W1;
+}
+
+impl Half for width::W4 {
+    type Halved = width::W2;
+}
+
+impl Half for width::W8 {
+    type Halved = width::W4;
+}
+
+impl Half for width::W16 {
+    type Halved = width::W8;
+}
+
+impl Half for width::W32 {
+    type Halved = width::W16;
+}
+
+impl Half for width::W64 {
+    type Halved = width::W32;
+}
+
 /// Shim that doubles the width of a vector.
 #[derive(Copy, Clone, Debug)]
 #[repr(transparent)]
@@ -32,6 +77,41 @@ pub type Shim4<Underlying, Scalar> = Shim2<Shim2<Underlying, Scalar>, Scalar>;
 /// Shim that octuples the width of a vector.
 pub type Shim8<Underlying, Scalar> = Shim4<Shim2<Underlying, Scalar>, Scalar>;
 
+/// Shim that multiplies the width of a vector by 16.
+pub type Shim16<Underlying, Scalar> = Shim8<Shim2<Underlying, Scalar>, Scalar>;
+
+/// Shim that multiplies the width of a vector by 32.
+pub type Shim32<Underlying, Scalar> = Shim16<Shim2<Underlying, Scalar>, Scalar>;
+
+/// Shim that multiplies the width of a vector by 64.
+pub type Shim64<Underlying, Scalar> = Shim32<Shim2<Underlying, Scalar>, Scalar>;
+
+impl<Underlying, Scalar> Shim2<Underlying, Scalar> {
+    /// Create a shim from its low and high halves.
+    #[inline]
+    pub(crate) fn from_halves(low: Underlying, high: Underlying) -> Self {
+        Self([low, high], PhantomData)
+    }
+
+    /// Returns the low and high halves of the shim.
+    #[inline]
+    pub(crate) fn halves(self) -> (Underlying, Underlying) {
+        let [low, high] = self.0;
+        (low, high)
+    }
+
+    /// Apply a function to the corresponding halves of two shims.
+    #[inline]
+    pub(crate) fn zip_halves(
+        self,
+        other: Self,
+        f: impl Fn(Underlying, Underlying) -> Underlying,
+    ) -> Self {
+        let ([a_low, a_high], [b_low, b_high]) = (self.0, other.0);
+        Self::from_halves(f(a_low, b_low), f(a_high, b_high))
+    }
+}
+
 unsafe impl<Underlying, Scalar> Vector for Shim2<Underlying, Scalar>
 where
     Underlying: Vector<Scalar = Scalar>,
@@ -52,6 +132,170 @@ where
     fn splat(token: Self::Token, from: Self::Scalar) -> Self {
         Self([Underlying::splat(token, from); 2], PhantomData)
     }
+
+    #[inline]
+    fn read_partial(token: Self::Token, from: &[Self::Scalar], fill: Self::Scalar) -> Self {
+        let (low, high) = from.split_at(from.len().min(Underlying::width()));
+        Self(
+            [
+                Underlying::read_partial(token, low, fill),
+                Underlying::read_partial(token, high, fill),
+            ],
+            PhantomData,
+        )
+    }
+
+    #[inline]
+    fn write_partial(self, to: &mut [Self::Scalar]) {
+        let (low, high) = to.split_at_mut(to.len().min(Underlying::width()));
+        self.0[0].write_partial(low);
+        self.0[1].write_partial(high);
+    }
+
+    #[inline]
+    unsafe fn scatter_unchecked(self, to: &mut [Self::Scalar], indices: &[usize]) {
+        self.0[0].scatter_unchecked(to, indices);
+        self.0[1].scatter_unchecked(to, indices.get_unchecked(Underlying::width()..));
+    }
+
+    #[inline]
+    unsafe fn write_strided_unchecked(self, to: &mut [Self::Scalar], stride: usize) {
+        self.0[0].write_strided_unchecked(to, stride);
+        self.0[1]
+            .write_strided_unchecked(to.get_unchecked_mut(Underlying::width() * stride..), stride);
+    }
+
+    #[inline]
+    unsafe fn deinterleave2_unchecked(token: Self::Token, from: &[Self::Scalar]) -> (Self, Self) {
+        let (low, high) = from.split_at(2 * Underlying::width());
+        let (a_low, b_low) = Underlying::deinterleave2_unchecked(token, low);
+        let (a_high, b_high) = Underlying::deinterleave2_unchecked(token, high);
+        (
+            Self([a_low, a_high], PhantomData),
+            Self([b_low, b_high], PhantomData),
+        )
+    }
+
+    #[inline]
+    unsafe fn deinterleave3_unchecked(
+        token: Self::Token,
+        from: &[Self::Scalar],
+    ) -> (Self, Self, Self) {
+        let (low, high) = from.split_at(3 * Underlying::width());
+        let (a_low, b_low, c_low) = Underlying::deinterleave3_unchecked(token, low);
+        let (a_high, b_high, c_high) = Underlying::deinterleave3_unchecked(token, high);
+        (
+            Self([a_low, a_high], PhantomData),
+            Self([b_low, b_high], PhantomData),
+            Self([c_low, c_high], PhantomData),
+        )
+    }
+
+    #[inline]
+    unsafe fn deinterleave4_unchecked(
+        token: Self::Token,
+        from: &[Self::Scalar],
+    ) -> (Self, Self, Self, Self) {
+        let (low, high) = from.split_at(4 * Underlying::width());
+        let (a_low, b_low, c_low, d_low) = Underlying::deinterleave4_unchecked(token, low);
+        let (a_high, b_high, c_high, d_high) = Underlying::deinterleave4_unchecked(token, high);
+        (
+            Self([a_low, a_high], PhantomData),
+            Self([b_low, b_high], PhantomData),
+            Self([c_low, c_high], PhantomData),
+            Self([d_low, d_high], PhantomData),
+        )
+    }
+
+    #[inline]
+    unsafe fn interleave2_unchecked(self, b: Self, to: &mut [Self::Scalar]) {
+        let (low, high) = to.split_at_mut(2 * Underlying::width());
+        self.0[0].interleave2_unchecked(b.0[0], low);
+        self.0[1].interleave2_unchecked(b.0[1], high);
+    }
+
+    #[inline]
+    unsafe fn interleave3_unchecked(self, b: Self, c: Self, to: &mut [Self::Scalar]) {
+        let (low, high) = to.split_at_mut(3 * Underlying::width());
+        self.0[0].interleave3_unchecked(b.0[0], c.0[0], low);
+        self.0[1].interleave3_unchecked(b.0[1], c.0[1], high);
+    }
+
+    #[inline]
+    unsafe fn interleave4_unchecked(self, b: Self, c: Self, d: Self, to: &mut [Self::Scalar]) {
+        let (low, high) = to.split_at_mut(4 * Underlying::width());
+        self.0[0].interleave4_unchecked(b.0[0], c.0[0], d.0[0], low);
+        self.0[1].interleave4_unchecked(b.0[1], c.0[1], d.0[1], high);
+    }
+
+    #[inline]
+    fn reverse(self) -> Self {
+        Self([self.0[1].reverse(), self.0[0].reverse()], PhantomData)
+    }
+
+    // Rotates each half, then exchanges the lanes that wrapped around into the other half.
+    #[inline]
+    fn rotate_lanes_left_impl(self, n: usize) -> Self {
+        let [low, high] = if n < Underlying::width() {
+            self.0
+        } else {
+            [self.0[1], self.0[0]]
+        };
+        let n = n % Underlying::width();
+        let mut low = low.rotate_lanes_left_impl(n);
+        let mut high = high.rotate_lanes_left_impl(n);
+        for (low, high) in low.as_slice_mut()[Underlying::width() - n..]
+            .iter_mut()
+            .zip(&mut high.as_slice_mut()[Underlying::width() - n..])
+        {
+            core::mem::swap(low, high);
+        }
+        Self([low, high], PhantomData)
+    }
+
+    #[inline]
+    fn broadcast_lane_impl(self, lane: usize) -> Self {
+        let broadcast = if lane < Underlying::width() {
+            self.0[0].broadcast_lane_impl(lane)
+        } else {
+            self.0[1].broadcast_lane_impl(lane - Underlying::width())
+        };
+        Self([broadcast; 2], PhantomData)
+    }
+
+    // `HalfVector<Self>` has the width of `Underlying`, so both have the layout of an array of
+    // `Scalar` and the halves are moved directly.
+    #[inline]
+    fn split(self) -> (HalfVector<Self>, HalfVector<Self>)
+    where
+        Self::Width: Half,
+        Self::Scalar: crate::scalar::Scalar<Self::Token, <Self::Width as Half>::Halved>,
+    {
+        let [low, high] = self.0;
+        unsafe {
+            (
+                core::mem::transmute_copy(&low),
+                core::mem::transmute_copy(&high),
+            )
+        }
+    }
+
+    #[inline]
+    fn concat(low: HalfVector<Self>, high: HalfVector<Self>) -> Self
+    where
+        Self::Width: Half,
+        Self::Scalar: crate::scalar::Scalar<Self::Token, <Self::Width as Half>::Halved>,
+    {
+        unsafe {
+            Self(
+                [
+                    core::mem::transmute_copy(&low),
+                    core::mem::transmute_copy(&high),
+                ],
+                PhantomData,
+            )
+        }
+    }
 }
 
 impl<Underlying, Scalar> AsRef<[Scalar]> for Shim2<Underlying, Scalar>
@@ -172,6 +416,150 @@ implement! { @op_assign SubAssign::sub_assign }
 implement! { @op_assign MulAssign::mul_assign }
 implement! { @op_assign DivAssign::div_assign }
 
+macro_rules! implement_bitwise {
+    {
+        $trait:ident :: $func:ident
+    } => {
+        impl<Underlying, Scalar> core::ops::$trait<Self> for Shim2<Underlying, Scalar>
+        where
+            Underlying: Copy + core::ops::$trait<Underlying, Output=Underlying>,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn $func(self, rhs: Self) -> Self {
+                Self([self.0[0].$func(rhs.0[0]), self.0[1].$func(rhs.0[1])], PhantomData)
+            }
+        }
+    }
+}
+
+implement_bitwise! { BitAnd::bitand }
+implement_bitwise! { BitOr::bitor }
+implement_bitwise! { BitXor::bitxor }
+
+impl<Underlying, Scalar> core::ops::Not for Shim2<Underlying, Scalar>
+where
+    Underlying: Copy + core::ops::Not<Output = Underlying>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        Self([!self.0[0], !self.0[1]], PhantomData)
+    }
+}
+
+impl<Underlying, Scalar> Bitwise for Shim2<Underlying, Scalar>
+where
+    Underlying: Bitwise<Scalar = Scalar>,
+    Underlying::Width: Double,
+    Scalar: Copy,
+{
+    #[inline]
+    fn and_not(self, other: Self) -> Self {
+        Self(
+            [self.0[0].and_not(other.0[0]), self.0[1].and_not(other.0[1])],
+            PhantomData,
+        )
+    }
+}
+
+impl<Underlying, Scalar> Shift for Shim2<Underlying, Scalar>
+where
+    Underlying: Shift,
+    Scalar: Copy,
+{
+    #[inline]
+    fn shift_left(self, count: u32) -> Self {
+        Self(
+            [self.0[0].shift_left(count), self.0[1].shift_left(count)],
+            PhantomData,
+        )
+    }
+
+    #[inline]
+    fn shift_right(self, count: u32) -> Self {
+        Self(
+            [self.0[0].shift_right(count), self.0[1].shift_right(count)],
+            PhantomData,
+        )
+    }
+}
+
+impl<Underlying> Mask for Shim2<Underlying, bool>
+where
+    Underlying: Mask,
+    Underlying::Width: Double,
+{
+    type Token = <Underlying as Mask>::Token;
+    type Width = <Underlying::Width as Double>::Doubled;
+
+    #[inline]
+    fn splat(token: Self::Token, value: bool) -> Self {
+        Self([Underlying::splat(token, value); 2], PhantomData)
+    }
+
+    #[inline]
+    fn test(&self, lane: usize) -> bool {
+        if lane < Underlying::width() {
+            self.0[0].test(lane)
+        } else {
+            self.0[1].test(lane - Underlying::width())
+        }
+    }
+
+    #[inline]
+    fn any(&self) -> bool {
+        self.0[0].any() || self.0[1].any()
+    }
+
+    #[inline]
+    fn all(&self) -> bool {
+        self.0[0].all() && self.0[1].all()
+    }
+}
+
+impl<Underlying, Vector, Scalar> Select<Shim2<Vector, Scalar>> for Shim2<Underlying, bool>
+where
+    Underlying: Select<Vector>,
+    Underlying::Width: Double,
+{
+    #[inline]
+    fn select(self, a: Shim2<Vector, Scalar>, b: Shim2<Vector, Scalar>) -> Shim2<Vector, Scalar> {
+        let [a0, a1] = a.0;
+        let [b0, b1] = b.0;
+        Shim2(
+            [self.0[0].select(a0, b0), self.0[1].select(a1, b1)],
+            PhantomData,
+        )
+    }
+}
+
+macro_rules! implement_compare {
+    {
+        $($func:ident),*
+    } => {
+        impl<Underlying, Scalar> Compare for Shim2<Underlying, Scalar>
+        where
+            Underlying: Compare<Scalar = Scalar>,
+            Underlying::Width: Double,
+            Scalar: Copy,
+        {
+            type Mask = Shim2<<Underlying as Compare>::Mask, bool>;
+
+            $(
+            #[inline]
+            fn $func(self, other: Self) -> Self::Mask {
+                Shim2([self.0[0].$func(other.0[0]), self.0[1].$func(other.0[1])], PhantomData)
+            }
+            )*
+        }
+    }
+}
+
+implement_compare! { lanes_eq, lanes_ne, lanes_lt, lanes_le, lanes_gt, lanes_ge }
+
 impl<Underlying, Scalar> core::ops::Neg for Shim2<Underlying, Scalar>
 where
     Underlying: Copy + core::ops::Neg<Output = Underlying>,
@@ -184,6 +572,291 @@ where
     }
 }
 
+impl<Underlying, Scalar> Float for Shim2<Underlying, Scalar>
+where
+    Self: Signed,
+    Underlying: Float<Scalar = Scalar>,
+    Underlying::Width: Double,
+    Scalar: Copy,
+{
+    #[inline]
+    fn min(self, other: Self) -> Self {
+        Self(
+            [self.0[0].min(other.0[0]), self.0[1].min(other.0[1])],
+            PhantomData,
+        )
+    }
+
+    #[inline]
+    fn max(self, other: Self) -> Self {
+        Self(
+            [self.0[0].max(other.0[0]), self.0[1].max(other.0[1])],
+            PhantomData,
+        )
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        Self([self.0[0].abs(), self.0[1].abs()], PhantomData)
+    }
+
+    #[inline]
+    fn copysign(self, sign: Self) -> Self {
+        Self(
+            [self.0[0].copysign(sign.0[0]), self.0[1].copysign(sign.0[1])],
+            PhantomData,
+        )
+    }
+}
+
+impl<Underlying, Scalar> Sqrt for Shim2<Underlying, Scalar>
+where
+    Self: Float,
+    Underlying: Sqrt<Scalar = Scalar>,
+    Underlying::Width: Double,
+    Scalar: Copy,
+{
+    #[inline]
+    fn sqrt(self) -> Self {
+        Self([self.0[0].sqrt(), self.0[1].sqrt()], PhantomData)
+    }
+
+    #[inline]
+    fn recip(self) -> Self {
+        Self([self.0[0].recip(), self.0[1].recip()], PhantomData)
+    }
+
+    #[inline]
+    fn rsqrt(self) -> Self {
+        Self([self.0[0].rsqrt(), self.0[1].rsqrt()], PhantomData)
+    }
+
+    #[inline]
+    fn recip_estimate(self) -> Self {
+        Self(
+            [self.0[0].recip_estimate(), self.0[1].recip_estimate()],
+            PhantomData,
+        )
+    }
+
+    #[inline]
+    fn rsqrt_estimate(self) -> Self {
+        Self(
+            [self.0[0].rsqrt_estimate(), self.0[1].rsqrt_estimate()],
+            PhantomData,
+        )
+    }
+
+    #[inline]
+    fn recip_refined(self) -> Self {
+        Self(
+            [self.0[0].recip_refined(), self.0[1].recip_refined()],
+            PhantomData,
+        )
+    }
+
+    #[inline]
+    fn rsqrt_refined(self) -> Self {
+        Self(
+            [self.0[0].rsqrt_refined(), self.0[1].rsqrt_refined()],
+            PhantomData,
+        )
+    }
+}
+
+impl<Underlying, Scalar> Round for Shim2<Underlying, Scalar>
+where
+    Self: Float,
+    Underlying: Round<Scalar = Scalar>,
+    Underlying::Width: Double,
+    Scalar: Copy,
+{
+    #[inline]
+    fn floor(self) -> Self {
+        Self([self.0[0].floor(), self.0[1].floor()], PhantomData)
+    }
+
+    #[inline]
+    fn ceil(self) -> Self {
+        Self([self.0[0].ceil(), self.0[1].ceil()], PhantomData)
+    }
+
+    #[inline]
+    fn round(self) -> Self {
+        Self([self.0[0].round(), self.0[1].round()], PhantomData)
+    }
+
+    #[inline]
+    fn round_ties_even(self) -> Self {
+        Self(
+            [self.0[0].round_ties_even(), self.0[1].round_ties_even()],
+            PhantomData,
+        )
+    }
+
+    #[inline]
+    fn trunc(self) -> Self {
+        Self([self.0[0].trunc(), self.0[1].trunc()], PhantomData)
+    }
+}
+
+impl<Underlying, Scalar, Token> MulAddImpl<Token> for Shim2<Underlying, Scalar>
+where
+    Underlying: Copy + MulAddImpl<Token>,
+{
+    #[inline]
+    fn mul_add_impl(self, a: Self, b: Self) -> Self {
+        Self(
+            [
+                self.0[0].mul_add_impl(a.0[0], b.0[0]),
+                self.0[1].mul_add_impl(a.0[1], b.0[1]),
+            ],
+            PhantomData,
+        )
+    }
+
+    #[inline]
+    fn mul_sub_impl(self, a: Self, b: Self) -> Self {
+        Self(
+            [
+                self.0[0].mul_sub_impl(a.0[0], b.0[0]),
+                self.0[1].mul_sub_impl(a.0[1], b.0[1]),
+            ],
+            PhantomData,
+        )
+    }
+}
+
+impl<Underlying, Scalar, Token> GatherImpl<Token> for Shim2<Underlying, Scalar>
+where
+    Underlying: Vector<Scalar = Scalar> + GatherImpl<Token>,
+    Underlying::Width: Double,
+    Scalar: Copy,
+{
+    #[inline]
+    unsafe fn gather_impl(token: Self::Token, from: &[Self::Scalar], indices: &[usize]) -> Self {
+        Self(
+            [
+                Underlying::gather_impl(token, from, indices),
+                Underlying::gather_impl(token, from, indices.get_unchecked(Underlying::width()..)),
+            ],
+            PhantomData,
+        )
+    }
+
+    #[inline]
+    unsafe fn read_strided_impl(token: Self::Token, from: &[Self::Scalar], stride: usize) -> Self {
+        Self(
+            [
+                Underlying::read_strided_impl(token, from, stride),
+                Underlying::read_strided_impl(
+                    token,
+                    from.get_unchecked(Underlying::width() * stride..),
+                    stride,
+                ),
+            ],
+            PhantomData,
+        )
+    }
+}
+
+impl<Underlying, Scalar, To> Convert<To> for Shim2<Underlying, Scalar>
+where
+    Underlying: Convert<To, Scalar = Scalar>,
+    Underlying::Width: Double,
+    Scalar: Copy,
+    To: Copy,
+{
+    type Output = Shim2<<Underlying as Convert<To>>::Output, To>;
+
+    #[inline]
+    fn convert(self) -> Self::Output {
+        Shim2([self.0[0].convert(), self.0[1].convert()], PhantomData)
+    }
+}
+
+impl<Underlying, Scalar, To> ConvertRound<To> for Shim2<Underlying, Scalar>
+where
+    Underlying: ConvertRound<To, Scalar = Scalar>,
+    Underlying::Width: Double,
+    Scalar: Copy,
+    To: Copy,
+{
+    #[inline]
+    fn convert_round(self) -> Self::Output {
+        Shim2(
+            [self.0[0].convert_round(), self.0[1].convert_round()],
+            PhantomData,
+        )
+    }
+}
+
+impl<Underlying, Scalar> Bits for Shim2<Underlying, Scalar>
+where
+    Underlying: Bits<Scalar = Scalar>,
+    Underlying::Width: Double,
+    Scalar: Copy,
+{
+    type Bits = Shim2<Underlying::Bits, <Underlying::Bits as Vector>::Scalar>;
+
+    #[inline]
+    fn to_bits(self) -> Self::Bits {
+        Shim2([self.0[0].to_bits(), self.0[1].to_bits()], PhantomData)
+    }
+
+    #[inline]
+    fn from_bits(bits: Self::Bits) -> Self {
+        Self(
+            [
+                Underlying::from_bits(bits.0[0]),
+                Underlying::from_bits(bits.0[1]),
+            ],
+            PhantomData,
+        )
+    }
+}
+
+// Reductions combine the two halves and then reduce the underlying vector.
+impl<Underlying, Scalar> Reduce for Shim2<Underlying, Scalar>
+where
+    Underlying: Reduce<Scalar = Scalar>
+        + core::ops::Add<Output = Underlying>
+        + core::ops::Mul<Output = Underlying>,
+    Underlying::Width: Double,
+    Scalar: Copy,
+{
+    #[inline]
+    fn reduce_sum(self) -> Scalar {
+        let [a, b] = self.0;
+        (a + b).reduce_sum()
+    }
+
+    #[inline]
+    fn reduce_product(self) -> Scalar {
+        let [a, b] = self.0;
+        (a * b).reduce_product()
+    }
+}
+
+impl<Underlying, Scalar> ReduceOrd for Shim2<Underlying, Scalar>
+where
+    Underlying: ReduceOrd<Scalar = Scalar>,
+    Underlying::Width: Double,
+    Scalar: Copy,
+{
+    #[inline]
+    fn reduce_min(self) -> Scalar {
+        let [a, b] = self.0;
+        a.lanes_lt(b).select(a, b).reduce_min()
+    }
+
+    #[inline]
+    fn reduce_max(self) -> Scalar {
+        let [a, b] = self.0;
+        a.lanes_gt(b).select(a, b).reduce_max()
+    }
+}
+
 impl<Underlying, Scalar> core::iter::Sum<Shim2<Underlying, Scalar>>
     for Option<Shim2<Underlying, Scalar>>
 where
@@ -277,7 +950,10 @@ where
 #[cfg(feature = "complex")]
 impl<Underlying, Real> Complex for Shim2<Underlying, num_complex::Complex<Real>>
 where
-    Underlying: Vector<Scalar = num_complex::Complex<Real>> + Complex<RealScalar = Real>,
+    Underlying: Vector<Scalar = num_complex::Complex<Real>>
+        + Complex<RealScalar = Real>
+        + MulAddImpl<<Underlying as Vector>::Token>
+        + GatherImpl<<Underlying as Vector>::Token>,
     Underlying::Width: Double,
     Real: Copy,
 {
@@ -3,7 +3,8 @@
 use crate::arch::Token;
 use crate::{
     scalar::Scalar,
-    vector::{width, Native, NativeWidth, Vector},
+    shim::Shim2,
+    vector::{width, Gather, Native, NativeVector, NativeWidth, Vector},
 };
 use core::marker::PhantomData;
 
@@ -26,6 +27,26 @@ where
     /// See [`read`](../vector/trait.Vector.html#method.read).
     fn read(&self, token: Token) -> Self::Vector;
 
+    /// Read a vector from a slice that may be shorter than the vector, filling the remaining
+    /// lanes.
+    ///
+    /// See [`read_partial`](../vector/trait.Vector.html#method.read_partial).
+    fn read_partial(&self, token: Token, fill: <Self::Vector as Vector>::Scalar) -> Self::Vector;
+
+    /// Read a vector from arbitrary elements of a slice.
+    ///
+    /// See [`gather`](../vector/trait.Gather.html#tymethod.gather).
+    fn gather(&self, token: Token, indices: &[usize]) -> Self::Vector
+    where
+        Self::Vector: Gather;
+
+    /// Read a vector from evenly spaced elements of a slice.
+    ///
+    /// See [`read_strided`](../vector/trait.Gather.html#tymethod.read_strided).
+    fn read_strided(&self, token: Token, stride: usize) -> Self::Vector
+    where
+        Self::Vector: Gather;
+
     /// Extract a slice of aligned vectors, as if by [`align_to`].
     ///
     /// [`align_to`]: https://doc.rust-lang.org/std/primitive.slice.html#method.align_to
@@ -57,6 +78,16 @@ where
 
     /// Create a mutable slice of overlapping vectors from a slice of scalars.
     fn overlapping_mut(&mut self, token: Token) -> OverlappingMut<'_, Self::Vector>;
+
+    /// Iterate over the vectors in a slice of scalars.
+    ///
+    /// See [`Vectors`](struct.Vectors.html).
+    fn vectors(&self, token: Token) -> Vectors<'_, Self::Vector>;
+
+    /// Iterate over the mutable vectors in a slice of scalars.
+    ///
+    /// See [`VectorsMut`](struct.VectorsMut.html).
+    fn vectors_mut(&mut self, token: Token) -> VectorsMut<'_, Self::Vector>;
 }
 
 impl<T, Token, Width> Slice<Token, Width> for [T]
@@ -77,6 +108,27 @@ where
         Self::Vector::read(token, self)
     }
 
+    #[inline]
+    fn read_partial(&self, token: Token, fill: T) -> Self::Vector {
+        Self::Vector::read_partial(token, self, fill)
+    }
+
+    #[inline]
+    fn gather(&self, token: Token, indices: &[usize]) -> Self::Vector
+    where
+        Self::Vector: Gather,
+    {
+        Self::Vector::gather(token, self, indices)
+    }
+
+    #[inline]
+    fn read_strided(&self, token: Token, stride: usize) -> Self::Vector
+    where
+        Self::Vector: Gather,
+    {
+        Self::Vector::read_strided(token, self, stride)
+    }
+
     #[allow(clippy::type_complexity)]
     #[inline]
     fn align(
@@ -112,24 +164,45 @@ where
     fn overlapping_mut(&mut self, token: Token) -> OverlappingMut<'_, Self::Vector> {
         OverlappingMut::new(token, self)
     }
+
+    #[inline]
+    fn vectors(&self, token: Token) -> Vectors<'_, Self::Vector> {
+        Vectors::new(token, self)
+    }
+
+    #[inline]
+    fn vectors_mut(&mut self, token: Token) -> VectorsMut<'_, Self::Vector> {
+        VectorsMut::new(token, self)
+    }
 }
 
 macro_rules! slice_impl {
     {
         $width:literal,
+        [$($generics:tt)*],
         $width_type:ty,
         $read_unchecked:ident,
         $read:ident,
+        $read_partial:ident,
+        $gather:ident,
+        $read_strided:ident,
         $align:ident,
         $align_mut:ident,
         $overlapping:ident,
-        $overlapping_mut:ident
+        $overlapping_mut:ident,
+        $vectors:ident,
+        $vectors_mut:ident
     } => {
         #[doc = "Read a vector with "]
         #[doc = $width]
-        #[doc = " from a slice without checking the length.\n\nSee [`read_unchecked`](../vector/trait.Vector.html#method.read_ptr)."]
+        #[doc = " from a slice without checking the length.\n\n# Safety\nThe slice must be long enough for "]
+        #[doc = $width]
+        #[doc = ".\n\nSee [`read_unchecked`](../vector/trait.Vector.html#method.read_ptr)."]
         #[inline]
-        unsafe fn $read_unchecked(&self, token: Token) -> <Self as Slice<Token, $width_type>>::Vector {
+        unsafe fn $read_unchecked $($generics)* (&self, token: Token) -> <Self as Slice<Token, $width_type>>::Vector
+        where
+            Self: Slice<Token, $width_type>,
+        {
             <Self as Slice<Token, $width_type>>::read_unchecked(self, token)
         }
 
@@ -137,21 +210,66 @@ macro_rules! slice_impl {
         #[doc = $width]
         #[doc = " from a slice.\n\nSee [`read`](../vector/trait.Vector.html#method.read)."]
         #[inline]
-        fn $read(&self, token: Token) -> <Self as Slice<Token, $width_type>>::Vector {
+        fn $read $($generics)* (&self, token: Token) -> <Self as Slice<Token, $width_type>>::Vector
+        where
+            Self: Slice<Token, $width_type>,
+        {
             <Self as Slice<Token, $width_type>>::read(self, token)
         }
 
+        #[doc = "Read a vector with "]
+        #[doc = $width]
+        #[doc = " from a slice that may be shorter than the vector, filling the remaining lanes.\n\nSee [`read_partial`](../vector/trait.Vector.html#method.read_partial)."]
+        #[inline]
+        fn $read_partial $($generics)* (
+            &self,
+            token: Token,
+            fill: <<Self as Slice<Token, $width_type>>::Vector as Vector>::Scalar,
+        ) -> <Self as Slice<Token, $width_type>>::Vector
+        where
+            Self: Slice<Token, $width_type>,
+        {
+            <Self as Slice<Token, $width_type>>::read_partial(self, token, fill)
+        }
+
+        #[doc = "Read a vector with "]
+        #[doc = $width]
+        #[doc = " from arbitrary elements of a slice.\n\nSee [`gather`](../vector/trait.Gather.html#tymethod.gather)."]
+        #[inline]
+        fn $gather $($generics)* (&self, token: Token, indices: &[usize]) -> <Self as Slice<Token, $width_type>>::Vector
+        where
+            Self: Slice<Token, $width_type>,
+            <Self as Slice<Token, $width_type>>::Vector: Gather,
+        {
+            <Self as Slice<Token, $width_type>>::gather(self, token, indices)
+        }
+
+        #[doc = "Read a vector with "]
+        #[doc = $width]
+        #[doc = " from evenly spaced elements of a slice.\n\nSee [`read_strided`](../vector/trait.Gather.html#tymethod.read_strided)."]
+        #[inline]
+        fn $read_strided $($generics)* (&self, token: Token, stride: usize) -> <Self as Slice<Token, $width_type>>::Vector
+        where
+            Self: Slice<Token, $width_type>,
+            <Self as Slice<Token, $width_type>>::Vector: Gather,
+        {
+            <Self as Slice<Token, $width_type>>::read_strided(self, token, stride)
+        }
+
         #[doc = "Align a slice of scalars to vectors with "]
         #[doc = $width]
         #[doc = ".\n\nSee [`align`](trait.Slice.html#tymethod.align)."]
         #[allow(clippy::type_complexity)]
         #[inline]
-        fn $align(&self, token: Token) ->
+        fn $align $($generics)* (&self, token: Token) ->
         (
             &[<<Self as Slice<Token, $width_type>>::Vector as Vector>::Scalar],
             &[<Self as Slice<Token, $width_type>>::Vector],
             &[<<Self as Slice<Token, $width_type>>::Vector as Vector>::Scalar],
-        ) {
+        )
+        where
+            Self: Slice<Token, $width_type>,
+        {
             <Self as Slice<Token, $width_type>>::align(self, token)
         }
 
@@ -160,12 +278,15 @@ macro_rules! slice_impl {
         #[doc = ".\n\nSee [`align_mut`](trait.Slice.html#tymethod.align_mut)."]
         #[allow(clippy::type_complexity)]
         #[inline]
-        fn $align_mut(&mut self, token: Token) ->
+        fn $align_mut $($generics)* (&mut self, token: Token) ->
         (
             &mut [<<Self as Slice<Token, $width_type>>::Vector as Vector>::Scalar],
             &mut [<Self as Slice<Token, $width_type>>::Vector],
             &mut [<<Self as Slice<Token, $width_type>>::Vector as Vector>::Scalar],
-        ){
+        )
+        where
+            Self: Slice<Token, $width_type>,
+        {
             <Self as Slice<Token, $width_type>>::align_mut(self, token)
         }
 
@@ -173,7 +294,10 @@ macro_rules! slice_impl {
         #[doc = $width]
         #[doc = "from a slice of scalars.\n\nSee [`overlapping`](trait.Slice.html#tymethod.overlapping)."]
         #[inline]
-        fn $overlapping(&self, token: Token) -> Overlapping<'_, <Self as Slice<Token, $width_type>>::Vector> {
+        fn $overlapping $($generics)* (&self, token: Token) -> Overlapping<'_, <Self as Slice<Token, $width_type>>::Vector>
+        where
+            Self: Slice<Token, $width_type>,
+        {
             <Self as Slice<Token, $width_type>>::overlapping(self, token)
         }
 
@@ -181,12 +305,40 @@ macro_rules! slice_impl {
         #[doc = $width]
         #[doc = "from a slice of scalars.\n\nSee [`overlapping_mut`](trait.Slice.html#tymethod.overlapping_mut)."]
         #[inline]
-        fn $overlapping_mut(
+        fn $overlapping_mut $($generics)* (
             &mut self,
             token: Token,
-        ) -> OverlappingMut<'_, <Self as Slice<Token, $width_type>>::Vector> {
+        ) -> OverlappingMut<'_, <Self as Slice<Token, $width_type>>::Vector>
+        where
+            Self: Slice<Token, $width_type>,
+        {
             <Self as Slice<Token, $width_type>>::overlapping_mut(self, token)
         }
+
+        #[doc = "Iterate over the vectors of "]
+        #[doc = $width]
+        #[doc = " in a slice of scalars.\n\nSee [`vectors`](trait.Slice.html#tymethod.vectors)."]
+        #[inline]
+        fn $vectors $($generics)* (&self, token: Token) -> Vectors<'_, <Self as Slice<Token, $width_type>>::Vector>
+        where
+            Self: Slice<Token, $width_type>,
+        {
+            <Self as Slice<Token, $width_type>>::vectors(self, token)
+        }
+
+        #[doc = "Iterate over the mutable vectors of "]
+        #[doc = $width]
+        #[doc = " in a slice of scalars.\n\nSee [`vectors_mut`](trait.Slice.html#tymethod.vectors_mut)."]
+        #[inline]
+        fn $vectors_mut $($generics)* (
+            &mut self,
+            token: Token,
+        ) -> VectorsMut<'_, <Self as Slice<Token, $width_type>>::Vector>
+        where
+            Self: Slice<Token, $width_type>,
+        {
+            <Self as Slice<Token, $width_type>>::vectors_mut(self, token)
+        }
     }
 }
 
@@ -204,15 +356,22 @@ pub trait SliceExt<Token>:
     + Slice<Token, width::W2>
     + Slice<Token, width::W4>
     + Slice<Token, width::W8>
+    + Slice<Token, width::W16>
+    + Slice<Token, width::W32>
+    + Slice<Token, width::W64>
     + Slice<Token, NativeWidth<Self, Token>>
 where
     Token: crate::arch::Token,
 {
-    slice_impl! { "the native number of lanes", <Self as Native<Token>>::Width, read_unchecked_native, read_native, align_native, align_native_mut, overlapping_native, overlapping_native_mut }
-    slice_impl! { "1 lane",   width::W1, read_unchecked1, read1, align1, align1_mut, overlapping1, overlapping1_mut }
-    slice_impl! { "2 lanes",  width::W2, read_unchecked2, read2, align2, align2_mut, overlapping2, overlapping2_mut }
-    slice_impl! { "4 lanes",  width::W4, read_unchecked4, read4, align4, align4_mut, overlapping4, overlapping4_mut }
-    slice_impl! { "8 lanes",  width::W8, read_unchecked8, read8, align8, align8_mut, overlapping8, overlapping8_mut }
+    slice_impl! { "the native number of lanes", [], <Self as Native<Token>>::Width, read_unchecked_native, read_native, read_partial_native, gather_native, read_strided_native, align_native, align_native_mut, overlapping_native, overlapping_native_mut, vectors_native, vectors_native_mut }
+    slice_impl! { "1 lane",   [], width::W1, read_unchecked1, read1, read_partial1, gather1, read_strided1, align1, align1_mut, overlapping1, overlapping1_mut, vectors1, vectors1_mut }
+    slice_impl! { "2 lanes",  [], width::W2, read_unchecked2, read2, read_partial2, gather2, read_strided2, align2, align2_mut, overlapping2, overlapping2_mut, vectors2, vectors2_mut }
+    slice_impl! { "4 lanes",  [], width::W4, read_unchecked4, read4, read_partial4, gather4, read_strided4, align4, align4_mut, overlapping4, overlapping4_mut, vectors4, vectors4_mut }
+    slice_impl! { "8 lanes",  [], width::W8, read_unchecked8, read8, read_partial8, gather8, read_strided8, align8, align8_mut, overlapping8, overlapping8_mut, vectors8, vectors8_mut }
+    slice_impl! { "16 lanes", [], width::W16, read_unchecked16, read16, read_partial16, gather16, read_strided16, align16, align16_mut, overlapping16, overlapping16_mut, vectors16, vectors16_mut }
+    slice_impl! { "32 lanes", [], width::W32, read_unchecked32, read32, read_partial32, gather32, read_strided32, align32, align32_mut, overlapping32, overlapping32_mut, vectors32, vectors32_mut }
+    slice_impl! { "64 lanes", [], width::W64, read_unchecked64, read64, read_partial64, gather64, read_strided64, align64, align64_mut, overlapping64, overlapping64_mut, vectors64, vectors64_mut }
+    slice_impl! { "`N` lanes", [<const N: usize>], width::Lanes<N>, read_unchecked_lanes, read_lanes, read_partial_lanes, gather_lanes, read_strided_lanes, align_lanes, align_lanes_mut, overlapping_lanes, overlapping_lanes_mut, vectors_lanes, vectors_lanes_mut }
 }
 
 impl<T, Token> SliceExt<Token> for T
@@ -223,6 +382,9 @@ where
         + Slice<Token, width::W2>
         + Slice<Token, width::W4>
         + Slice<Token, width::W8>
+        + Slice<Token, width::W16>
+        + Slice<Token, width::W32>
+        + Slice<Token, width::W64>
         + Slice<Token, NativeWidth<Self, Token>>,
     Token: crate::arch::Token,
 {
@@ -234,6 +396,7 @@ where
     V: Vector,
 {
     source: *mut V::Scalar,
+    lanes: usize,
     temp: V,
     lifetime: PhantomData<&'a V::Scalar>,
 }
@@ -242,11 +405,24 @@ impl<'a, V> RefMut<'a, V>
 where
     V: Vector,
 {
+    // `source` must be valid for reads and writes of a full vector.
     #[inline]
-    fn new(token: V::Token, source: *mut V::Scalar) -> Self {
+    unsafe fn new(token: V::Token, source: *mut V::Scalar) -> Self {
         Self {
             source,
-            temp: V::zeroed(token),
+            lanes: V::width(),
+            temp: V::read_ptr(token, source),
+            lifetime: PhantomData,
+        }
+    }
+
+    // Only the first `lanes` lanes are written back to `source`.
+    #[inline]
+    fn with_lanes(source: *mut V::Scalar, lanes: usize, temp: V) -> Self {
+        Self {
+            source,
+            lanes,
+            temp,
             lifetime: PhantomData,
         }
     }
@@ -281,7 +457,12 @@ where
     #[inline]
     fn drop(&mut self) {
         unsafe {
-            self.temp.write_ptr(self.source);
+            if self.lanes == V::width() {
+                self.temp.write_ptr(self.source);
+            } else {
+                self.temp
+                    .write_partial(core::slice::from_raw_parts_mut(self.source, self.lanes));
+            }
         }
     }
 }
@@ -346,6 +527,22 @@ where
     {
         V::read_ptr(V::Token::new_unchecked(), self.slice.as_ptr().add(index))
     }
+
+    /// Returns an iterator over the vectors at every offset into the slice of scalars.
+    #[inline]
+    pub fn iter(&self) -> OverlappingIter<'_, V> {
+        OverlappingIter::new(self.slice, 1)
+    }
+
+    /// Returns an iterator over the vectors at every `step` offsets into the slice of scalars,
+    /// starting at offset 0.
+    ///
+    /// # Panics
+    /// Panics if `step` is 0.
+    #[inline]
+    pub fn windows_step(&self, step: usize) -> OverlappingIter<'_, V> {
+        OverlappingIter::new(self.slice, step)
+    }
 }
 
 /// Wrapper for indexing into overlapping mutable vectors.
@@ -407,8 +604,10 @@ where
     }
 
     /// Returns the mutable vector offset `index` into the slice of scalars.
+    ///
+    /// The vector is written back to the slice when the `RefMut` is dropped.
     #[inline]
-    pub fn get_mut(&'a mut self, index: usize) -> Option<RefMut<'a, V>> {
+    pub fn get_mut(&mut self, index: usize) -> Option<RefMut<'_, V>> {
         if index < self.len() {
             Some(unsafe { self.get_unchecked_mut(index) })
         } else {
@@ -422,10 +621,437 @@ where
     /// Index must be less than `len()`, i.e. the underlying slice must be at least `index
     /// + V::width()` long.
     #[inline]
-    pub unsafe fn get_unchecked_mut(&'a mut self, index: usize) -> RefMut<'a, V> {
+    pub unsafe fn get_unchecked_mut(&mut self, index: usize) -> RefMut<'_, V> {
         RefMut::new(
             V::Token::new_unchecked(),
             self.slice.as_mut_ptr().add(index),
         )
     }
+
+    /// Returns an iterator over the vectors at every offset into the slice of scalars.
+    #[inline]
+    pub fn iter(&self) -> OverlappingIter<'_, V> {
+        OverlappingIter::new(self.slice, 1)
+    }
+
+    /// Returns an iterator over the vectors at every `step` offsets into the slice of scalars,
+    /// starting at offset 0.
+    ///
+    /// # Panics
+    /// Panics if `step` is 0.
+    #[inline]
+    pub fn windows_step(&self, step: usize) -> OverlappingIter<'_, V> {
+        OverlappingIter::new(self.slice, step)
+    }
+
+    /// Calls a function on the mutable vector at every offset into the slice of scalars.
+    ///
+    /// Each vector is written back to the slice before the next vector is read, so changes are
+    /// visible to later, overlapping vectors.
+    #[inline]
+    pub fn for_each_mut(&mut self, f: impl FnMut(&mut V)) {
+        self.for_each_step_mut(1, f)
+    }
+
+    /// Calls a function on the mutable vector at every `step` offsets into the slice of scalars,
+    /// starting at offset 0.
+    ///
+    /// Each vector is written back to the slice before the next vector is read, so changes are
+    /// visible to later, overlapping vectors.
+    ///
+    /// # Panics
+    /// Panics if `step` is 0.
+    #[inline]
+    pub fn for_each_step_mut(&mut self, step: usize, mut f: impl FnMut(&mut V)) {
+        assert!(step > 0, "step must be nonzero");
+        for index in (0..self.len()).step_by(step) {
+            f(&mut *unsafe { self.get_unchecked_mut(index) });
+        }
+    }
+}
+
+/// Iterator over overlapping vectors.
+///
+/// Created by [`Overlapping::iter`](struct.Overlapping.html#method.iter) and
+/// [`Overlapping::windows_step`](struct.Overlapping.html#method.windows_step).
+pub struct OverlappingIter<'a, V>
+where
+    V: Vector,
+{
+    slice: &'a [V::Scalar],
+    index: usize,
+    step: usize,
+    phantom: PhantomData<V>,
+}
+
+impl<'a, V> OverlappingIter<'a, V>
+where
+    V: Vector,
+{
+    // `slice` must be at least as wide as the vector.
+    #[inline]
+    fn new(slice: &'a [V::Scalar], step: usize) -> Self {
+        assert!(step > 0, "step must be nonzero");
+        Self {
+            slice,
+            index: 0,
+            step,
+            phantom: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn end(&self) -> usize {
+        self.slice.len() - V::width() + 1
+    }
+}
+
+impl<'a, V> Iterator for OverlappingIter<'a, V>
+where
+    V: Vector,
+{
+    type Item = V;
+
+    #[inline]
+    fn next(&mut self) -> Option<V> {
+        if self.index < self.end() {
+            let vector = unsafe {
+                V::read_ptr(
+                    V::Token::new_unchecked(),
+                    self.slice.as_ptr().add(self.index),
+                )
+            };
+            self.index = self.index.saturating_add(self.step);
+            Some(vector)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = if self.index < self.end() {
+            (self.end() - self.index - 1) / self.step + 1
+        } else {
+            0
+        };
+        (len, Some(len))
+    }
+}
+
+impl<'a, V> ExactSizeIterator for OverlappingIter<'a, V> where V: Vector {}
+
+/// Iterator over the vectors in a slice of scalars.
+///
+/// Each item is a vector and the number of lanes read from the slice.  If the length of the
+/// slice is not a multiple of the vector width, the final vector is padded with zeroes.
+pub struct Vectors<'a, V>
+where
+    V: Vector,
+{
+    token: V::Token,
+    slice: &'a [V::Scalar],
+    zero: V::Scalar,
+}
+
+impl<'a, V> Vectors<'a, V>
+where
+    V: Vector,
+{
+    /// Create a new vector iterator.
+    #[inline]
+    pub fn new(token: impl Into<V::Token>, slice: &'a [V::Scalar]) -> Self {
+        let token = token.into();
+        Self {
+            token,
+            slice,
+            zero: V::zeroed(token).as_slice()[0],
+        }
+    }
+}
+
+impl<'a, V> Iterator for Vectors<'a, V>
+where
+    V: Vector,
+{
+    type Item = (V, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            return None;
+        }
+        let lanes = self.slice.len().min(V::width());
+        let (head, tail) = self.slice.split_at(lanes);
+        self.slice = tail;
+        let vector = V::read_partial(self.token, head, self.zero);
+        Some((vector, lanes))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.slice.len() + V::width() - 1) / V::width();
+        (len, Some(len))
+    }
+}
+
+impl<'a, V> ExactSizeIterator for Vectors<'a, V> where V: Vector {}
+
+/// Iterator over the mutable vectors in a slice of scalars.
+///
+/// Each item is a mutable vector and the number of lanes read from the slice.  If the length of
+/// the slice is not a multiple of the vector width, the final vector is padded with zeroes.
+/// Each vector is written back to the slice when dropped, except for the padding.
+pub struct VectorsMut<'a, V>
+where
+    V: Vector,
+{
+    token: V::Token,
+    slice: &'a mut [V::Scalar],
+    zero: V::Scalar,
+}
+
+impl<'a, V> VectorsMut<'a, V>
+where
+    V: Vector,
+{
+    /// Create a new mutable vector iterator.
+    #[inline]
+    pub fn new(token: impl Into<V::Token>, slice: &'a mut [V::Scalar]) -> Self {
+        let token = token.into();
+        Self {
+            token,
+            slice,
+            zero: V::zeroed(token).as_slice()[0],
+        }
+    }
+}
+
+impl<'a, V> Iterator for VectorsMut<'a, V>
+where
+    V: Vector,
+{
+    type Item = (RefMut<'a, V>, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.slice.is_empty() {
+            return None;
+        }
+        let lanes = self.slice.len().min(V::width());
+        let (head, tail) = core::mem::take(&mut self.slice).split_at_mut(lanes);
+        self.slice = tail;
+        let vector = V::read_partial(self.token, head, self.zero);
+        Some((RefMut::with_lanes(head.as_mut_ptr(), lanes, vector), lanes))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.slice.len() + V::width() - 1) / V::width();
+        (len, Some(len))
+    }
+}
+
+impl<'a, V> ExactSizeIterator for VectorsMut<'a, V> where V: Vector {}
+
+/// Apply a function to each element of a slice of scalars, writing the results to another slice.
+///
+/// `vector_op` is applied to native vectors and `scalar_op` is applied to the unaligned start and
+/// end of `to`.  Both must compute the same function.
+///
+/// # Panics
+/// Panics if `from` and `to` have different lengths.
+#[inline]
+pub fn map_into<Token, Scalar, VectorOp, ScalarOp>(
+    token: Token,
+    from: &[Scalar],
+    to: &mut [Scalar],
+    vector_op: VectorOp,
+    scalar_op: ScalarOp,
+) where
+    Token: crate::arch::Token,
+    Scalar: Native<Token> + self::Scalar<Token, NativeWidth<Scalar, Token>>,
+    VectorOp: Fn(NativeVector<Scalar, Token>) -> NativeVector<Scalar, Token>,
+    ScalarOp: Fn(Scalar) -> Scalar,
+{
+    assert_eq!(from.len(), to.len(), "slices must have the same length");
+    let width = NativeVector::<Scalar, Token>::width();
+
+    // Align the destination, since the source may not be aligned with it.
+    let (head, pairs, tail) =
+        unsafe { to.align_to_mut::<Shim2<NativeVector<Scalar, Token>, Scalar>>() };
+    let (from_head, from) = from.split_at(head.len());
+    for (to, from) in head.iter_mut().zip(from_head) {
+        *to = scalar_op(*from);
+    }
+
+    // Operate on pairs of vectors, so the two halves are independent.
+    let (from, from_tail) = from.split_at(pairs.len() * 2 * width);
+    for (to, from) in pairs.iter_mut().zip(from.chunks_exact(2 * width)) {
+        let (low, high) = unsafe {
+            (
+                NativeVector::<Scalar, Token>::read_unchecked(token, from),
+                NativeVector::<Scalar, Token>::read_unchecked(token, &from[width..]),
+            )
+        };
+        *to = Shim2::from_halves(vector_op(low), vector_op(high));
+    }
+
+    // A single vector may remain after the pairs.
+    let (tail, from_tail) = if tail.len() >= width {
+        unsafe {
+            vector_op(NativeVector::<Scalar, Token>::read_unchecked(
+                token, from_tail,
+            ))
+            .write_unchecked(tail);
+        }
+        (&mut tail[width..], &from_tail[width..])
+    } else {
+        (tail, from_tail)
+    };
+    for (to, from) in tail.iter_mut().zip(from_tail) {
+        *to = scalar_op(*from);
+    }
+}
+
+/// Apply a function to each pair of elements of two slices of scalars, writing the results to
+/// another slice.
+///
+/// `vector_op` is applied to native vectors and `scalar_op` is applied to the unaligned start and
+/// end of `to`.  Both must compute the same function.
+///
+/// # Panics
+/// Panics if `a`, `b`, and `to` have different lengths.
+#[inline]
+pub fn zip_map<Token, Scalar, VectorOp, ScalarOp>(
+    token: Token,
+    a: &[Scalar],
+    b: &[Scalar],
+    to: &mut [Scalar],
+    vector_op: VectorOp,
+    scalar_op: ScalarOp,
+) where
+    Token: crate::arch::Token,
+    Scalar: Native<Token> + self::Scalar<Token, NativeWidth<Scalar, Token>>,
+    VectorOp:
+        Fn(NativeVector<Scalar, Token>, NativeVector<Scalar, Token>) -> NativeVector<Scalar, Token>,
+    ScalarOp: Fn(Scalar, Scalar) -> Scalar,
+{
+    zip_map_impl(token, a, b, to, vector_op, scalar_op)
+}
+
+/// Reduce a slice of scalars to a single scalar.
+///
+/// `vector_op` combines native vectors lane-wise, and `scalar_op` combines the lanes of the
+/// result and the unaligned start and end of `from`.  Both must compute the same function, which
+/// must be associative and commutative.  Each vector lane is initialized to `init`, so it must be
+/// the identity of the operation (such as `0` for addition).
+#[inline]
+pub fn reduce<Token, Scalar, VectorOp, ScalarOp>(
+    token: Token,
+    from: &[Scalar],
+    init: Scalar,
+    vector_op: VectorOp,
+    scalar_op: ScalarOp,
+) -> Scalar
+where
+    Token: crate::arch::Token,
+    Scalar: Native<Token> + self::Scalar<Token, NativeWidth<Scalar, Token>>,
+    VectorOp:
+        Fn(NativeVector<Scalar, Token>, NativeVector<Scalar, Token>) -> NativeVector<Scalar, Token>,
+    ScalarOp: Fn(Scalar, Scalar) -> Scalar,
+{
+    let (head, pairs, tail) =
+        unsafe { from.align_to::<Shim2<NativeVector<Scalar, Token>, Scalar>>() };
+
+    // Accumulate pairs of vectors, so the two halves are independent.
+    let init_vector = NativeVector::<Scalar, Token>::splat(token, init);
+    let (low, high) = pairs
+        .iter()
+        .fold(Shim2::from_halves(init_vector, init_vector), |acc, pair| {
+            acc.zip_halves(*pair, &vector_op)
+        })
+        .halves();
+    let mut accumulator = vector_op(low, high);
+
+    // A single vector may remain after the pairs.
+    let width = NativeVector::<Scalar, Token>::width();
+    let tail = if tail.len() >= width {
+        accumulator = vector_op(accumulator, unsafe {
+            NativeVector::<Scalar, Token>::read_unchecked(token, tail)
+        });
+        &tail[width..]
+    } else {
+        tail
+    };
+
+    head.iter()
+        .chain(accumulator.as_slice())
+        .chain(tail)
+        .fold(init, |acc, x| scalar_op(acc, *x))
+}
+
+#[inline]
+fn zip_map_impl<Token, Scalar, VectorOp, ScalarOp>(
+    token: Token,
+    a: &[Scalar],
+    b: &[Scalar],
+    to: &mut [Scalar],
+    vector_op: VectorOp,
+    scalar_op: ScalarOp,
+) where
+    Token: crate::arch::Token,
+    Scalar: Native<Token> + self::Scalar<Token, NativeWidth<Scalar, Token>>,
+    VectorOp:
+        Fn(NativeVector<Scalar, Token>, NativeVector<Scalar, Token>) -> NativeVector<Scalar, Token>,
+    ScalarOp: Fn(Scalar, Scalar) -> Scalar,
+{
+    assert!(
+        a.len() == to.len() && b.len() == to.len(),
+        "slices must have the same length"
+    );
+    let width = NativeVector::<Scalar, Token>::width();
+
+    // Align the destination, since the sources may not be aligned with each other.
+    let (head, pairs, tail) =
+        unsafe { to.align_to_mut::<Shim2<NativeVector<Scalar, Token>, Scalar>>() };
+    let (a_head, a) = a.split_at(head.len());
+    let (b_head, b) = b.split_at(head.len());
+    for ((to, a), b) in head.iter_mut().zip(a_head).zip(b_head) {
+        *to = scalar_op(*a, *b);
+    }
+
+    // Operate on pairs of vectors, so the two halves are independent.
+    let read_pair = |from: &[Scalar]| unsafe {
+        Shim2::from_halves(
+            NativeVector::<Scalar, Token>::read_unchecked(token, from),
+            NativeVector::<Scalar, Token>::read_unchecked(token, &from[width..]),
+        )
+    };
+    let (a, a_tail) = a.split_at(pairs.len() * 2 * width);
+    let (b, b_tail) = b.split_at(pairs.len() * 2 * width);
+    for ((to, a), b) in pairs
+        .iter_mut()
+        .zip(a.chunks_exact(2 * width))
+        .zip(b.chunks_exact(2 * width))
+    {
+        *to = read_pair(a).zip_halves(read_pair(b), &vector_op);
+    }
+
+    // A single vector may remain after the pairs.
+    let (tail, a_tail, b_tail) = if tail.len() >= width {
+        unsafe {
+            vector_op(
+                NativeVector::<Scalar, Token>::read_unchecked(token, a_tail),
+                NativeVector::<Scalar, Token>::read_unchecked(token, b_tail),
+            )
+            .write_unchecked(tail);
+        }
+        (&mut tail[width..], &a_tail[width..], &b_tail[width..])
+    } else {
+        (tail, a_tail, b_tail)
+    };
+    for ((to, a), b) in tail.iter_mut().zip(a_tail).zip(b_tail) {
+        *to = scalar_op(*a, *b);
+    }
 }
@@ -4,8 +4,10 @@ pub mod width;
 
 use crate::arch::Token;
 use crate::scalar::Scalar;
+use crate::shim::Half;
 use core::ops::{
-    Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign,
+    Add, AddAssign, BitAnd, BitOr, BitXor, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Neg,
+    Not, Sub, SubAssign,
 };
 
 /// Indicates the widest native vector.
@@ -17,10 +19,22 @@ pub trait Native<Token> {
 pub type NativeWidth<Scalar, Token> = <Scalar as Native<Token>>::Width;
 
 /// Convenience type for the widest native vector.
-pub type NativeVector<Scalar, Token> = VectorOf<Scalar, NativeWidth<Scalar, Token>, Token>;
+pub type NativeVector<Scalar, Token> =
+    <Scalar as self::Scalar<Token, NativeWidth<Scalar, Token>>>::Vector;
 
-/// Convenience type for the vector with a particular width.
-pub type VectorOf<Scalar, Width, Token> = <Scalar as self::Scalar<Token, Width>>::Vector;
+/// Convenience type for the vector with a particular number of lanes.
+///
+/// For example, `VectorOf<f32, 4, Token>` is a vector of 4 `f32`s.
+pub type VectorOf<Scalar, const N: usize, Token> =
+    <Scalar as self::Scalar<Token, width::Lanes<N>>>::Vector;
+
+/// Convenience type for the vector with half as many lanes as `V`.
+///
+/// For example, `HalfVector<VectorOf<f32, 8, Token>>` is `VectorOf<f32, 4, Token>`.
+pub type HalfVector<V> = <<V as Vector>::Scalar as Scalar<
+    <V as Vector>::Token,
+    <<V as Vector>::Width as Half>::Halved,
+>>::Vector;
 
 /// The fundamental vector type.
 ///
@@ -72,7 +86,7 @@ pub unsafe trait Vector: Copy {
                 core::mem::size_of::<Self::Underlying>(),
                 core::mem::align_of::<Self::Underlying>(),
             ),
-            (core::mem::align_of::<Self>(), core::mem::size_of::<Self>(),)
+            (core::mem::size_of::<Self>(), core::mem::align_of::<Self>())
         );
         unsafe { core::mem::transmute_copy(&self) }
     }
@@ -88,7 +102,7 @@ pub unsafe trait Vector: Copy {
                 core::mem::size_of::<Self::Underlying>(),
                 core::mem::align_of::<Self::Underlying>(),
             ),
-            (core::mem::align_of::<Self>(), core::mem::size_of::<Self>(),)
+            (core::mem::size_of::<Self>(), core::mem::align_of::<Self>())
         );
         unsafe { core::mem::transmute_copy(&underlying) }
     }
@@ -183,6 +197,436 @@ pub unsafe trait Vector: Copy {
         unsafe { self.write_unchecked(to) };
     }
 
+    /// Read from a slice that may be shorter than the vector.
+    ///
+    /// Reads `min(from.len(), width())` lanes from the slice and sets the remaining lanes to `fill`.
+    #[inline]
+    fn read_partial(token: Self::Token, from: &[Self::Scalar], fill: Self::Scalar) -> Self {
+        if from.len() >= Self::width() {
+            unsafe { Self::read_unchecked(token, from) }
+        } else {
+            let mut vector = Self::splat(token, fill);
+            vector.as_slice_mut()[..from.len()].copy_from_slice(from);
+            vector
+        }
+    }
+
+    /// Write to a slice that may be shorter than the vector.
+    ///
+    /// Writes `min(to.len(), width())` lanes to the slice.
+    #[inline]
+    fn write_partial(self, to: &mut [Self::Scalar]) {
+        if to.len() >= Self::width() {
+            unsafe { self.write_unchecked(to) };
+        } else {
+            let len = to.len();
+            to.copy_from_slice(&self.as_slice()[..len]);
+        }
+    }
+
+    /// Write to arbitrary elements of a slice without checking the indices.
+    ///
+    /// Lane `i` is written to `to[indices[i]]`.  If an index is repeated, the highest lane is
+    /// written last.
+    ///
+    /// # Safety
+    /// * `indices` must be length at least `width()`.
+    /// * The first `width()` indices must be less than the length of `to`.
+    #[inline]
+    unsafe fn scatter_unchecked(self, to: &mut [Self::Scalar], indices: &[usize]) {
+        for (lane, index) in self.as_slice().iter().zip(indices) {
+            *to.get_unchecked_mut(*index) = *lane;
+        }
+    }
+
+    /// Write to arbitrary elements of a slice.
+    ///
+    /// Lane `i` is written to `to[indices[i]]`.  If an index is repeated, the highest lane is
+    /// written last.
+    ///
+    /// # Panics
+    /// Panics if the length of `indices` is less than `width()`, or if any of the first `width()`
+    /// indices are out of bounds.
+    #[inline]
+    fn scatter(self, to: &mut [Self::Scalar], indices: &[usize]) {
+        assert!(
+            indices.len() >= Self::width(),
+            "not enough indices to scatter vector"
+        );
+        assert!(
+            indices[..Self::width()]
+                .iter()
+                .all(|index| *index < to.len()),
+            "scatter index out of bounds"
+        );
+        unsafe { self.scatter_unchecked(to, indices) };
+    }
+
+    /// Write to evenly spaced elements of a slice without checking the length.
+    ///
+    /// Lane `i` is written to `to[i * stride]`.
+    ///
+    /// # Safety
+    /// * The length of `to` must be greater than `(width() - 1) * stride`.
+    #[inline]
+    unsafe fn write_strided_unchecked(self, to: &mut [Self::Scalar], stride: usize) {
+        for (i, lane) in self.as_slice().iter().enumerate() {
+            *to.get_unchecked_mut(i * stride) = *lane;
+        }
+    }
+
+    /// Write to evenly spaced elements of a slice.
+    ///
+    /// Lane `i` is written to `to[i * stride]`.
+    ///
+    /// # Panics
+    /// Panics if the length of `to` is not greater than `(width() - 1) * stride`.
+    #[inline]
+    fn write_strided(self, to: &mut [Self::Scalar], stride: usize) {
+        assert!(
+            (Self::width() - 1)
+                .checked_mul(stride)
+                .map_or(false, |last| last < to.len()),
+            "destination not large enough to store strided vector"
+        );
+        unsafe { self.write_strided_unchecked(to, stride) };
+    }
+
+    /// Read two vectors from a slice of interleaved pairs without checking the length.
+    ///
+    /// Lane `i` of the `j`th vector is read from `from[2 * i + j]`.
+    ///
+    /// # Safety
+    /// * `from` must be length at least `2 * width()`.
+    #[inline]
+    unsafe fn deinterleave2_unchecked(token: Self::Token, from: &[Self::Scalar]) -> (Self, Self) {
+        let mut a = Self::zeroed(token);
+        let mut b = Self::zeroed(token);
+        for ((a, b), from) in a
+            .as_slice_mut()
+            .iter_mut()
+            .zip(b.as_slice_mut())
+            .zip(from.chunks_exact(2))
+        {
+            *a = from[0];
+            *b = from[1];
+        }
+        (a, b)
+    }
+
+    /// Read two vectors from a slice of interleaved pairs.
+    ///
+    /// Lane `i` of the `j`th vector is read from `from[2 * i + j]`.
+    ///
+    /// # Panics
+    /// Panics if the length of `from` is less than `2 * width()`.
+    #[inline]
+    fn deinterleave2(token: Self::Token, from: &[Self::Scalar]) -> (Self, Self) {
+        assert!(
+            from.len() >= 2 * Self::width(),
+            "source not large enough to load vectors"
+        );
+        unsafe { Self::deinterleave2_unchecked(token, from) }
+    }
+
+    /// Read three vectors from a slice of interleaved triples without checking the length.
+    ///
+    /// Lane `i` of the `j`th vector is read from `from[3 * i + j]`.
+    ///
+    /// # Safety
+    /// * `from` must be length at least `3 * width()`.
+    #[inline]
+    unsafe fn deinterleave3_unchecked(
+        token: Self::Token,
+        from: &[Self::Scalar],
+    ) -> (Self, Self, Self) {
+        let mut a = Self::zeroed(token);
+        let mut b = Self::zeroed(token);
+        let mut c = Self::zeroed(token);
+        for (((a, b), c), from) in a
+            .as_slice_mut()
+            .iter_mut()
+            .zip(b.as_slice_mut())
+            .zip(c.as_slice_mut())
+            .zip(from.chunks_exact(3))
+        {
+            *a = from[0];
+            *b = from[1];
+            *c = from[2];
+        }
+        (a, b, c)
+    }
+
+    /// Read three vectors from a slice of interleaved triples.
+    ///
+    /// Lane `i` of the `j`th vector is read from `from[3 * i + j]`.
+    ///
+    /// # Panics
+    /// Panics if the length of `from` is less than `3 * width()`.
+    #[inline]
+    fn deinterleave3(token: Self::Token, from: &[Self::Scalar]) -> (Self, Self, Self) {
+        assert!(
+            from.len() >= 3 * Self::width(),
+            "source not large enough to load vectors"
+        );
+        unsafe { Self::deinterleave3_unchecked(token, from) }
+    }
+
+    /// Read four vectors from a slice of interleaved quadruples without checking the length.
+    ///
+    /// Lane `i` of the `j`th vector is read from `from[4 * i + j]`.
+    ///
+    /// # Safety
+    /// * `from` must be length at least `4 * width()`.
+    #[inline]
+    unsafe fn deinterleave4_unchecked(
+        token: Self::Token,
+        from: &[Self::Scalar],
+    ) -> (Self, Self, Self, Self) {
+        let mut a = Self::zeroed(token);
+        let mut b = Self::zeroed(token);
+        let mut c = Self::zeroed(token);
+        let mut d = Self::zeroed(token);
+        for ((((a, b), c), d), from) in a
+            .as_slice_mut()
+            .iter_mut()
+            .zip(b.as_slice_mut())
+            .zip(c.as_slice_mut())
+            .zip(d.as_slice_mut())
+            .zip(from.chunks_exact(4))
+        {
+            *a = from[0];
+            *b = from[1];
+            *c = from[2];
+            *d = from[3];
+        }
+        (a, b, c, d)
+    }
+
+    /// Read four vectors from a slice of interleaved quadruples.
+    ///
+    /// Lane `i` of the `j`th vector is read from `from[4 * i + j]`.
+    ///
+    /// # Panics
+    /// Panics if the length of `from` is less than `4 * width()`.
+    #[inline]
+    fn deinterleave4(token: Self::Token, from: &[Self::Scalar]) -> (Self, Self, Self, Self) {
+        assert!(
+            from.len() >= 4 * Self::width(),
+            "source not large enough to load vectors"
+        );
+        unsafe { Self::deinterleave4_unchecked(token, from) }
+    }
+
+    /// Write this vector and another to a slice of interleaved pairs without checking the length.
+    ///
+    /// Lane `i` of `self` is written to `to[2 * i]`, and lane `i` of `b` to `to[2 * i + 1]`.
+    ///
+    /// # Safety
+    /// * `to` must be length at least `2 * width()`.
+    #[inline]
+    unsafe fn interleave2_unchecked(self, b: Self, to: &mut [Self::Scalar]) {
+        for ((a, b), to) in self
+            .as_slice()
+            .iter()
+            .zip(b.as_slice())
+            .zip(to.chunks_exact_mut(2))
+        {
+            to[0] = *a;
+            to[1] = *b;
+        }
+    }
+
+    /// Write this vector and another to a slice of interleaved pairs.
+    ///
+    /// Lane `i` of `self` is written to `to[2 * i]`, and lane `i` of `b` to `to[2 * i + 1]`.
+    ///
+    /// # Panics
+    /// Panics if the length of `to` is less than `2 * width()`.
+    #[inline]
+    fn interleave2(self, b: Self, to: &mut [Self::Scalar]) {
+        assert!(
+            to.len() >= 2 * Self::width(),
+            "destination not large enough to store vectors"
+        );
+        unsafe { self.interleave2_unchecked(b, to) };
+    }
+
+    /// Write this vector and two others to a slice of interleaved triples without checking the
+    /// length.
+    ///
+    /// Lane `i` of `self`, `b` and `c` is written to `to[3 * i]`, `to[3 * i + 1]` and
+    /// `to[3 * i + 2]`, respectively.
+    ///
+    /// # Safety
+    /// * `to` must be length at least `3 * width()`.
+    #[inline]
+    unsafe fn interleave3_unchecked(self, b: Self, c: Self, to: &mut [Self::Scalar]) {
+        for (((a, b), c), to) in self
+            .as_slice()
+            .iter()
+            .zip(b.as_slice())
+            .zip(c.as_slice())
+            .zip(to.chunks_exact_mut(3))
+        {
+            to[0] = *a;
+            to[1] = *b;
+            to[2] = *c;
+        }
+    }
+
+    /// Write this vector and two others to a slice of interleaved triples.
+    ///
+    /// Lane `i` of `self`, `b` and `c` is written to `to[3 * i]`, `to[3 * i + 1]` and
+    /// `to[3 * i + 2]`, respectively.
+    ///
+    /// # Panics
+    /// Panics if the length of `to` is less than `3 * width()`.
+    #[inline]
+    fn interleave3(self, b: Self, c: Self, to: &mut [Self::Scalar]) {
+        assert!(
+            to.len() >= 3 * Self::width(),
+            "destination not large enough to store vectors"
+        );
+        unsafe { self.interleave3_unchecked(b, c, to) };
+    }
+
+    /// Write this vector and three others to a slice of interleaved quadruples without checking
+    /// the length.
+    ///
+    /// Lane `i` of `self`, `b`, `c` and `d` is written to `to[4 * i]` through `to[4 * i + 3]`.
+    ///
+    /// # Safety
+    /// * `to` must be length at least `4 * width()`.
+    #[inline]
+    unsafe fn interleave4_unchecked(self, b: Self, c: Self, d: Self, to: &mut [Self::Scalar]) {
+        for ((((a, b), c), d), to) in self
+            .as_slice()
+            .iter()
+            .zip(b.as_slice())
+            .zip(c.as_slice())
+            .zip(d.as_slice())
+            .zip(to.chunks_exact_mut(4))
+        {
+            to[0] = *a;
+            to[1] = *b;
+            to[2] = *c;
+            to[3] = *d;
+        }
+    }
+
+    /// Write this vector and three others to a slice of interleaved quadruples.
+    ///
+    /// Lane `i` of `self`, `b`, `c` and `d` is written to `to[4 * i]` through `to[4 * i + 3]`.
+    ///
+    /// # Panics
+    /// Panics if the length of `to` is less than `4 * width()`.
+    #[inline]
+    fn interleave4(self, b: Self, c: Self, d: Self, to: &mut [Self::Scalar]) {
+        assert!(
+            to.len() >= 4 * Self::width(),
+            "destination not large enough to store vectors"
+        );
+        unsafe { self.interleave4_unchecked(b, c, d, to) };
+    }
+
+    /// Reverse the order of the lanes.
+    #[inline]
+    fn reverse(mut self) -> Self {
+        self.as_slice_mut().reverse();
+        self
+    }
+
+    /// Rotate the lanes left by `N` lanes.
+    ///
+    /// Lane `i` is read from lane `(i + N) % width()`.
+    #[inline]
+    fn rotate_lanes_left<const N: usize>(self) -> Self {
+        self.rotate_lanes_left_impl(N % Self::width())
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn rotate_lanes_left_impl(mut self, n: usize) -> Self {
+        self.as_slice_mut().rotate_left(n);
+        self
+    }
+
+    /// Create a new vector with each lane containing lane `I` of this vector.
+    ///
+    /// # Panics
+    /// Panics if `I` is not less than `width()`.
+    #[inline]
+    fn broadcast_lane<const I: usize>(self) -> Self {
+        assert!(I < Self::width(), "lane index out of range");
+        self.broadcast_lane_impl(I)
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn broadcast_lane_impl(self, lane: usize) -> Self {
+        Self::splat(self.to_token(), self.as_slice()[lane])
+    }
+
+    /// Shuffle the lanes of this vector.
+    ///
+    /// Lane `i` is read from lane `I::INDICES[i]`.
+    ///
+    /// # Panics
+    /// Panics if the length of `I::INDICES` is less than `width()`, or if any of the first
+    /// `width()` indices are not less than `width()`.
+    #[inline]
+    fn shuffle<I: ShuffleIndices>(self) -> Self {
+        assert!(
+            I::INDICES.len() >= Self::width(),
+            "not enough indices to shuffle vector"
+        );
+        assert!(
+            I::INDICES[..Self::width()]
+                .iter()
+                .all(|index| *index < Self::width()),
+            "lane index out of range"
+        );
+        let mut vector = self;
+        for (lane, index) in vector.as_slice_mut().iter_mut().zip(I::INDICES) {
+            *lane = self.as_slice()[*index];
+        }
+        vector
+    }
+
+    /// Split the vector into its low and high halves.
+    #[inline]
+    fn split(self) -> (HalfVector<Self>, HalfVector<Self>)
+    where
+        Self::Width: Half,
+        Self::Scalar: Scalar<Self::Token, <Self::Width as Half>::Halved>,
+    {
+        let token = self.to_token();
+        let (low, high) = self.as_slice().split_at(Self::width() / 2);
+        unsafe {
+            (
+                HalfVector::<Self>::read_unchecked(token, low),
+                HalfVector::<Self>::read_unchecked(token, high),
+            )
+        }
+    }
+
+    /// Join two vectors, with `low` in the low lanes and `high` in the high lanes.
+    #[inline]
+    fn concat(low: HalfVector<Self>, high: HalfVector<Self>) -> Self
+    where
+        Self::Width: Half,
+        Self::Scalar: Scalar<Self::Token, <Self::Width as Half>::Halved>,
+    {
+        let mut vector = Self::zeroed(low.to_token());
+        let (low_lanes, high_lanes) = vector.as_slice_mut().split_at_mut(Self::width() / 2);
+        unsafe {
+            low.write_unchecked(low_lanes);
+            high.write_unchecked(high_lanes);
+        }
+        vector
+    }
+
     /// Create a new vector with each lane containing zeroes.
     fn zeroed(token: Self::Token) -> Self;
 
@@ -190,7 +634,28 @@ pub unsafe trait Vector: Copy {
     fn splat(token: Self::Token, from: Self::Scalar) -> Self;
 }
 
+/// Compile-time lane indices for [`Vector::shuffle`](trait.Vector.html#method.shuffle).
+///
+/// The following example swaps adjacent pairs of lanes in vectors of up to 8 lanes:
+/// ```
+/// use generic_simd::vector::ShuffleIndices;
+///
+/// struct SwapPairs;
+///
+/// impl ShuffleIndices for SwapPairs {
+///     const INDICES: &'static [usize] = &[1, 0, 3, 2, 5, 4, 7, 6];
+/// }
+/// ```
+pub trait ShuffleIndices {
+    /// The lane to read for each lane of the shuffled vector.
+    const INDICES: &'static [usize];
+}
+
 /// A supertrait for vectors supporting typical arithmetic operations.
+///
+/// Integer arithmetic wraps on overflow.  No supported instruction set provides integer division,
+/// so integer `Div` is performed lane-wise with `wrapping_div` on every token, and panics if any
+/// lane of the divisor is zero.
 pub trait Ops:
     Vector
     + AsRef<[<Self as Vector>::Scalar]>
@@ -213,6 +678,7 @@ pub trait Ops:
     + Div<<Self as Vector>::Scalar, Output = Self>
     + DivAssign<Self>
     + DivAssign<<Self as Vector>::Scalar>
+    + MulAdd
 {
 }
 impl<V> Ops for V where
@@ -237,13 +703,461 @@ impl<V> Ops for V where
         + Div<<V as Vector>::Scalar, Output = V>
         + DivAssign<V>
         + DivAssign<<V as Vector>::Scalar>
+        + MulAdd
+{
+}
+
+/// A supertrait for vectors supporting lane-wise bitwise operations.
+///
+/// Float lanes are operated on through their bit representation, which is useful for masking and
+/// manipulating the sign.  Complex lanes are operated on as pairs of float lanes.
+pub trait Bitwise:
+    Vector
+    + BitAnd<Self, Output = Self>
+    + BitOr<Self, Output = Self>
+    + BitXor<Self, Output = Self>
+    + Not<Output = Self>
 {
+    /// Returns the bitwise AND of `self` and the complement of `other`, equivalent to
+    /// `self & !other`.
+    fn and_not(self, other: Self) -> Self;
+}
+
+/// A supertrait for vectors supporting multiply-add.
+///
+/// The operations are fused (computed with a single rounding) when supported by the vector's
+/// token, such as [`Avx2`](../arch/x86/struct.Avx2.html).  Otherwise, they are computed with a
+/// separate multiply and add.
+pub trait MulAdd: Vector {
+    /// Returns `self * a + b`.
+    fn mul_add(self, a: Self, b: Self) -> Self;
+
+    /// Returns `self * a - b`.
+    fn mul_sub(self, a: Self, b: Self) -> Self;
+}
+
+impl<V> MulAdd for V
+where
+    V: Vector + MulAddImpl<<V as Vector>::Token>,
+{
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self.mul_add_impl(a, b)
+    }
+
+    #[inline]
+    fn mul_sub(self, a: Self, b: Self) -> Self {
+        self.mul_sub_impl(a, b)
+    }
+}
+
+/// Implements [`MulAdd`](trait.MulAdd.html) for a particular token.
+///
+/// Vectors wrapped in a [`ShimToken`](../shim/struct.ShimToken.html) may use a fused
+/// implementation if the wrapping token supports it.
+#[doc(hidden)]
+pub trait MulAddImpl<Token>: Sized {
+    fn mul_add_impl(self, a: Self, b: Self) -> Self;
+    fn mul_sub_impl(self, a: Self, b: Self) -> Self;
+}
+
+/// A supertrait for vectors supporting gathered and strided loads.
+///
+/// The loads use hardware gathers when supported by the vector's token, such as
+/// [`Avx2`](../arch/x86/struct.Avx2.html).  Otherwise, each lane is loaded separately.
+pub trait Gather: Vector {
+    /// Read from arbitrary elements of a slice.
+    ///
+    /// Lane `i` is read from `from[indices[i]]`.
+    ///
+    /// # Panics
+    /// Panics if the length of `indices` is less than `width()`, or if any of the first `width()`
+    /// indices are out of bounds.
+    fn gather(token: Self::Token, from: &[Self::Scalar], indices: &[usize]) -> Self;
+
+    /// Read from arbitrary elements of a slice without checking the indices.
+    ///
+    /// Lane `i` is read from `from[indices[i]]`.
+    ///
+    /// # Safety
+    /// * `indices` must be length at least `width()`.
+    /// * The first `width()` indices must be less than the length of `from`.
+    unsafe fn gather_unchecked(
+        token: Self::Token,
+        from: &[Self::Scalar],
+        indices: &[usize],
+    ) -> Self;
+
+    /// Read from evenly spaced elements of a slice.
+    ///
+    /// Lane `i` is read from `from[i * stride]`.
+    ///
+    /// # Panics
+    /// Panics if the length of `from` is not greater than `(width() - 1) * stride`.
+    fn read_strided(token: Self::Token, from: &[Self::Scalar], stride: usize) -> Self;
+
+    /// Read from evenly spaced elements of a slice without checking the length.
+    ///
+    /// Lane `i` is read from `from[i * stride]`.
+    ///
+    /// # Safety
+    /// * The length of `from` must be greater than `(width() - 1) * stride`.
+    unsafe fn read_strided_unchecked(
+        token: Self::Token,
+        from: &[Self::Scalar],
+        stride: usize,
+    ) -> Self;
+}
+
+impl<V> Gather for V
+where
+    V: Vector + GatherImpl<<V as Vector>::Token>,
+{
+    #[inline]
+    fn gather(token: Self::Token, from: &[Self::Scalar], indices: &[usize]) -> Self {
+        assert!(
+            indices.len() >= Self::width(),
+            "not enough indices to gather vector"
+        );
+        assert!(
+            indices[..Self::width()]
+                .iter()
+                .all(|index| *index < from.len()),
+            "gather index out of bounds"
+        );
+        unsafe { Self::gather_impl(token, from, indices) }
+    }
+
+    #[inline]
+    unsafe fn gather_unchecked(
+        token: Self::Token,
+        from: &[Self::Scalar],
+        indices: &[usize],
+    ) -> Self {
+        Self::gather_impl(token, from, indices)
+    }
+
+    #[inline]
+    fn read_strided(token: Self::Token, from: &[Self::Scalar], stride: usize) -> Self {
+        assert!(
+            (Self::width() - 1)
+                .checked_mul(stride)
+                .map_or(false, |last| last < from.len()),
+            "source not large enough to load strided vector"
+        );
+        unsafe { Self::read_strided_impl(token, from, stride) }
+    }
+
+    #[inline]
+    unsafe fn read_strided_unchecked(
+        token: Self::Token,
+        from: &[Self::Scalar],
+        stride: usize,
+    ) -> Self {
+        Self::read_strided_impl(token, from, stride)
+    }
+}
+
+/// Implements [`Gather`](trait.Gather.html) for a particular token.
+///
+/// The default implementations load each lane separately.  Vectors wrapped in a
+/// [`ShimToken`](../shim/struct.ShimToken.html) may use hardware gathers if the wrapping token
+/// supports them.
+#[doc(hidden)]
+pub trait GatherImpl<Token>: Vector {
+    #[inline]
+    unsafe fn gather_impl(token: Self::Token, from: &[Self::Scalar], indices: &[usize]) -> Self {
+        gather_lanes(token, from, indices)
+    }
+
+    #[inline]
+    unsafe fn read_strided_impl(token: Self::Token, from: &[Self::Scalar], stride: usize) -> Self {
+        read_strided_lanes(token, from, stride)
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn gather_lanes<V: Vector>(
+    token: V::Token,
+    from: &[V::Scalar],
+    indices: &[usize],
+) -> V {
+    let mut vector = V::zeroed(token);
+    for (lane, index) in vector.as_slice_mut().iter_mut().zip(indices) {
+        *lane = *from.get_unchecked(*index);
+    }
+    vector
+}
+
+#[inline]
+pub(crate) unsafe fn read_strided_lanes<V: Vector>(
+    token: V::Token,
+    from: &[V::Scalar],
+    stride: usize,
+) -> V {
+    let mut vector = V::zeroed(token);
+    for (i, lane) in vector.as_slice_mut().iter_mut().enumerate() {
+        *lane = *from.get_unchecked(i * stride);
+    }
+    vector
+}
+
+/// A supertrait for vectors supporting horizontal reductions.
+pub trait Reduce: Vector {
+    /// Returns the sum of all lanes.
+    fn reduce_sum(self) -> Self::Scalar;
+
+    /// Returns the product of all lanes.
+    fn reduce_product(self) -> Self::Scalar;
+}
+
+/// A supertrait for vectors supporting horizontal minimum and maximum reductions.
+///
+/// If any lane is NaN, the result is unspecified.
+pub trait ReduceOrd: Compare {
+    /// Returns the minimum lane.
+    fn reduce_min(self) -> Self::Scalar;
+
+    /// Returns the maximum lane.
+    fn reduce_max(self) -> Self::Scalar;
+}
+
+/// A mask containing a boolean for each lane of a vector.
+///
+/// Masks are produced by the lane-wise comparisons in [`Compare`](trait.Compare.html).
+pub trait Mask:
+    Copy
+    + BitAnd<Self, Output = Self>
+    + BitOr<Self, Output = Self>
+    + BitXor<Self, Output = Self>
+    + Not<Output = Self>
+{
+    /// The token that proves support for this mask on the CPU.
+    type Token: Token;
+
+    /// The number of lanes in the mask.
+    type Width: width::Width;
+
+    /// Returns the number of lanes.
+    #[inline]
+    fn width() -> usize {
+        <Self::Width as width::Width>::VALUE
+    }
+
+    /// Create a new mask with each lane set to the provided value.
+    fn splat(token: Self::Token, value: bool) -> Self;
+
+    /// Returns the value of a lane.
+    ///
+    /// # Panics
+    /// Panics if `lane` is not less than `width()`.
+    fn test(&self, lane: usize) -> bool;
+
+    /// Returns true if any lane is set.
+    #[inline]
+    fn any(&self) -> bool {
+        (0..Self::width()).any(|lane| self.test(lane))
+    }
+
+    /// Returns true if every lane is set.
+    #[inline]
+    fn all(&self) -> bool {
+        (0..Self::width()).all(|lane| self.test(lane))
+    }
+
+    /// Returns true if no lane is set.
+    #[inline]
+    fn none(&self) -> bool {
+        !self.any()
+    }
+}
+
+/// A mask that can select lanes from a vector.
+pub trait Select<V>: Mask {
+    /// Select each lane from `a` if the mask is set, or from `b` otherwise.
+    fn select(self, a: V, b: V) -> V;
+}
+
+/// A supertrait for vectors supporting lane-wise comparisons.
+///
+/// Comparisons follow the semantics of `PartialEq` and `PartialOrd` for each lane.  In
+/// particular, any comparison involving NaN is false, except for `lanes_ne`, which is true.
+pub trait Compare: Vector {
+    /// The mask produced by comparisons.
+    type Mask: Mask<Token = <Self as Vector>::Token, Width = <Self as Vector>::Width> + Select<Self>;
+
+    /// Lane-wise `==`.
+    fn lanes_eq(self, other: Self) -> Self::Mask;
+
+    /// Lane-wise `!=`.
+    fn lanes_ne(self, other: Self) -> Self::Mask;
+
+    /// Lane-wise `<`.
+    fn lanes_lt(self, other: Self) -> Self::Mask;
+
+    /// Lane-wise `<=`.
+    fn lanes_le(self, other: Self) -> Self::Mask;
+
+    /// Lane-wise `>`.
+    fn lanes_gt(self, other: Self) -> Self::Mask;
+
+    /// Lane-wise `>=`.
+    fn lanes_ge(self, other: Self) -> Self::Mask;
+}
+
+/// A supertrait for vectors supporting lane-wise conversion to another scalar type.
+///
+/// Conversions are provided from `f32` to `f64` and `i32`, from `f64` to `f32`, and from `i32`
+/// to `f32`.  Conversions follow the semantics of `as`, except that float to integer conversions
+/// of NaN or out of range lanes produce unspecified values.
+pub trait Convert<To>: Vector {
+    /// The converted vector, with the same token and number of lanes.
+    type Output: Vector<
+        Scalar = To,
+        Token = <Self as Vector>::Token,
+        Width = <Self as Vector>::Width,
+    >;
+
+    /// Converts each lane, truncating towards zero when converting to an integer.
+    fn convert(self) -> Self::Output;
+}
+
+/// A supertrait for float vectors supporting lane-wise conversion to integers with rounding.
+pub trait ConvertRound<To>: Convert<To> {
+    /// Converts each lane, rounding to the nearest integer with ties to even.
+    fn convert_round(self) -> Self::Output;
+}
+
+/// A supertrait for float vectors supporting reinterpretation of lanes as unsigned integers.
+///
+/// Each lane is converted like `f32::to_bits` and `f32::from_bits`.
+pub trait Bits: Vector {
+    /// The vector of bits, with the same token and number of lanes.
+    type Bits: Vector<Token = <Self as Vector>::Token, Width = <Self as Vector>::Width>;
+
+    /// Reinterprets each lane as an unsigned integer.
+    fn to_bits(self) -> Self::Bits;
+
+    /// Reinterprets each lane of an unsigned integer vector as a float.
+    fn from_bits(bits: Self::Bits) -> Self;
+}
+
+#[inline]
+pub(crate) fn convert_lanes<V: Vector, U: Vector<Token = V::Token>>(
+    vector: V,
+    convert: impl Fn(V::Scalar) -> U::Scalar,
+) -> U {
+    let mut output = U::zeroed(vector.to_token());
+    for (lane, from) in output.as_slice_mut().iter_mut().zip(vector.as_slice()) {
+        *lane = convert(*from);
+    }
+    output
+}
+
+// Adding and subtracting 2^23 rounds away the fraction, since `f32` has no fractional bits at that
+// magnitude.  Larger values are already integers.
+#[inline]
+pub(crate) fn round_to_i32(x: f32) -> i32 {
+    const ROUND: f32 = 8_388_608.;
+    if (0. ..ROUND).contains(&x) {
+        ((x + ROUND) - ROUND) as i32
+    } else if (-ROUND..0.).contains(&x) {
+        ((x - ROUND) + ROUND) as i32
+    } else {
+        x as i32
+    }
 }
 
 /// A supertrait for vectors that allow arithmetic operations over signed types.
 pub trait Signed: Ops + Neg<Output = Self> {}
 impl<V> Signed for V where V: Ops + Neg<Output = V> {}
 
+/// A supertrait for float vectors supporting lane-wise minimum, maximum and sign operations.
+///
+/// NaN is handled identically by every token: if either lane passed to `min` or `max` is NaN,
+/// the resulting lane is NaN.  If the lanes are zeros of opposite sign, either may be returned.
+pub trait Float: Signed {
+    /// Returns the lane-wise minimum.
+    fn min(self, other: Self) -> Self;
+
+    /// Returns the lane-wise maximum.
+    fn max(self, other: Self) -> Self;
+
+    /// Restricts each lane to the range `[lo, hi]`.
+    ///
+    /// This is equivalent to `self.max(lo).min(hi)`, so NaN lanes are propagated.
+    #[inline]
+    fn clamp(self, lo: Self, hi: Self) -> Self {
+        self.max(lo).min(hi)
+    }
+
+    /// Returns the lane-wise absolute value, by clearing the sign bit.
+    fn abs(self) -> Self;
+
+    /// Returns the magnitude of `self` with the sign bit of `sign`, for each lane.
+    fn copysign(self, sign: Self) -> Self;
+}
+
+/// A supertrait for float vectors supporting square roots and reciprocals.
+///
+/// Each reciprocal is available in three accuracy tiers:
+/// * `recip` and `rsqrt` are computed with a correctly rounded division and square root.
+/// * `recip_estimate` and `rsqrt_estimate` use the fastest estimate instruction available, with a
+///   relative error of at most 2<sup>-8</sup>.  Tokens without an estimate instruction return the
+///   exact result.
+/// * `recip_refined` and `rsqrt_refined` improve the estimate with one Newton-Raphson iteration,
+///   which approximately doubles the number of correct bits.  Lanes that are zero or infinite may
+///   produce NaN.
+pub trait Sqrt: Float {
+    /// Returns the lane-wise square root.
+    fn sqrt(self) -> Self;
+
+    /// Returns the lane-wise reciprocal, `1 / self`.
+    fn recip(self) -> Self;
+
+    /// Returns the lane-wise reciprocal square root, `1 / sqrt(self)`.
+    fn rsqrt(self) -> Self;
+
+    /// Returns an estimate of the lane-wise reciprocal.
+    fn recip_estimate(self) -> Self;
+
+    /// Returns an estimate of the lane-wise reciprocal square root.
+    fn rsqrt_estimate(self) -> Self;
+
+    /// Returns an estimate of the lane-wise reciprocal, refined with one Newton-Raphson iteration.
+    fn recip_refined(self) -> Self;
+
+    /// Returns an estimate of the lane-wise reciprocal square root, refined with one
+    /// Newton-Raphson iteration.
+    fn rsqrt_refined(self) -> Self;
+}
+
+/// A supertrait for float vectors supporting rounding to integral values.
+pub trait Round: Float {
+    /// Rounds each lane toward negative infinity.
+    fn floor(self) -> Self;
+
+    /// Rounds each lane toward positive infinity.
+    fn ceil(self) -> Self;
+
+    /// Rounds each lane to the nearest integer, with ties rounded away from zero.
+    fn round(self) -> Self;
+
+    /// Rounds each lane to the nearest integer, with ties rounded to even.
+    fn round_ties_even(self) -> Self;
+
+    /// Rounds each lane toward zero.
+    fn trunc(self) -> Self;
+
+    /// Returns the fractional part of each lane.
+    ///
+    /// This is equivalent to `self - self.trunc()`, so infinite lanes produce NaN.
+    #[inline]
+    fn fract(self) -> Self {
+        self - self.trunc()
+    }
+}
+
 /// Complex valued vectors.
 pub trait Complex: Signed {
     /// The real scalar type.
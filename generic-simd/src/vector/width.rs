@@ -5,30 +5,53 @@ pub trait Width {
     const VALUE: usize;
 }
 
+/// Indicates a vector contains `N` lanes.
+///
+/// Generic code can name widths with this type directly, such as `Lanes<{ 2 * 4 }>`.
+pub struct Lanes<const N: usize>;
+
+impl<const N: usize> Width for Lanes<N> {
+    const VALUE: usize = N;
+}
+
 /// Indicates a vector contains 1 lane.
-pub struct W1;
+pub type W1 = Lanes<1>;
+
+/// The value of [`W1`](type.W1.html), for use in expressions.
+pub const W1: W1 = Lanes;
 
 /// Indicates a vector contains 2 lanes.
-pub struct W2;
+pub type W2 = Lanes<2>;
+
+/// The value of [`W2`](type.W2.html), for use in expressions.
+pub const W2: W2 = Lanes;
 
 /// Indicates a vector contains 4 lanes.
-pub struct W4;
+pub type W4 = Lanes<4>;
+
+/// The value of [`W4`](type.W4.html), for use in expressions.
+pub const W4: W4 = Lanes;
 
 /// Indicates a vector contains 8 lanes.
-pub struct W8;
+pub type W8 = Lanes<8>;
 
-impl Width for W1 {
-    const VALUE: usize = 1;
-}
+/// The value of [`W8`](type.W8.html), for use in expressions.
+pub const W8: W8 = Lanes;
 
-impl Width for W2 {
-    const VALUE: usize = 2;
-}
+/// Indicates a vector contains 16 lanes.
+pub type W16 = Lanes<16>;
 
-impl Width for W4 {
-    const VALUE: usize = 4;
-}
+/// The value of [`W16`](type.W16.html), for use in expressions.
+pub const W16: W16 = Lanes;
 
-impl Width for W8 {
-    const VALUE: usize = 8;
-}
+/// Indicates a vector contains 32 lanes.
+pub type W32 = Lanes<32>;
+
+/// The value of [`W32`](type.W32.html), for use in expressions.
+pub const W32: W32 = Lanes;
+
+/// Indicates a vector contains 64 lanes.
+pub type W64 = Lanes<64>;
+
+/// The value of [`W64`](type.W64.html), for use in expressions.
+pub const W64: W64 = Lanes;